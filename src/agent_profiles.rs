@@ -0,0 +1,183 @@
+//! Agent service profiles and corridor index.
+//!
+//! The admin records for each agent the destination countries and payout
+//! currencies it serves, how it pays out, and an optional hash of its display
+//! name. Agents with a profile can only be assigned remittances whose currency
+//! and country they serve; agents without one are not restricted. A
+//! per-country index lets routing look up the registered agents serving a
+//! corridor page by page.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::{is_agent_registered, ContractError, MAX_PAGE_SIZE};
+
+/// Maximum number of countries, currencies or payout methods in a profile
+pub const MAX_PROFILE_ENTRIES: u32 = 32;
+
+/// How an agent hands the funds to the recipient.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutMethod {
+    /// Cash pickup at an agent location
+    Cash,
+    /// Deposit into a bank account
+    BankTransfer,
+    /// Mobile money wallet
+    MobileMoney,
+    /// Home delivery of cash
+    HomeDelivery,
+}
+
+/// Service profile of an agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentProfile {
+    /// Destination countries served (e.g., "NG")
+    pub countries: Vec<String>,
+    /// Payout currencies offered (e.g., "NGN")
+    pub currencies: Vec<String>,
+    /// Payout methods offered
+    pub payout_methods: Vec<PayoutMethod>,
+    /// Timestamp at which the profile was last updated
+    pub updated_at: u64,
+}
+
+/// One page of the agents serving a corridor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentPage {
+    /// Agents on this page, in profile registration order
+    pub agents: Vec<Address>,
+    /// 1-based page number that was requested
+    pub page: u32,
+    /// Number of pages at the effective page size
+    pub total_pages: u32,
+    /// Number of registered agents serving the corridor
+    pub total_records: u32,
+}
+
+/// Storage keys for agent profiles.
+#[contracttype]
+#[derive(Clone)]
+pub enum AgentProfileKey {
+    /// Profile indexed by agent (persistent storage)
+    Profile(Address),
+    /// Agents whose profile lists a country (persistent storage)
+    CountryAgents(String),
+    /// Hash of an agent's display name (persistent storage)
+    NameHash(Address),
+}
+
+/// Gets an agent's profile, if any
+pub fn get_agent_profile(env: &Env, agent: &Address) -> Option<AgentProfile> {
+    env.storage()
+        .persistent()
+        .get(&AgentProfileKey::Profile(agent.clone()))
+}
+
+/// Gets the hash of an agent's display name, if published
+pub fn get_agent_name_hash(env: &Env, agent: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&AgentProfileKey::NameHash(agent.clone()))
+}
+
+/// Publishes the hash of an agent's display name, or withdraws it with `None`
+pub fn set_agent_name_hash(env: &Env, agent: &Address, name_hash: &Option<BytesN<32>>) {
+    let key = AgentProfileKey::NameHash(agent.clone());
+    match name_hash {
+        Some(name_hash) => env.storage().persistent().set(&key, name_hash),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+fn get_country_agents(env: &Env, country: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AgentProfileKey::CountryAgents(country.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_country_agents(env: &Env, country: &String, agents: &Vec<Address>) {
+    let key = AgentProfileKey::CountryAgents(country.clone());
+    if agents.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, agents);
+    }
+}
+
+/// Stores an agent's profile and moves the agent between country indexes.
+pub fn set_agent_profile(env: &Env, agent: &Address, profile: &AgentProfile) {
+    if let Some(previous) = get_agent_profile(env, agent) {
+        for country in previous.countries.iter() {
+            if !profile.countries.contains(&country) {
+                let mut agents = get_country_agents(env, &country);
+                if let Some(index) = agents.first_index_of(agent) {
+                    agents.remove(index);
+                }
+                set_country_agents(env, &country, &agents);
+            }
+        }
+    }
+
+    for country in profile.countries.iter() {
+        let mut agents = get_country_agents(env, &country);
+        if !agents.contains(agent) {
+            agents.push_back(agent.clone());
+            set_country_agents(env, &country, &agents);
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&AgentProfileKey::Profile(agent.clone()), profile);
+}
+
+/// Returns whether an agent serves a corridor; agents without a profile serve every corridor.
+pub fn agent_serves_corridor(env: &Env, agent: &Address, currency: &String, country: &String) -> bool {
+    match get_agent_profile(env, agent) {
+        Some(profile) => profile.countries.contains(country) && profile.currencies.contains(currency),
+        None => true,
+    }
+}
+
+/// Returns the registered agents whose profile serves a corridor, in profile registration order.
+pub fn get_corridor_agents(env: &Env, currency: &String, country: &String) -> Vec<Address> {
+    let mut serving = Vec::new(env);
+    for agent in get_country_agents(env, country).iter() {
+        if is_agent_registered(env, &agent) && agent_serves_corridor(env, &agent, currency, country) {
+            serving.push_back(agent);
+        }
+    }
+    serving
+}
+
+/// Gets a page of the registered agents whose profile serves a corridor.
+///
+/// Agents without a profile are not listed, and removed agents are skipped.
+/// `page` is 1-based and `limit` is clamped to `MAX_PAGE_SIZE`.
+pub fn get_agents_by_corridor(
+    env: &Env,
+    currency: &String,
+    country: &String,
+    page: u32,
+    limit: u32,
+) -> Result<AgentPage, ContractError> {
+    if page == 0 || limit == 0 {
+        return Err(ContractError::InvalidPagination);
+    }
+    let limit = limit.min(MAX_PAGE_SIZE);
+
+    let serving = get_corridor_agents(env, currency, country);
+    let total_records = serving.len();
+    let start = (page - 1).saturating_mul(limit).min(total_records);
+    let end = start.saturating_add(limit).min(total_records);
+
+    Ok(AgentPage {
+        agents: serving.slice(start..end),
+        page,
+        total_pages: total_records.div_ceil(limit),
+        total_records,
+    })
+}
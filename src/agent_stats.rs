@@ -0,0 +1,228 @@
+//! On-chain agent performance metrics.
+//!
+//! Every settlement and every cancellation of a remittance assigned to an
+//! agent updates that agent's counters, so agents can be compared on settled
+//! volume, reliability and speed. Settled volume is kept per token, since
+//! amounts in different tokens cannot be added up.
+//!
+//! Each token has a ranking of the agents that settled in it, ordered by
+//! descending volume. It is maintained on every settlement by moving the
+//! agent up past the agents it overtook, so `get_agent_leaderboard` only
+//! reads the requested page.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{ContractError, MAX_PAGE_SIZE};
+
+/// Performance counters of an agent.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AgentStats {
+    /// Number of remittances the agent settled
+    pub settled_count: u64,
+    /// Number of remittances cancelled while assigned to the agent
+    pub cancelled_count: u64,
+    /// Sum of the seconds from creation to settlement over all settlements
+    pub total_settle_time: u64,
+    /// Average seconds from creation to settlement
+    pub avg_settle_time: u64,
+    /// Longest seconds from creation to settlement
+    pub max_settle_time: u64,
+    /// Timestamp of the agent's last settlement
+    pub last_active: u64,
+}
+
+/// An agent's position on a token's leaderboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentRanking {
+    /// Agent address
+    pub agent: Address,
+    /// Total amount the agent settled in the leaderboard's token
+    pub settled_volume: i128,
+    /// Agent's performance counters
+    pub stats: AgentStats,
+}
+
+/// One page of a token's agent leaderboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentLeaderboardPage {
+    /// Agents on this page, by descending settled volume
+    pub rankings: Vec<AgentRanking>,
+    /// 1-based page number that was requested
+    pub page: u32,
+    /// Number of pages at the effective page size
+    pub total_pages: u32,
+    /// Number of agents that settled in the token
+    pub total_records: u32,
+}
+
+/// Storage keys for agent performance metrics.
+#[contracttype]
+#[derive(Clone)]
+pub enum AgentStatsKey {
+    /// Counters indexed by agent (persistent storage)
+    Stats(Address),
+    /// Settled volume indexed by agent and token (persistent storage)
+    SettledVolume(Address, Address),
+    /// Number of ranked agents indexed by token (persistent storage)
+    RankLen(Address),
+    /// Ranked agent indexed by token and position (persistent storage)
+    RankEntry(Address, u32),
+    /// Agent's position in a token's ranking, indexed by agent and token (persistent storage)
+    RankPosition(Address, Address),
+}
+
+/// Gets an agent's counters, all zero if nothing was recorded yet
+pub fn get_agent_stats(env: &Env, agent: &Address) -> AgentStats {
+    env.storage()
+        .persistent()
+        .get(&AgentStatsKey::Stats(agent.clone()))
+        .unwrap_or_default()
+}
+
+fn set_agent_stats(env: &Env, agent: &Address, stats: &AgentStats) {
+    env.storage()
+        .persistent()
+        .set(&AgentStatsKey::Stats(agent.clone()), stats);
+}
+
+/// Gets the total amount an agent settled in a token
+pub fn get_agent_settled_volume(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&AgentStatsKey::SettledVolume(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+fn get_rank_len(env: &Env, token: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&AgentStatsKey::RankLen(token.clone()))
+        .unwrap_or(0)
+}
+
+fn get_rank_entry(env: &Env, token: &Address, position: u32) -> Result<Address, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&AgentStatsKey::RankEntry(token.clone(), position))
+        .ok_or(ContractError::KeyNotFound)
+}
+
+fn set_rank_entry(env: &Env, token: &Address, position: u32, agent: &Address) {
+    env.storage()
+        .persistent()
+        .set(&AgentStatsKey::RankEntry(token.clone(), position), agent);
+    env.storage()
+        .persistent()
+        .set(&AgentStatsKey::RankPosition(agent.clone(), token.clone()), &position);
+}
+
+/// Adds to an agent's settled volume in a token and moves it up the token's ranking.
+///
+/// The agent is swapped past every agent above it with a lower volume, so the
+/// cost is proportional to the number of places it climbs. Agents with equal
+/// volume keep the order in which they reached it.
+fn add_settled_volume(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<(), ContractError> {
+    let volume = get_agent_settled_volume(env, agent, token)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&AgentStatsKey::SettledVolume(agent.clone(), token.clone()), &volume);
+
+    let position_key = AgentStatsKey::RankPosition(agent.clone(), token.clone());
+    let mut position = match env.storage().persistent().get(&position_key) {
+        Some(position) => position,
+        None => {
+            let len = get_rank_len(env, token);
+            env.storage()
+                .persistent()
+                .set(&AgentStatsKey::RankLen(token.clone()), &(len + 1));
+            len
+        }
+    };
+
+    while position > 0 {
+        let above = get_rank_entry(env, token, position - 1)?;
+        if get_agent_settled_volume(env, &above, token) >= volume {
+            break;
+        }
+        set_rank_entry(env, token, position, &above);
+        position -= 1;
+    }
+    set_rank_entry(env, token, position, agent);
+    Ok(())
+}
+
+/// Records a settlement by an agent of a remittance in `token` created at `created_at`.
+pub fn record_agent_settlement(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    amount: i128,
+    created_at: u64,
+) -> Result<(), ContractError> {
+    let now = env.ledger().timestamp();
+    let settle_time = now.saturating_sub(created_at);
+
+    let mut stats = get_agent_stats(env, agent);
+    stats.settled_count = stats.settled_count.checked_add(1).ok_or(ContractError::Overflow)?;
+    stats.total_settle_time = stats
+        .total_settle_time
+        .checked_add(settle_time)
+        .ok_or(ContractError::Overflow)?;
+    stats.avg_settle_time = stats.total_settle_time / stats.settled_count;
+    stats.max_settle_time = stats.max_settle_time.max(settle_time);
+    stats.last_active = now;
+
+    set_agent_stats(env, agent, &stats);
+    add_settled_volume(env, agent, token, amount)
+}
+
+/// Records the cancellation of a remittance assigned to an agent.
+pub fn record_agent_cancellation(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let mut stats = get_agent_stats(env, agent);
+    stats.cancelled_count = stats.cancelled_count.checked_add(1).ok_or(ContractError::Overflow)?;
+
+    set_agent_stats(env, agent, &stats);
+    Ok(())
+}
+
+/// Gets a page of the agents that settled in a token, by descending settled volume.
+///
+/// Reads only the requested page of the maintained ranking.
+/// `page` is 1-based and `limit` is clamped to `MAX_PAGE_SIZE`.
+pub fn get_agent_leaderboard(
+    env: &Env,
+    token: &Address,
+    page: u32,
+    limit: u32,
+) -> Result<AgentLeaderboardPage, ContractError> {
+    if page == 0 || limit == 0 {
+        return Err(ContractError::InvalidPagination);
+    }
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total_records = get_rank_len(env, token);
+
+    let start = (page - 1).saturating_mul(limit).min(total_records);
+    let end = start.saturating_add(limit).min(total_records);
+
+    let mut rankings = Vec::new(env);
+    for position in start..end {
+        let agent = get_rank_entry(env, token, position)?;
+        rankings.push_back(AgentRanking {
+            settled_volume: get_agent_settled_volume(env, &agent, token),
+            stats: get_agent_stats(env, &agent),
+            agent,
+        });
+    }
+
+    Ok(AgentLeaderboardPage {
+        rankings,
+        page,
+        total_pages: total_records.div_ceil(limit),
+        total_records,
+    })
+}
@@ -0,0 +1,163 @@
+//! Agent collateral bonds and slashing.
+//!
+//! Agents post a bond in each settlement token they pay out. Once the admin
+//! sets a `BondConfig`, remittances above its threshold can only be assigned
+//! to agents whose active bond in the remittance's token meets the minimum.
+//! An arbitrator or the admin can slash a bond to compensate the sender of a
+//! disputed remittance, paying the sender directly or an insurance pool.
+//! Agents withdraw collateral by first unbonding it; unbonding funds stay
+//! slashable until the unbonding delay, which always exceeds the dispute
+//! window, has passed and no dispute against the agent is open. Removing an
+//! agent unbonds its whole active bond in every token.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+/// Unbonding delay applied before a bond configuration is set (14 days)
+pub const DEFAULT_UNBONDING_DELAY: u64 = 1209600;
+
+/// Bond requirement configured by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    /// Remittance amount above which the agent must be bonded (in units of the remittance's token)
+    pub threshold: i128,
+    /// Minimum active bond in the remittance's token required above the threshold
+    pub min_bond: i128,
+    /// Seconds between unbonding and being able to withdraw (longer than the dispute window)
+    pub unbonding_delay: u64,
+    /// Recipient of slashed funds routed to insurance, if any
+    pub insurance_pool: Option<Address>,
+}
+
+/// Collateral an agent has posted in one token.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AgentBond {
+    /// Active bond counted towards the requirement
+    pub bonded: i128,
+    /// Collateral waiting out the unbonding delay
+    pub unbonding: i128,
+    /// Timestamp from which the unbonding collateral can be withdrawn
+    pub withdrawable_at: u64,
+    /// Total slashed from the agent over its lifetime
+    pub slashed: i128,
+}
+
+/// Where slashed collateral is sent.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlashDestination {
+    /// Sender of the disputed remittance
+    Sender,
+    /// Insurance pool from the bond configuration
+    InsurancePool,
+}
+
+/// Storage keys for agent bonds.
+#[contracttype]
+#[derive(Clone)]
+pub enum BondKey {
+    /// Bond requirement (instance storage)
+    BondConfig,
+    /// Bond indexed by agent and token (persistent storage)
+    Bond(Address, Address),
+    /// Amount slashed for a remittance (persistent storage)
+    Slashed(u64),
+}
+
+/// Gets the bond requirement, if configured
+pub fn get_bond_config(env: &Env) -> Option<BondConfig> {
+    env.storage().instance().get(&BondKey::BondConfig)
+}
+
+/// Sets the bond requirement
+pub fn set_bond_config(env: &Env, config: &BondConfig) {
+    env.storage().instance().set(&BondKey::BondConfig, config);
+}
+
+/// Gets the unbonding delay in seconds (defaults to 7 days)
+pub fn get_unbonding_delay(env: &Env) -> u64 {
+    get_bond_config(env)
+        .map(|config| config.unbonding_delay)
+        .unwrap_or(DEFAULT_UNBONDING_DELAY)
+}
+
+/// Gets an agent's bond in a token, all zero if the agent never posted one
+pub fn get_agent_bond(env: &Env, agent: &Address, token: &Address) -> AgentBond {
+    env.storage()
+        .persistent()
+        .get(&BondKey::Bond(agent.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+/// Stores an agent's bond in a token
+pub fn set_agent_bond(env: &Env, agent: &Address, token: &Address, bond: &AgentBond) {
+    env.storage()
+        .persistent()
+        .set(&BondKey::Bond(agent.clone(), token.clone()), bond);
+}
+
+/// Gets the amount already slashed for a remittance
+pub fn get_slashed_amount(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BondKey::Slashed(remittance_id))
+        .unwrap_or(0)
+}
+
+/// Sets the amount slashed for a remittance
+pub fn set_slashed_amount(env: &Env, remittance_id: u64, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&BondKey::Slashed(remittance_id), &amount);
+}
+
+/// Moves part of the active bond into unbonding, restarting the unbonding delay.
+///
+/// Returns the timestamp from which the unbonding collateral can be withdrawn.
+pub fn start_unbonding(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<u64, ContractError> {
+    let mut bond = get_agent_bond(env, agent, token);
+    if amount > bond.bonded {
+        return Err(ContractError::InsufficientBond);
+    }
+
+    bond.bonded -= amount;
+    bond.unbonding = bond.unbonding.checked_add(amount).ok_or(ContractError::Overflow)?;
+    bond.withdrawable_at = env
+        .ledger()
+        .timestamp()
+        .checked_add(get_unbonding_delay(env))
+        .ok_or(ContractError::Overflow)?;
+    set_agent_bond(env, agent, token, &bond);
+
+    Ok(bond.withdrawable_at)
+}
+
+/// Deducts slashed collateral from the active bond first, then from unbonding collateral.
+pub fn deduct_slash(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<(), ContractError> {
+    let mut bond = get_agent_bond(env, agent, token);
+    let available = bond.bonded.checked_add(bond.unbonding).ok_or(ContractError::Overflow)?;
+    if amount > available {
+        return Err(ContractError::InsufficientBond);
+    }
+
+    let from_bonded = amount.min(bond.bonded);
+    bond.bonded -= from_bonded;
+    bond.unbonding -= amount - from_bonded;
+    bond.slashed = bond.slashed.checked_add(amount).ok_or(ContractError::Overflow)?;
+    set_agent_bond(env, agent, token, &bond);
+
+    Ok(())
+}
+
+/// Checks that an agent is bonded enough to be assigned a remittance of `amount` in `token`.
+pub fn check_agent_bond(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<(), ContractError> {
+    match get_bond_config(env) {
+        Some(config) if amount > config.threshold && get_agent_bond(env, agent, token).bonded < config.min_bond => {
+            Err(ContractError::AgentBondRequired)
+        }
+        _ => Ok(()),
+    }
+}
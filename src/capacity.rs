@@ -0,0 +1,108 @@
+//! Agent liquidity capacity and outstanding exposure.
+//!
+//! Every remittance assigned to an agent adds its amount to the agent's
+//! exposure in the remittance token until it is settled, cancelled, failed or
+//! expired. Open-claim remittances count against the agent that claims them.
+//! Either the admin or the agent can declare a maximum capacity per token;
+//! assignments that would take the exposure in that token above it are
+//! rejected. Agents without a declared capacity in a token are not limited
+//! in it.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+/// Capacity usage of an agent in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentCapacity {
+    /// Declared maximum exposure, if any (in token units)
+    pub capacity: Option<i128>,
+    /// Amount of the agent's outstanding remittances
+    pub used: i128,
+    /// Remaining capacity (never negative), if a capacity is declared
+    pub available: Option<i128>,
+}
+
+/// Storage keys for agent capacity tracking.
+#[contracttype]
+#[derive(Clone)]
+pub enum CapacityKey {
+    /// Declared capacity indexed by agent and token (persistent storage)
+    Capacity(Address, Address),
+    /// Outstanding exposure indexed by agent and token (persistent storage)
+    Exposure(Address, Address),
+}
+
+/// Gets an agent's declared capacity in a token, if any
+pub fn get_agent_capacity_limit(env: &Env, agent: &Address, token: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&CapacityKey::Capacity(agent.clone(), token.clone()))
+}
+
+/// Declares an agent's capacity in a token, or removes the limit with `None`
+pub fn set_agent_capacity_limit(env: &Env, agent: &Address, token: &Address, capacity: Option<i128>) {
+    let key = CapacityKey::Capacity(agent.clone(), token.clone());
+    match capacity {
+        Some(capacity) => env.storage().persistent().set(&key, &capacity),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Gets the amount of an agent's outstanding remittances in a token
+pub fn get_agent_exposure(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CapacityKey::Exposure(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+fn set_agent_exposure(env: &Env, agent: &Address, token: &Address, exposure: i128) {
+    env.storage()
+        .persistent()
+        .set(&CapacityKey::Exposure(agent.clone(), token.clone()), &exposure);
+}
+
+/// Returns an agent's capacity, exposure and remaining capacity in a token
+pub fn get_agent_capacity(env: &Env, agent: &Address, token: &Address) -> AgentCapacity {
+    let capacity = get_agent_capacity_limit(env, agent, token);
+    let used = get_agent_exposure(env, agent, token);
+    AgentCapacity {
+        capacity,
+        used,
+        available: capacity.map(|capacity| capacity.saturating_sub(used).max(0)),
+    }
+}
+
+/// Checks that an agent has capacity left for a remittance of `amount` in `token`.
+pub fn check_agent_capacity(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<(), ContractError> {
+    if let Some(capacity) = get_agent_capacity_limit(env, agent, token) {
+        let exposure = get_agent_exposure(env, agent, token)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if exposure > capacity {
+            return Err(ContractError::AgentCapacityExceeded);
+        }
+    }
+    Ok(())
+}
+
+/// Adds an assigned remittance to an agent's exposure, enforcing its capacity.
+pub fn add_agent_exposure(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<(), ContractError> {
+    check_agent_capacity(env, agent, token, amount)?;
+    let exposure = get_agent_exposure(env, agent, token)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_agent_exposure(env, agent, token, exposure);
+    Ok(())
+}
+
+/// Removes a settled or refunded remittance from an agent's exposure.
+///
+/// Remittances assigned before exposure was tracked are not counted, so the
+/// exposure is floored at zero rather than going negative.
+pub fn release_agent_exposure(env: &Env, agent: &Address, token: &Address, amount: i128) {
+    let exposure = get_agent_exposure(env, agent, token).saturating_sub(amount).max(0);
+    set_agent_exposure(env, agent, token, exposure);
+}
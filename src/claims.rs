@@ -0,0 +1,110 @@
+//! Open-claim remittances.
+//!
+//! An open-claim remittance is not assigned to an agent at creation. Instead
+//! it stores the SHA-256 hash of a one-time claim code that the sender shares
+//! with the recipient. Any registered settler agent can pay it out by
+//! presenting the preimage, which the recipient reads out at the counter.
+//!
+//! Presenting the code publishes it, so claims are commit-reveal: the agent
+//! first commits to `sha256(code ‖ agent)` and reveals the code in a later
+//! ledger. Another agent copying a revealed code has no earlier commitment
+//! bound to its own address and cannot take over the claim.
+//!
+//! Until claimed, the remittance's `agent` is the contract's own address, so
+//! unclaimed open claims are listed by `get_remittances_by_agent` for the
+//! contract address. Claiming assigns the presenting agent.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::ContractError;
+
+/// Storage keys for open-claim remittances.
+#[contracttype]
+#[derive(Clone)]
+pub enum ClaimKey {
+    /// SHA-256 hash of the claim code indexed by remittance ID (persistent storage)
+    ClaimHash(u64),
+    /// Agent's commitment to a claim code indexed by (remittance ID, agent) (persistent storage)
+    ClaimCommitment(u64, Address),
+}
+
+/// A claiming agent's commitment to a claim code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCommitment {
+    /// `sha256(claim_code ‖ agent)`
+    pub commitment: BytesN<32>,
+    /// Ledger sequence the commitment was recorded in
+    pub ledger: u32,
+}
+
+/// Gets the claim code hash of an open-claim remittance, if any
+pub fn get_claim_hash(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&ClaimKey::ClaimHash(remittance_id))
+}
+
+/// Stores the claim code hash of an open-claim remittance
+pub fn set_claim_hash(env: &Env, remittance_id: u64, claim_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&ClaimKey::ClaimHash(remittance_id), claim_hash);
+}
+
+/// Gets an agent's commitment to the claim code of an open-claim remittance, if any
+pub fn get_claim_commitment(env: &Env, remittance_id: u64, agent: &Address) -> Option<ClaimCommitment> {
+    env.storage()
+        .persistent()
+        .get(&ClaimKey::ClaimCommitment(remittance_id, agent.clone()))
+}
+
+/// Records an agent's commitment to the claim code of an open-claim remittance
+pub fn set_claim_commitment(env: &Env, remittance_id: u64, agent: &Address, commitment: &BytesN<32>) {
+    let record = ClaimCommitment {
+        commitment: commitment.clone(),
+        ledger: env.ledger().sequence(),
+    };
+    env.storage()
+        .persistent()
+        .set(&ClaimKey::ClaimCommitment(remittance_id, agent.clone()), &record);
+}
+
+/// Computes the commitment binding a claim code to the agent presenting it.
+pub fn compute_claim_commitment(env: &Env, claim_code: &Bytes, agent: &Address) -> BytesN<32> {
+    let mut buf = claim_code.clone();
+    buf.append(&agent.clone().to_xdr(env));
+    env.crypto().sha256(&buf).into()
+}
+
+/// Checks a claim code presented by an agent.
+///
+/// The code must hash to the stored claim hash, and the agent must have
+/// committed to it in an earlier ledger.
+///
+/// # Returns
+///
+/// * `Ok(())` - The code matches and the agent committed to it beforehand
+/// * `Err(ContractError::InvalidClaimCode)` - The remittance is not an open claim, or the code does not match the claim hash or the agent's commitment
+/// * `Err(ContractError::ClaimNotCommitted)` - The agent has no commitment from an earlier ledger
+pub fn verify_claim_code(
+    env: &Env,
+    remittance_id: u64,
+    agent: &Address,
+    claim_code: &Bytes,
+) -> Result<(), ContractError> {
+    let claim_hash = get_claim_hash(env, remittance_id).ok_or(ContractError::InvalidClaimCode)?;
+    let presented: BytesN<32> = env.crypto().sha256(claim_code).into();
+    if presented != claim_hash {
+        return Err(ContractError::InvalidClaimCode);
+    }
+
+    let record = get_claim_commitment(env, remittance_id, agent).ok_or(ContractError::ClaimNotCommitted)?;
+    if record.ledger >= env.ledger().sequence() {
+        return Err(ContractError::ClaimNotCommitted);
+    }
+    if record.commitment != compute_claim_commitment(env, claim_code, agent) {
+        return Err(ContractError::InvalidClaimCode);
+    }
+    Ok(())
+}
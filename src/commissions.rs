@@ -0,0 +1,111 @@
+//! Agent commission on the platform fee.
+//!
+//! At settlement a share of the remittance's platform fee, in basis points,
+//! goes to the agent instead of `AccumulatedFees`. The admin sets a default
+//! rate, optional per-agent rates, and whether commission is paid together
+//! with the payout or accrued to a balance the agent claims later. The
+//! protocol fee is not affected.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+/// How agents receive their commission.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommissionPayout {
+    /// Transferred to the agent together with the payout
+    WithPayout,
+    /// Added to the agent's claimable balance
+    Accrue,
+}
+
+/// Storage keys for agent commissions.
+#[contracttype]
+#[derive(Clone)]
+pub enum CommissionKey {
+    /// Default commission rate in basis points (instance storage)
+    DefaultBps,
+    /// How commission is paid (instance storage)
+    Payout,
+    /// Commission rate override indexed by agent (persistent storage)
+    AgentBps(Address),
+    /// Claimable commission indexed by agent and token (persistent storage)
+    Balance(Address, Address),
+}
+
+/// Gets the default commission rate in basis points (defaults to 0)
+pub fn get_default_commission_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CommissionKey::DefaultBps)
+        .unwrap_or(0)
+}
+
+/// Sets the default commission rate in basis points
+pub fn set_default_commission_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&CommissionKey::DefaultBps, &bps);
+}
+
+/// Gets how commission is paid (defaults to with the payout)
+pub fn get_commission_payout(env: &Env) -> CommissionPayout {
+    env.storage()
+        .instance()
+        .get(&CommissionKey::Payout)
+        .unwrap_or(CommissionPayout::WithPayout)
+}
+
+/// Sets how commission is paid
+pub fn set_commission_payout(env: &Env, payout: CommissionPayout) {
+    env.storage().instance().set(&CommissionKey::Payout, &payout);
+}
+
+/// Gets an agent's commission rate override, if any
+pub fn get_agent_commission_bps(env: &Env, agent: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&CommissionKey::AgentBps(agent.clone()))
+}
+
+/// Sets an agent's commission rate override, or removes it with `None`
+pub fn set_agent_commission_bps(env: &Env, agent: &Address, bps: Option<u32>) {
+    let key = CommissionKey::AgentBps(agent.clone());
+    match bps {
+        Some(bps) => env.storage().persistent().set(&key, &bps),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns the commission rate that applies to an agent
+pub fn get_commission_bps(env: &Env, agent: &Address) -> u32 {
+    get_agent_commission_bps(env, agent).unwrap_or_else(|| get_default_commission_bps(env))
+}
+
+/// Gets an agent's claimable commission in a token
+pub fn get_commission_balance(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CommissionKey::Balance(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets an agent's claimable commission in a token
+pub fn set_commission_balance(env: &Env, agent: &Address, token: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&CommissionKey::Balance(agent.clone(), token.clone()), &balance);
+}
+
+/// Adds commission to an agent's claimable balance.
+pub fn accrue_commission(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let balance = get_commission_balance(env, agent, token)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_commission_balance(env, agent, token, balance);
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Disputes over completed remittances.
+//!
+//! A sender who never received the cash can open a dispute for a limited
+//! window after the remittance completed, committing to off-chain evidence by
+//! hash. An address holding the `Arbitrator` role rules on it. Rulings in the
+//! sender's favour are paid either by clawing the platform's share of the fee
+//! back out of `AccumulatedFees` or by charging the payout the agent received
+//! to the agent's bond, which the contract already holds. An agent cannot
+//! withdraw unbonded collateral while a dispute against it is open.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+/// Default time after completion during which a sender can open a dispute (7 days)
+pub const DEFAULT_DISPUTE_WINDOW: u64 = 604800;
+
+/// Arbitrator ruling on a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeOutcome {
+    /// Claim rejected; no funds move
+    Rejected,
+    /// Platform's share of the fee refunded to the sender out of accumulated fees
+    FeeRefunded,
+    /// Payout the agent received is paid back to the sender out of the agent's bond
+    AgentCharged,
+}
+
+/// Lifecycle state of a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Awaiting an arbitrator ruling
+    Open,
+    /// An arbitrator has ruled
+    Resolved(DisputeOutcome),
+}
+
+/// Dispute record for a completed remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    /// ID of the disputed remittance
+    pub remittance_id: u64,
+    /// Sender who opened the dispute
+    pub sender: Address,
+    /// Agent that confirmed the payout
+    pub agent: Address,
+    /// Hash of the off-chain evidence submitted by the sender
+    pub evidence_hash: BytesN<32>,
+    /// Timestamp at which the dispute was opened
+    pub opened_at: u64,
+    /// Current dispute status, including the ruling once resolved
+    pub status: DisputeStatus,
+    /// Arbitrator that ruled, once resolved
+    pub arbitrator: Option<Address>,
+    /// Amount returned to the sender by the ruling
+    pub refunded_amount: i128,
+    /// Timestamp of the ruling, once resolved
+    pub resolved_at: Option<u64>,
+}
+
+/// Payout facts recorded when a remittance completes, used to bound disputes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementRecord {
+    /// Timestamp at which the remittance completed
+    pub completed_at: u64,
+    /// Amount transferred to the agent
+    pub payout_amount: i128,
+    /// Part of the fee credited to the agent as commission
+    pub commission: i128,
+}
+
+/// Storage keys for the dispute subsystem.
+#[contracttype]
+#[derive(Clone)]
+pub enum DisputeKey {
+    /// Dispute window in seconds (instance storage)
+    Window,
+    /// Dispute record indexed by remittance ID (persistent storage)
+    Dispute(u64),
+    /// Settlement record indexed by remittance ID (persistent storage)
+    Settlement(u64),
+    /// Number of unresolved disputes against an agent (persistent storage)
+    AgentOpenDisputes(Address),
+}
+
+/// Gets the dispute window in seconds (defaults to 7 days)
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DisputeKey::Window)
+        .unwrap_or(DEFAULT_DISPUTE_WINDOW)
+}
+
+/// Sets the dispute window in seconds
+pub fn set_dispute_window(env: &Env, window_seconds: u64) {
+    env.storage().instance().set(&DisputeKey::Window, &window_seconds);
+}
+
+/// Gets the number of unresolved disputes against an agent
+pub fn get_open_dispute_count(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DisputeKey::AgentOpenDisputes(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets the number of unresolved disputes against an agent
+pub fn set_open_dispute_count(env: &Env, agent: &Address, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&DisputeKey::AgentOpenDisputes(agent.clone()), &count);
+}
+
+/// Gets the dispute opened for a remittance, if any
+pub fn get_dispute(env: &Env, remittance_id: u64) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get(&DisputeKey::Dispute(remittance_id))
+}
+
+/// Stores a dispute record under its remittance ID
+pub fn set_dispute(env: &Env, dispute: &Dispute) {
+    env.storage()
+        .persistent()
+        .set(&DisputeKey::Dispute(dispute.remittance_id), dispute);
+}
+
+/// Gets the settlement record of a completed remittance, if any
+pub fn get_settlement_record(env: &Env, remittance_id: u64) -> Option<SettlementRecord> {
+    env.storage()
+        .persistent()
+        .get(&DisputeKey::Settlement(remittance_id))
+}
+
+/// Records when a remittance completed, how much the agent was paid and the
+/// commission it earned on the fee.
+pub fn record_settlement(env: &Env, remittance_id: u64, payout_amount: i128, commission: i128) {
+    let record = SettlementRecord {
+        completed_at: env.ledger().timestamp(),
+        payout_amount,
+        commission,
+    };
+    env.storage()
+        .persistent()
+        .set(&DisputeKey::Settlement(remittance_id), &record);
+}
@@ -321,6 +321,380 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Medium,
             ),
+            
+            // Idempotency Errors (41-42)
+            ContractError::IdempotencyConflict => (
+                41,
+                SorobanString::from_str(env, "Idempotency key reused with a different request"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidIdempotencyKey => (
+                42,
+                SorobanString::from_str(env, "Idempotency key is empty, too long or malformed"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Proof Validation Errors (43-44)
+            ContractError::MissingProof => (
+                43,
+                SorobanString::from_str(env, "Settlement requires an oracle proof"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::OracleNotRegistered => (
+                44,
+                SorobanString::from_str(env, "Proof signer is not a registered oracle"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Corridor Errors (45)
+            ContractError::InvalidCorridor => (
+                45,
+                SorobanString::from_str(env, "Corridor currency and country are required"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Query Errors (46)
+            ContractError::InvalidPagination => (
+                46,
+                SorobanString::from_str(env, "Page and page size must be at least 1"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Acceptance Errors (47)
+            ContractError::RemittanceAccepted => (
+                47,
+                SorobanString::from_str(env, "Remittance is locked by an active agent acceptance"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Dispute Errors (48-53)
+            ContractError::DisputeWindowClosed => (
+                48,
+                SorobanString::from_str(env, "Dispute window has closed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DisputeAlreadyExists => (
+                49,
+                SorobanString::from_str(env, "Dispute already exists for this remittance"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DisputeNotFound => (
+                50,
+                SorobanString::from_str(env, "Dispute not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DisputeAlreadyResolved => (
+                51,
+                SorobanString::from_str(env, "Dispute has already been resolved"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InsufficientFees => (
+                52,
+                SorobanString::from_str(env, "Accumulated fees are insufficient for the refund"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InsufficientAgentAllowance => (
+                53,
+                SorobanString::from_str(env, "Agent allowance is insufficient for the charge"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Claim Errors (54)
+            ContractError::InvalidClaimCode => (
+                54,
+                SorobanString::from_str(env, "Invalid claim code"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            
+            // HTLC Errors (55-58)
+            ContractError::InvalidPreimage => (
+                55,
+                SorobanString::from_str(env, "Invalid preimage"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::PreimageRequired => (
+                56,
+                SorobanString::from_str(env, "Preimage required to settle"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidTimelock => (
+                57,
+                SorobanString::from_str(env, "Timelock must be in the future"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TimelockActive => (
+                58,
+                SorobanString::from_str(env, "Timelock has not expired"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Standing Order Errors (59-60)
+            ContractError::StandingOrderNotFound => (
+                59,
+                SorobanString::from_str(env, "Standing order not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidSchedule => (
+                60,
+                SorobanString::from_str(env, "Invalid standing order schedule"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Token Errors (61)
+            ContractError::TokenMismatch => (
+                61,
+                SorobanString::from_str(env, "Remittance token does not match"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Quote Errors (62-65)
+            ContractError::QuoteNotFound => (
+                62,
+                SorobanString::from_str(env, "Quote not found"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::QuoteExpired => (
+                63,
+                SorobanString::from_str(env, "Quote has expired"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::QuoteAlreadyUsed => (
+                64,
+                SorobanString::from_str(env, "Quote already used"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InvalidFxRate => (
+                65,
+                SorobanString::from_str(env, "FX rate must be positive"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // FX Rate Errors (66-68)
+            ContractError::RateNotFound => (
+                66,
+                SorobanString::from_str(env, "FX rate not found"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::StaleRate => (
+                67,
+                SorobanString::from_str(env, "FX rate is stale"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InvalidRateTimestamp => (
+                68,
+                SorobanString::from_str(env, "Invalid FX rate timestamp"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // KYC Errors (69-72)
+            ContractError::KycTransactionLimitExceeded => (
+                69,
+                SorobanString::from_str(env, "KYC per-transaction limit exceeded"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::KycDailyLimitExceeded => (
+                70,
+                SorobanString::from_str(env, "KYC daily limit exceeded"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::KycMonthlyLimitExceeded => (
+                71,
+                SorobanString::from_str(env, "KYC monthly limit exceeded"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InvalidKycLimits => (
+                72,
+                SorobanString::from_str(env, "Invalid KYC tier limits"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Sanctions Errors (73)
+            ContractError::AddressSanctioned => (
+                73,
+                SorobanString::from_str(env, "Address is sanctioned"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::High,
+            ),
+            
+            // Agent Bond Errors (74-77)
+            ContractError::AgentBondRequired => (
+                74,
+                SorobanString::from_str(env, "Agent bond below required minimum"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InsufficientBond => (
+                75,
+                SorobanString::from_str(env, "Agent bond cannot cover amount"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::UnbondingNotReady => (
+                76,
+                SorobanString::from_str(env, "Unbonded collateral not yet withdrawable"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InsurancePoolNotSet => (
+                77,
+                SorobanString::from_str(env, "Insurance pool not configured"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Agent Capacity Errors (78)
+            ContractError::AgentCapacityExceeded => (
+                78,
+                SorobanString::from_str(env, "Agent capacity exceeded"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Agent Profile Errors (79-80)
+            ContractError::InvalidAgentProfile => (
+                79,
+                SorobanString::from_str(env, "Invalid agent profile"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AgentCorridorNotServed => (
+                80,
+                SorobanString::from_str(env, "Agent does not serve corridor"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Routing Errors (81)
+            ContractError::NoEligibleAgent => (
+                81,
+                SorobanString::from_str(env, "No eligible agent for corridor"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Operator Errors (82-84)
+            ContractError::OperatorNotFound => (
+                82,
+                SorobanString::from_str(env, "Operator not granted by agent"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::OperatorExpired => (
+                83,
+                SorobanString::from_str(env, "Operator grant expired"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::OperatorLimitExceeded => (
+                84,
+                SorobanString::from_str(env, "Operator settlement limit exceeded"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Integrator Errors (85)
+            ContractError::IntegratorNotRegistered => (
+                85,
+                SorobanString::from_str(env, "Integrator is not registered"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            
+            // Fee Schedule Errors (86-87)
+            ContractError::InvalidFeeTiers => (
+                86,
+                SorobanString::from_str(env, "Invalid fee tier schedule"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidFeeLimits => (
+                87,
+                SorobanString::from_str(env, "Invalid fee limits"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Claim Commitment Errors (88-89)
+            ContractError::ClaimCodeRequired => (
+                88,
+                SorobanString::from_str(env, "Claim code required"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ClaimNotCommitted => (
+                89,
+                SorobanString::from_str(env, "Claim not committed"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Bond Delay Errors (90)
+            ContractError::InvalidUnbondingDelay => (
+                90,
+                SorobanString::from_str(env, "Unbonding delay must exceed dispute window"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Fee Bound Errors (91)
+            ContractError::FeesExceedAmount => (
+                91,
+                SorobanString::from_str(env, "Fees exceed amount"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Quote Rate Errors (92-94)
+            ContractError::InsufficientRateSources => (
+                92,
+                SorobanString::from_str(env, "Not enough FX rate sources"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::FxRateOutOfTolerance => (
+                93,
+                SorobanString::from_str(env, "Quoted FX rate outside tolerance of median"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::TokenCurrencyNotSet => (
+                94,
+                SorobanString::from_str(env, "Token currency not configured"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
         }
     }
     
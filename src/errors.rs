@@ -6,7 +6,9 @@
 
 use soroban_sdk::contracterror;
 
-#[contracterror]
+// The contract spec caps error enums at 50 cases, so the enum is kept out of
+// the spec. Codes are stable and documented here and in `error_handler.rs`.
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ContractError {
@@ -211,4 +213,320 @@ pub enum ContractError {
     /// Invalid escrow status for this operation.
     /// Cause: Attempting operation on escrow in wrong status.
     InvalidEscrowStatus = 40,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Idempotency Errors (41-42)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Idempotency key already used by the same sender with a different request payload.
+    /// Cause: Retrying create_remittance_idempotent from the same sender with the same key but a different agent, amount, token, currency, country or expiry.
+    IdempotencyConflict = 41,
+    
+    /// Idempotency key is empty, too long or has invalid characters.
+    /// Cause: Providing an idempotency key of zero length, longer than 255 bytes, or with characters other than letters, digits, `-` and `_`.
+    InvalidIdempotencyKey = 42,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Proof Validation Errors (43-44)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Settlement requires an oracle proof but none was supplied.
+    /// Cause: Calling confirm_payout on a remittance or agent configured to require proof.
+    MissingProof = 43,
+    
+    /// Proof was signed by a key that is not a registered oracle.
+    /// Cause: Supplying a proof whose public key is not in the oracle registry.
+    OracleNotRegistered = 44,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Corridor Errors (45)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Corridor currency or country is missing.
+    /// Cause: Creating a remittance or configuring a limit with an empty currency or country.
+    InvalidCorridor = 45,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Query Errors (46)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Page number or page size is invalid.
+    /// Cause: Requesting page 0 or a page size of 0 from a history query.
+    InvalidPagination = 46,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Acceptance Errors (47)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Remittance is locked by an active agent acceptance.
+    /// Cause: Cancelling an accepted remittance, or releasing an acceptance before it lapsed.
+    RemittanceAccepted = 47,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Dispute Errors (48-53)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Dispute window for the remittance has closed.
+    /// Cause: Opening a dispute on a remittance that is not completed or completed too long ago.
+    DisputeWindowClosed = 48,
+    
+    /// A dispute was already opened for the remittance.
+    /// Cause: Opening a second dispute for the same remittance.
+    DisputeAlreadyExists = 49,
+    
+    /// No dispute exists for the remittance.
+    /// Cause: Querying or resolving a dispute that was never opened.
+    DisputeNotFound = 50,
+    
+    /// Dispute has already been resolved.
+    /// Cause: Ruling on a dispute a second time.
+    DisputeAlreadyResolved = 51,
+    
+    /// Accumulated fees cannot cover a fee refund.
+    /// Cause: Refunding a fee after accumulated fees were withdrawn.
+    InsufficientFees = 52,
+    
+    /// Agent has not approved enough funds to cover a charge.
+    /// Cause: Charging an agent whose token allowance to the contract is below the payout.
+    InsufficientAgentAllowance = 53,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Claim Errors (54)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Claim code does not match the remittance.
+    /// Cause: Presenting a wrong claim code, or claiming a remittance that is not an open claim.
+    InvalidClaimCode = 54,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // HTLC Errors (55-58)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Preimage does not hash to the remittance hashlock.
+    /// Cause: Settling a hash time-locked remittance with a wrong preimage.
+    InvalidPreimage = 55,
+    
+    /// Remittance can only be settled by revealing its preimage.
+    /// Cause: Settling a hash time-locked remittance through confirm_payout or batch settlement.
+    PreimageRequired = 56,
+    
+    /// Timelock is not in the future.
+    /// Cause: Creating a hash time-locked remittance whose timelock has already passed.
+    InvalidTimelock = 57,
+    
+    /// Timelock of the remittance has not expired yet.
+    /// Cause: Cancelling or refunding a hash time-locked remittance before its timelock.
+    TimelockActive = 58,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Standing Order Errors (59-60)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Standing order does not exist.
+    /// Cause: Querying or updating a standing order ID that was never created.
+    StandingOrderNotFound = 59,
+    
+    /// Standing order schedule is invalid.
+    /// Cause: Zero interval or installment count, or an end date before the first due date.
+    InvalidSchedule = 60,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Token Errors (61)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Remittance is denominated in a different token.
+    /// Cause: Batch settling remittances in a token other than the batch token.
+    TokenMismatch = 61,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Quote Errors (62-65)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Quote does not exist.
+    /// Cause: Querying or consuming a non-existent quote ID.
+    QuoteNotFound = 62,
+    
+    /// Quote validity deadline has passed.
+    /// Cause: Creating a quote with a past deadline or consuming it after the deadline.
+    QuoteExpired = 63,
+    
+    /// Quote was already consumed by a remittance.
+    /// Cause: Creating a second remittance from the same quote, or issuing a second quote from the same oracle-signed hash.
+    QuoteAlreadyUsed = 64,
+    
+    /// FX rate is zero or negative.
+    /// Cause: Quoting with or posting a non-positive exchange rate.
+    InvalidFxRate = 65,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // FX Rate Errors (66-68)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// No rate has been posted for the currency pair.
+    /// Cause: Reading a pair no current rate feeder has posted to.
+    RateNotFound = 66,
+    
+    /// Rate is older than the maximum rate age.
+    /// Cause: Posting an old rate, or reading a pair whose rates have all aged out.
+    StaleRate = 67,
+    
+    /// Rate timestamp is in the future or older than the feeder's last post.
+    /// Cause: Posting a rate with a timestamp ahead of the ledger or out of order.
+    InvalidRateTimestamp = 68,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // KYC Errors (69-72)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Amount exceeds the sender's KYC tier per-transaction cap.
+    /// Cause: Sending more in one remittance or escrow than the sender's tier allows.
+    KycTransactionLimitExceeded = 69,
+    
+    /// Amount exceeds the sender's KYC tier daily cap.
+    /// Cause: Sender's total over the last 24 hours would exceed the tier's daily cap.
+    KycDailyLimitExceeded = 70,
+    
+    /// Amount exceeds the sender's KYC tier monthly cap.
+    /// Cause: Sender's total over the last 30 days would exceed the tier's monthly cap.
+    KycMonthlyLimitExceeded = 71,
+    
+    /// KYC tier caps are negative or inconsistent.
+    /// Cause: Setting caps where the per-transaction cap exceeds the daily cap or the daily cap exceeds the monthly cap.
+    InvalidKycLimits = 72,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Sanctions Errors (73)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Address is on the sanctions denylist.
+    /// Cause: Moving funds to, from or on behalf of a listed address, or releasing a frozen remittance whose party is still listed.
+    AddressSanctioned = 73,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Bond Errors (74-77)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent's active bond is below the required minimum.
+    /// Cause: Assigning a remittance above the bond threshold to an agent whose active bond is below the configured minimum.
+    AgentBondRequired = 74,
+    
+    /// Agent bond cannot cover the requested amount.
+    /// Cause: Unbonding more than the active bond, or slashing more than the agent's remaining collateral.
+    InsufficientBond = 75,
+    
+    /// No unbonded collateral can be withdrawn yet.
+    /// Cause: Withdrawing before the unbonding delay has passed, with nothing unbonding, or while a dispute against the agent is open.
+    UnbondingNotReady = 76,
+    
+    /// No insurance pool is configured.
+    /// Cause: Routing slashed collateral to the insurance pool before the admin configured one.
+    InsurancePoolNotSet = 77,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Capacity Errors (78)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Assignment would exceed the agent's declared capacity.
+    /// Cause: Creating or claiming a remittance that would take the agent's outstanding exposure above its declared capacity.
+    AgentCapacityExceeded = 78,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Profile Errors (79-80)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent profile is empty or too large.
+    /// Cause: Setting a profile without countries or currencies, with an empty entry, or with more than MAX_PROFILE_ENTRIES entries in a list.
+    InvalidAgentProfile = 79,
+    
+    /// Agent does not serve the remittance corridor.
+    /// Cause: Assigning a remittance to an agent whose profile lacks its currency or destination country.
+    AgentCorridorNotServed = 80,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Routing Errors (81)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// No eligible agent serves the corridor.
+    /// Cause: Routing a remittance to a corridor where every agent is suspended, sanctioned, under-bonded or at capacity, or no agent profile lists it.
+    NoEligibleAgent = 81,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Operator Errors (82-84)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent has not granted the operator settlement rights.
+    /// Cause: Confirming a payout as, or revoking, an operator the remittance's agent never granted.
+    OperatorNotFound = 82,
+    
+    /// Operator grant has expired.
+    /// Cause: Confirming a payout after the grant's expiry, or granting with an expiry already in the past.
+    OperatorExpired = 83,
+    
+    /// Remittance amount exceeds the operator's limit.
+    /// Cause: Confirming a payout as an operator for a remittance above the grant's maximum amount.
+    OperatorLimitExceeded = 84,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Integrator Errors (85)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Integrator is not registered.
+    /// Cause: Creating a remittance through or removing an unregistered integrator, or withdrawing fees for one that was never registered.
+    IntegratorNotRegistered = 85,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Schedule Errors (86-87)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Tiered fee schedule is malformed.
+    /// Cause: Setting a tiered fee strategy with no tiers, too many tiers, non-ascending or non-positive upper bounds, or a negative flat component.
+    InvalidFeeTiers = 86,
+    
+    /// Fee limits are inconsistent.
+    /// Cause: Setting a negative minimum or maximum fee, or a maximum below the minimum.
+    InvalidFeeLimits = 87,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Claim Commitment Errors (88-89)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Remittance can only be paid out through its claim code.
+    /// Cause: Settling an open-claim remittance through batch settlement.
+    ClaimCodeRequired = 88,
+    
+    /// Agent has not committed to the claim code in an earlier ledger.
+    /// Cause: Claiming an open-claim remittance without a prior commit_claim, or in the same ledger as the commitment.
+    ClaimNotCommitted = 89,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Bond Delay Errors (90)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Unbonding delay does not outlast the dispute window.
+    /// Cause: Setting an unbonding delay no longer than the dispute window, or a dispute window no shorter than the unbonding delay.
+    InvalidUnbondingDelay = 90,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Bound Errors (91)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Fees exceed the amount they are charged on.
+    /// Cause: Creating or quoting a remittance whose platform and protocol fees exceed its amount, or settling one whose fees exceed what is left to pay out.
+    FeesExceedAmount = 91,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Quote Rate Errors (92-94)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Too few fresh submissions to publish a rate.
+    /// Cause: Reading or quoting a pair with fewer fresh feeder submissions than the configured minimum.
+    InsufficientRateSources = 92,
+    
+    /// Quoted FX rate deviates too far from the published median.
+    /// Cause: Quoting a rate further from the median than the quote rate tolerance.
+    FxRateOutOfTolerance = 93,
+    
+    /// Token has no currency configured for rate lookups.
+    /// Cause: Quoting in a token the admin has not assigned a currency to.
+    TokenCurrencyNotSet = 94,
 }
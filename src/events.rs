@@ -4,7 +4,9 @@
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, String};
+
+use crate::{CommissionPayout, KycTier, RoutingPolicy, StandingOrderStatus};
 
 // ============================================================================
 // Event Schema Version
@@ -103,13 +105,13 @@ pub fn emit_remittance_created(
 ///
 /// * `env` - The contract execution environment
 /// * `remittance_id` - ID of the completed remittance
+/// * `sender` - Address of the sender who funded the remittance
 /// * `agent` - Address of the agent who received the payout
-/// * `amount` - Payout amount (after fee deduction)
 pub fn emit_remittance_completed(
     env: &Env,
     remittance_id: u64,
+    sender: Address,
     agent: Address,
-    amount: i128,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("complete")),
@@ -118,8 +120,8 @@ pub fn emit_remittance_completed(
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
+            sender,
             agent,
-            amount,
         ),
     );
 }
@@ -131,11 +133,15 @@ pub fn emit_remittance_completed(
 /// * `env` - The contract execution environment
 /// * `remittance_id` - ID of the cancelled remittance
 /// * `sender` - Address of the sender who received the refund
+/// * `agent` - Address of the agent the remittance was assigned to
+/// * `token` - Token that was refunded
 /// * `amount` - Refunded amount
 pub fn emit_remittance_cancelled(
     env: &Env,
     remittance_id: u64,
     sender: Address,
+    agent: Address,
+    token: Address,
     amount: i128,
 ) {
     env.events().publish(
@@ -146,181 +152,1217 @@ pub fn emit_remittance_cancelled(
             env.ledger().timestamp(),
             remittance_id,
             sender,
+            agent,
+            token,
             amount,
         ),
     );
 }
 
-// ── Agent Events ───────────────────────────────────────────────────
+/// Emits an event when an expired remittance is refunded to its sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the expired remittance
+/// * `sender` - Address of the sender who received the refund
+/// * `amount` - Refunded amount
+pub fn emit_remittance_expired(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("expired")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            amount,
+        ),
+    );
+}
 
-/// Emits an event when a new agent is registered.
+/// Emits an event when an agent redeems an open-claim remittance with its claim code.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the registered agent
-pub fn emit_agent_registered(env: &Env, agent: Address) {
+/// * `remittance_id` - ID of the claimed remittance
+/// * `agent` - Address of the agent that presented the claim code
+pub fn emit_remittance_claimed(env: &Env, remittance_id: u64, agent: Address) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("register")),
+        (symbol_short!("remit"), symbol_short!("claimed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
+            remittance_id,
             agent,
         ),
     );
 }
 
-/// Emits an event when an agent is removed.
+/// Emits an event when a remittance is routed to an automatically selected agent.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the removed agent
-pub fn emit_agent_removed(env: &Env, agent: Address) {
+/// * `remittance_id` - ID of the routed remittance
+/// * `agent` - Address of the selected agent
+/// * `policy` - Routing policy that selected the agent
+pub fn emit_remittance_routed(env: &Env, remittance_id: u64, agent: Address, policy: RoutingPolicy) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("removed")),
+        (symbol_short!("remit"), symbol_short!("routed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
+            remittance_id,
             agent,
+            policy,
         ),
     );
 }
 
-// ── Fee Events ─────────────────────────────────────────────────────
+/// Emits an event when an agent accepts a remittance for payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the accepted remittance
+/// * `agent` - Address of the accepting agent
+/// * `deadline` - Timestamp at which the acceptance lapses
+pub fn emit_remittance_accepted(env: &Env, remittance_id: u64, agent: Address, deadline: u64) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("accepted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            deadline,
+        ),
+    );
+}
 
-/// Emits an event when the platform fee is updated.
+/// Emits an event when a lapsed acceptance returns a remittance to Pending.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fee_bps` - New fee rate in basis points
-pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
+/// * `remittance_id` - ID of the released remittance
+/// * `agent` - Address of the agent whose acceptance lapsed
+pub fn emit_acceptance_lapsed(env: &Env, remittance_id: u64, agent: Address) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("updated")),
+        (symbol_short!("remit"), symbol_short!("lapsed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            fee_bps,
+            remittance_id,
+            agent,
         ),
     );
 }
 
-/// Emits an event when accumulated fees are withdrawn.
+/// Emits an event when an agent reports a failed payout and the sender is refunded.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `to` - Address that received the withdrawn fees
-/// * `amount` - Amount of fees withdrawn
-pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+/// * `remittance_id` - ID of the failed remittance
+/// * `sender` - Address of the sender who received the refund
+/// * `agent` - Address of the agent that reported the failure
+/// * `amount` - Refunded amount
+pub fn emit_remittance_failed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (symbol_short!("remit"), symbol_short!("failed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            to,
+            remittance_id,
+            sender,
+            agent,
             amount,
         ),
     );
 }
 
-// ── Settlement Events ──────────────────────────────────────────────
+// ── HTLC Events ────────────────────────────────────────────────────
 
-/// Emits a structured completion event when a settlement is finalized.
-///
-/// This event is emitted exactly once per completed settlement, after all state
-/// transitions are successfully committed. It includes sufficient identifiers to
-/// uniquely reference the finalized settlement.
-///
-/// # Guarantees
-///
-/// - **Exactly-Once Emission**: Event is emitted once and only once per settlement
-/// - **Post-Finalization**: Only emitted after all state changes are committed
-/// - **Unique Identification**: Includes remittance_id for unambiguous reference
-/// - **Deterministic**: Same settlement always produces same event
-/// - **No Re-entry**: Protected against duplicate emission on retries
+/// Emits an event when a hash time-locked remittance is created.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - Unique ID of the finalized settlement
-/// * `sender` - Address of the sender
-/// * `receiver` - Address of the receiver (agent)
-/// * `asset` - Address of the token contract (e.g., USDC)
-/// * `amount` - Settlement amount transferred
+/// * `remittance_id` - ID of the HTLC remittance
+/// * `hashlock` - SHA-256 hash the settling agent must open
+/// * `timelock` - Timestamp after which the remittance can only be refunded
+pub fn emit_htlc_created(env: &Env, remittance_id: u64, hashlock: BytesN<32>, timelock: u64) {
+    env.events().publish(
+        (symbol_short!("htlc"), symbol_short!("created")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            hashlock,
+            timelock,
+        ),
+    );
+}
+
+/// Emits an event when a hash time-locked remittance is settled, revealing its preimage.
 ///
-/// # Event Structure
+/// # Arguments
 ///
-/// Topic: `("settle", "complete")`
-/// Data: `(schema_version, ledger_sequence, timestamp, remittance_id, sender, receiver, asset, amount)`
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled HTLC remittance
+/// * `agent` - Address of the agent that settled it
+/// * `preimage` - Revealed preimage of the hashlock
+pub fn emit_htlc_settled(env: &Env, remittance_id: u64, agent: Address, preimage: Bytes) {
+    env.events().publish(
+        (symbol_short!("htlc"), symbol_short!("settled")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            preimage,
+        ),
+    );
+}
+
+// ── Standing Order Events ──────────────────────────────────────────
+
+/// Emits an event when a sender creates a standing order.
 ///
-/// # Usage
+/// # Arguments
 ///
-/// This function should only be called from `confirm_payout` after:
-/// 1. All validations pass
-/// 2. Token transfer completes
-/// 3. Fee accumulation succeeds
-/// 4. Status updated to Settled
-/// 5. Settlement hash set
-/// 6. Event emission flag checked
-pub fn emit_settlement_completed(
+/// * `env` - The contract execution environment
+/// * `order_id` - ID of the new standing order
+/// * `sender` - Address funding the installments
+/// * `agent` - Agent assigned to each installment
+/// * `amount` - Amount of each installment
+/// * `interval` - Seconds between installments
+pub fn emit_standing_order_created(
     env: &Env,
-    remittance_id: u64,
+    order_id: u64,
     sender: Address,
-    receiver: Address,
-    asset: Address,
+    agent: Address,
     amount: i128,
+    interval: u64,
 ) {
     env.events().publish(
-        (symbol_short!("settle"), symbol_short!("complete")),
+        (symbol_short!("order"), symbol_short!("created")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            remittance_id,
+            order_id,
             sender,
-            receiver,
-            asset,
+            agent,
             amount,
+            interval,
         ),
     );
 }
 
+/// Emits an event when a standing order is paused, resumed, cancelled or completed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `order_id` - ID of the standing order
+/// * `status` - New status of the order
+pub fn emit_standing_order_status(env: &Env, order_id: u64, status: StandingOrderStatus) {
+    env.events().publish(
+        (symbol_short!("order"), symbol_short!("status")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            order_id,
+            status,
+        ),
+    );
+}
 
-// ── Escrow Events ──────────────────────────────────────────────────
-
-/// Emits an event when escrow is created
-pub fn emit_escrow_created(env: &Env, transfer_id: u64, sender: Address, recipient: Address, amount: i128) {
+/// Emits an event when a due installment of a standing order creates a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `order_id` - ID of the standing order
+/// * `remittance_id` - ID of the remittance created for the installment
+/// * `remaining_count` - Installments left after this one
+pub fn emit_standing_order_executed(env: &Env, order_id: u64, remittance_id: u64, remaining_count: u32) {
     env.events().publish(
-        (symbol_short!("escrow"), symbol_short!("created")),
-        (SCHEMA_VERSION, env.ledger().sequence(), env.ledger().timestamp(), transfer_id, sender, recipient, amount),
+        (symbol_short!("order"), symbol_short!("executed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            order_id,
+            remittance_id,
+            remaining_count,
+        ),
     );
 }
 
-/// Emits an event when escrow funds are released
-pub fn emit_escrow_released(env: &Env, transfer_id: u64, recipient: Address, amount: i128) {
+/// Emits an event when a due installment is skipped because the sender cannot fund it.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `order_id` - ID of the standing order
+pub fn emit_standing_order_skipped(env: &Env, order_id: u64) {
     env.events().publish(
-        (symbol_short!("escrow"), symbol_short!("released")),
-        (SCHEMA_VERSION, env.ledger().sequence(), env.ledger().timestamp(), transfer_id, recipient, amount),
+        (symbol_short!("order"), symbol_short!("skipped")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            order_id,
+        ),
     );
 }
 
-/// Emits a settlement completed event with full transaction details.
-/// This event includes sender, recipient (agent), token address, and payout amount.
-pub fn emit_settlement_completed(
+// ── Quote Events ───────────────────────────────────────────────────
+
+/// Emits an event when a quote is locked for a sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `quote_id` - ID of the new quote
+/// * `sender` - Address the quote was issued to
+/// * `amount` - Quoted source amount
+/// * `dest_currency` - Currency the recipient is paid in
+/// * `dest_amount` - Amount the recipient receives in `dest_currency`
+/// * `valid_until` - Timestamp after which the quote can no longer be used
+pub fn emit_quote_created(
     env: &Env,
+    quote_id: u64,
     sender: Address,
-    recipient: Address,
-    token: Address,
     amount: i128,
+    dest_currency: String,
+    dest_amount: i128,
+    valid_until: u64,
 ) {
     env.events().publish(
-        (symbol_short!("settled"),),
-        (sender, recipient, token, amount),
+        (symbol_short!("quote"), symbol_short!("created")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            quote_id,
+            sender,
+            amount,
+            dest_currency,
+            dest_amount,
+            valid_until,
+        ),
+    );
+}
+
+/// Emits an event when a quote is consumed by a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `quote_id` - ID of the consumed quote
+/// * `remittance_id` - ID of the remittance created from it
+pub fn emit_quote_used(env: &Env, quote_id: u64, remittance_id: u64) {
+    env.events().publish(
+        (symbol_short!("quote"), symbol_short!("used")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            quote_id,
+            remittance_id,
+        ),
+    );
+}
+
+// ── FX Rate Events ─────────────────────────────────────────────────
+
+/// Emits an event when a rate feeder posts a price for a currency pair.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `base` - Base currency of the pair
+/// * `quote` - Quote currency of the pair
+/// * `feeder` - Address of the rate feeder
+/// * `rate` - Posted rate, scaled by `FX_RATE_SCALE`
+/// * `timestamp` - Timestamp at which the feeder observed the price
+pub fn emit_rate_posted(env: &Env, base: String, quote: String, feeder: Address, rate: i128, timestamp: u64) {
+    env.events().publish(
+        (symbol_short!("fx"), symbol_short!("posted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            base,
+            quote,
+            feeder,
+            rate,
+            timestamp,
+        ),
+    );
+}
+
+// ── KYC Events ─────────────────────────────────────────────────────
+
+/// Emits an event when a compliance officer records or clears a sender's KYC tier.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `address` - Sender whose record changed
+/// * `tier` - New tier (`KycTier::None` when the record was removed)
+/// * `expiry` - Timestamp after which the tier lapses (0 when removed)
+/// * `officer` - Compliance officer that made the change
+pub fn emit_kyc_updated(env: &Env, address: Address, tier: KycTier, expiry: u64, officer: Address) {
+    env.events().publish(
+        (symbol_short!("kyc"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            address,
+            tier,
+            expiry,
+            officer,
+        ),
+    );
+}
+
+/// Emits an event when the caps of a KYC tier change.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `tier` - Tier whose caps changed
+/// * `token` - Token the caps apply to
+/// * `officer` - Compliance officer that made the change
+pub fn emit_kyc_limits_updated(env: &Env, tier: KycTier, token: Address, officer: Address) {
+    env.events().publish(
+        (symbol_short!("kyc"), symbol_short!("limits")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            tier,
+            token,
+            officer,
+        ),
+    );
+}
+
+// ── Sanctions Events ───────────────────────────────────────────────
+
+/// Emits an event when a compliance officer adds an address to the denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `address` - Sanctioned address
+/// * `reason_code` - Compliance reason code
+/// * `officer` - Compliance officer that listed the address
+pub fn emit_address_denylisted(env: &Env, address: Address, reason_code: u32, officer: Address) {
+    env.events().publish(
+        (symbol_short!("sanction"), symbol_short!("listed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            address,
+            reason_code,
+            officer,
+        ),
+    );
+}
+
+/// Emits an event when a compliance officer removes an address from the denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `address` - Delisted address
+/// * `officer` - Compliance officer that delisted the address
+pub fn emit_address_delisted(env: &Env, address: Address, officer: Address) {
+    env.events().publish(
+        (symbol_short!("sanction"), symbol_short!("delisted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            address,
+            officer,
+        ),
+    );
+}
+
+/// Emits an event when a remittance involving a sanctioned address is frozen.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the frozen remittance
+/// * `party` - Sanctioned sender or agent of the remittance
+pub fn emit_remittance_frozen(env: &Env, remittance_id: u64, party: Address) {
+    env.events().publish(
+        (symbol_short!("sanction"), symbol_short!("frozen")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            party,
+        ),
+    );
+}
+
+/// Emits an event when a compliance officer releases a frozen remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the released remittance
+/// * `officer` - Compliance officer that released it
+pub fn emit_remittance_unfrozen(env: &Env, remittance_id: u64, officer: Address) {
+    env.events().publish(
+        (symbol_short!("sanction"), symbol_short!("unfrozen")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            officer,
+        ),
+    );
+}
+
+// ── Bond Events ────────────────────────────────────────────────────
+
+/// Emits an event when an agent posts collateral.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Bonded agent
+/// * `token` - Token the bond is held in
+/// * `amount` - Collateral posted
+/// * `bonded` - Active bond after posting
+pub fn emit_bond_posted(env: &Env, agent: Address, token: Address, amount: i128, bonded: i128) {
+    env.events().publish(
+        (symbol_short!("bond"), symbol_short!("posted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+            bonded,
+        ),
+    );
+}
+
+/// Emits an event when collateral starts unbonding.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Bonded agent
+/// * `token` - Token the bond is held in
+/// * `amount` - Collateral moved out of the active bond
+/// * `withdrawable_at` - Timestamp from which the unbonding collateral can be withdrawn
+pub fn emit_bond_unbonding(env: &Env, agent: Address, token: Address, amount: i128, withdrawable_at: u64) {
+    env.events().publish(
+        (symbol_short!("bond"), symbol_short!("unbonding")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+            withdrawable_at,
+        ),
+    );
+}
+
+/// Emits an event when an agent withdraws unbonded collateral.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Bonded agent
+/// * `token` - Token the bond is held in
+/// * `amount` - Collateral returned to the agent
+pub fn emit_bond_withdrawn(env: &Env, agent: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("bond"), symbol_short!("withdrawn")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when an agent's bond is slashed over a disputed remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the disputed remittance
+/// * `agent` - Slashed agent
+/// * `recipient` - Sender or insurance pool receiving the collateral
+/// * `amount` - Collateral slashed
+/// * `slasher` - Arbitrator or admin that slashed the bond
+pub fn emit_bond_slashed(env: &Env, remittance_id: u64, agent: Address, recipient: Address, amount: i128, slasher: Address) {
+    env.events().publish(
+        (symbol_short!("bond"), symbol_short!("slashed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            recipient,
+            amount,
+            slasher,
+        ),
+    );
+}
+
+// ── Dispute Events ─────────────────────────────────────────────────
+
+/// Emits an event when a sender opens a dispute on a completed remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the disputed remittance
+/// * `sender` - Address of the sender who opened the dispute
+/// * `agent` - Address of the agent that confirmed the payout
+/// * `evidence_hash` - Hash of the off-chain evidence
+pub fn emit_dispute_opened(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    evidence_hash: BytesN<32>,
+) {
+    env.events().publish(
+        (symbol_short!("dispute"), symbol_short!("opened")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            agent,
+            evidence_hash,
+        ),
+    );
+}
+
+/// Emits an event when an arbitrator rules on a dispute.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the disputed remittance
+/// * `arbitrator` - Address of the ruling arbitrator
+/// * `outcome` - Ruling
+/// * `refunded_amount` - Amount returned to the sender
+pub fn emit_dispute_resolved(
+    env: &Env,
+    remittance_id: u64,
+    arbitrator: Address,
+    outcome: crate::DisputeOutcome,
+    refunded_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("dispute"), symbol_short!("resolved")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            arbitrator,
+            outcome,
+            refunded_amount,
+        ),
+    );
+}
+
+/// Emits an event when a dispute ruling moves funds to the sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the disputed remittance
+/// * `from` - Source of the refund (the contract for fee clawbacks, or the agent)
+/// * `to` - Sender receiving the refund
+/// * `amount` - Refunded amount
+pub fn emit_dispute_refund(env: &Env, remittance_id: u64, from: Address, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("dispute"), symbol_short!("refund")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            from,
+            to,
+            amount,
+        ),
+    );
+}
+
+// ── Agent Events ───────────────────────────────────────────────────
+
+/// Emits an event when a new agent is registered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the registered agent
+/// * `admin` - Admin who registered the agent
+pub fn emit_agent_registered(env: &Env, agent: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("register")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an agent is removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the removed agent
+/// * `admin` - Admin who removed the agent
+pub fn emit_agent_removed(env: &Env, agent: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an agent's capacity is declared or removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent whose capacity changed
+/// * `token` - Token the capacity applies to
+/// * `capacity` - New maximum exposure, or `None` if unlimited
+/// * `caller` - Admin or agent that declared it
+pub fn emit_agent_capacity_updated(env: &Env, agent: Address, token: Address, capacity: Option<i128>, caller: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("capacity")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            capacity,
+            caller,
+        ),
+    );
+}
+
+/// Emits an event when the admin sets an agent's service profile.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent whose profile changed
+/// * `admin` - Admin that set the profile
+pub fn emit_agent_profile_updated(env: &Env, agent: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("profile")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the admin suspends an agent from routing.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Suspended agent
+/// * `admin` - Admin that suspended the agent
+pub fn emit_agent_suspended(env: &Env, agent: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("suspended")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the admin lifts an agent's routing suspension.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Reinstated agent
+/// * `admin` - Admin that reinstated the agent
+pub fn emit_agent_reinstated(env: &Env, agent: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("reinstate")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an agent grants an operator settlement rights.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent granting the rights
+/// * `operator` - Operator receiving them
+/// * `max_amount` - Largest remittance amount the operator can settle
+/// * `expiry` - Timestamp after which the grant no longer applies, if any
+pub fn emit_operator_added(env: &Env, agent: Address, operator: Address, max_amount: i128, expiry: Option<u64>) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            operator,
+            max_amount,
+            expiry,
+        ),
+    );
+}
+
+/// Emits an event when an agent revokes an operator's settlement rights.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent revoking the rights
+/// * `operator` - Operator losing them
+pub fn emit_operator_revoked(env: &Env, agent: Address, operator: Address) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("revoked")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            operator,
+        ),
+    );
+}
+
+/// Emits an event when an operator confirms a payout on an agent's behalf.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled remittance
+/// * `agent` - Agent that received the payout
+/// * `operator` - Operator that confirmed it
+pub fn emit_operator_settled(env: &Env, remittance_id: u64, agent: Address, operator: Address) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("settled")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            operator,
+        ),
+    );
+}
+
+// ── Oracle Events ──────────────────────────────────────────────────
+
+/// Emits an event when an oracle public key is registered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `oracle` - Ed25519 public key of the registered oracle
+pub fn emit_oracle_registered(env: &Env, oracle: BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("register")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            oracle,
+        ),
+    );
+}
+
+/// Emits an event when an oracle public key is removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `oracle` - Ed25519 public key of the removed oracle
+pub fn emit_oracle_removed(env: &Env, oracle: BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            oracle,
+        ),
+    );
+}
+
+/// Emits an event when an oracle proof is accepted for a settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance being settled
+/// * `oracle` - Ed25519 public key of the oracle that signed the proof
+pub fn emit_proof_verified(env: &Env, remittance_id: u64, oracle: BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("proof"), symbol_short!("verified")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            oracle,
+        ),
+    );
+}
+
+// ── Fee Events ─────────────────────────────────────────────────────
+
+/// Emits an event when the platform fee is updated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - New fee rate in basis points
+pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+        ),
+    );
+}
+
+/// Emits an event when a corridor's daily send limit is set or removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `currency` - Payout currency of the corridor
+/// * `country` - Destination country of the corridor
+/// * `limit` - New limit (0 when the limit was removed)
+pub fn emit_daily_limit_updated(env: &Env, currency: String, country: String, limit: i128) {
+    env.events().publish(
+        (symbol_short!("limit"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            currency,
+            country,
+            limit,
+        ),
+    );
+}
+
+/// Emits an event when accumulated fees are withdrawn.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who withdrew the fees
+/// * `to` - Address that received the withdrawn fees
+/// * `token` - Token withdrawn
+/// * `amount` - Amount of fees withdrawn
+pub fn emit_fees_withdrawn(env: &Env, admin: Address, to: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            to,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when an agent earns commission on a settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Settled remittance
+/// * `agent` - Agent earning the commission
+/// * `token` - Token the commission is denominated in
+/// * `amount` - Commission carved out of the platform fee
+/// * `payout` - Whether it was paid with the payout or accrued
+pub fn emit_commission_earned(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    token: Address,
+    amount: i128,
+    payout: CommissionPayout,
+) {
+    env.events().publish(
+        (symbol_short!("comm"), symbol_short!("earned")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            token,
+            amount,
+            payout,
+        ),
+    );
+}
+
+/// Emits an event when an agent claims accrued commission.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent claiming the commission
+/// * `token` - Token claimed
+/// * `amount` - Commission claimed
+pub fn emit_commission_claimed(env: &Env, agent: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("comm"), symbol_short!("claimed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when an integrator is registered or its terms change.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `fee_bps` - Fee charged on top of remittances in basis points
+/// * `withdrawal_address` - Address receiving fee withdrawals
+pub fn emit_integrator_updated(env: &Env, integrator: Address, fee_bps: u32, withdrawal_address: Address) {
+    env.events().publish(
+        (symbol_short!("integr"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            integrator,
+            fee_bps,
+            withdrawal_address,
+        ),
+    );
+}
+
+/// Emits an event when an integrator is deregistered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+pub fn emit_integrator_removed(env: &Env, integrator: Address) {
+    env.events().publish(
+        (symbol_short!("integr"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            integrator,
+        ),
+    );
+}
+
+/// Emits an event when an integrator withdraws its accumulated fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `to` - Withdrawal address that received the fees
+/// * `token` - Token withdrawn
+/// * `amount` - Amount of fees withdrawn
+pub fn emit_integrator_fees_withdrawn(env: &Env, integrator: Address, to: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("integr"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            integrator,
+            to,
+            token,
+            amount,
+        ),
+    );
+}
+
+// ── Settlement Events ──────────────────────────────────────────────
+
+/// Emits a structured completion event when a settlement is finalized.
+///
+/// This event is emitted exactly once per completed settlement, after all state
+/// transitions are successfully committed. It includes sufficient identifiers to
+/// uniquely reference the finalized settlement.
+///
+/// # Guarantees
+///
+/// - **Exactly-Once Emission**: Event is emitted once and only once per settlement
+/// - **Post-Finalization**: Only emitted after all state changes are committed
+/// - **Unique Identification**: Includes remittance_id for unambiguous reference
+/// - **Deterministic**: Same settlement always produces same event
+/// - **No Re-entry**: Protected against duplicate emission on retries
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Unique ID of the finalized settlement
+/// * `sender` - Address of the sender
+/// * `receiver` - Address of the receiver (agent)
+/// * `asset` - Address of the token contract (e.g., USDC)
+/// * `amount` - Settlement amount transferred
+///
+/// # Event Structure
+///
+/// Topic: `("settle", "complete")`
+/// Data: `(schema_version, ledger_sequence, timestamp, remittance_id, sender, receiver, asset, amount)`
+///
+/// # Usage
+///
+/// This function should only be called from `confirm_payout` after:
+/// 1. All validations pass
+/// 2. Token transfer completes
+/// 3. Fee accumulation succeeds
+/// 4. Status updated to Settled
+/// 5. Settlement hash set
+/// 6. Event emission flag checked
+pub fn emit_settlement_completed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    receiver: Address,
+    asset: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("complete")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            receiver,
+            asset,
+            amount,
+        ),
+    );
+}
+
+
+// ── Escrow Events ──────────────────────────────────────────────────
+
+/// Emits an event when escrow is created
+pub fn emit_escrow_created(env: &Env, transfer_id: u64, sender: Address, recipient: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("created")),
+        (SCHEMA_VERSION, env.ledger().sequence(), env.ledger().timestamp(), transfer_id, sender, recipient, amount),
+    );
+}
+
+/// Emits an event when escrow funds are released
+pub fn emit_escrow_released(env: &Env, transfer_id: u64, recipient: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("released")),
+        (SCHEMA_VERSION, env.ledger().sequence(), env.ledger().timestamp(), transfer_id, recipient, amount),
+    );
+}
+
+/// Emits an event when escrowed funds are refunded to the sender
+pub fn emit_escrow_refunded(env: &Env, transfer_id: u64, sender: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("refunded")),
+        (SCHEMA_VERSION, env.ledger().sequence(), env.ledger().timestamp(), transfer_id, sender, amount),
     );
 }
@@ -4,9 +4,64 @@
 //! - Percentage: Fee based on percentage of amount (basis points)
 //! - Flat: Fixed fee regardless of amount
 //! - Dynamic: Fee varies based on amount tiers
+//! - Tiered: Fee follows an admin-configured schedule of amount tiers
+//!
+//! Any strategy can be clamped to a minimum and maximum fee. Corridors and
+//! tokens can have their own clamped strategy; a remittance uses its
+//! corridor's schedule, then its token's, then the global strategy and limits.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use crate::{get_fee_strategy, ContractError};
+
+/// Maximum number of tiers in a tiered fee schedule
+pub const MAX_FEE_TIERS: u32 = 16;
+
+/// One tier of a tiered fee schedule.
+///
+/// An amount falls in the first tier whose `upper_bound` it does not exceed.
+/// Amounts above the last bound use the last tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    /// Largest amount the tier applies to (inclusive)
+    pub upper_bound: i128,
+    /// Percentage component in basis points
+    pub bps: u32,
+    /// Flat component added to the percentage
+    pub flat_component: i128,
+}
 
-use soroban_sdk::{contracttype, Env};
-use crate::ContractError;
+/// Minimum and maximum fee a strategy's result is clamped to.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeeLimits {
+    /// Smallest fee charged, if any
+    pub min_fee: Option<i128>,
+    /// Largest fee charged, if any
+    pub max_fee: Option<i128>,
+}
+
+/// A fee strategy together with the limits its result is clamped to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSchedule {
+    /// Strategy calculating the unclamped fee
+    pub strategy: FeeStrategy,
+    /// Limits the fee is clamped to
+    pub limits: FeeLimits,
+}
+
+/// Storage keys for fee limits and per-corridor and per-token schedules.
+#[contracttype]
+#[derive(Clone)]
+pub enum FeeKey {
+    /// Limits applied to the global fee strategy (instance storage)
+    GlobalLimits,
+    /// Schedule indexed by currency and country (persistent storage)
+    Corridor(String, String),
+    /// Schedule indexed by token (persistent storage)
+    Token(Address),
+}
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,10 +72,12 @@ pub enum FeeStrategy {
     Flat(i128),
     /// Dynamic tiered fee: (threshold, fee_bps)
     Dynamic(u32),
+    /// Configured schedule of tiers, ascending by upper bound
+    Tiered(Vec<FeeTier>),
 }
 
 /// Calculate fee based on configured strategy
-pub fn calculate_fee(env: &Env, strategy: &FeeStrategy, amount: i128) -> Result<i128, ContractError> {
+pub fn calculate_fee(_env: &Env, strategy: &FeeStrategy, amount: i128) -> Result<i128, ContractError> {
     match strategy {
         FeeStrategy::Percentage(bps) => {
             if *bps > 10000 {
@@ -58,13 +115,162 @@ pub fn calculate_fee(env: &Env, strategy: &FeeStrategy, amount: i128) -> Result<
                 .checked_div(10000)
                 .ok_or(ContractError::Overflow)
         }
+        FeeStrategy::Tiered(tiers) => {
+            let tier = select_fee_tier(tiers, amount)?;
+            if tier.bps > 10000 {
+                return Err(ContractError::InvalidFeeBps);
+            }
+
+            amount
+                .checked_mul(tier.bps as i128)
+                .ok_or(ContractError::Overflow)?
+                .checked_div(10000)
+                .ok_or(ContractError::Overflow)?
+                .checked_add(tier.flat_component)
+                .ok_or(ContractError::Overflow)
+        }
+    }
+}
+
+/// Calculate fee with a schedule's strategy, clamped to its limits
+pub fn calculate_scheduled_fee(env: &Env, schedule: &FeeSchedule, amount: i128) -> Result<i128, ContractError> {
+    let fee = calculate_fee(env, &schedule.strategy, amount)?;
+    Ok(apply_fee_limits(&schedule.limits, fee))
+}
+
+/// Clamps a fee to the minimum and maximum of `limits`.
+pub fn apply_fee_limits(limits: &FeeLimits, fee: i128) -> i128 {
+    let fee = match limits.min_fee {
+        Some(min_fee) => fee.max(min_fee),
+        None => fee,
+    };
+    match limits.max_fee {
+        Some(max_fee) => fee.min(max_fee),
+        None => fee,
+    }
+}
+
+/// Gets the limits applied to the global fee strategy (none by default)
+pub fn get_global_fee_limits(env: &Env) -> FeeLimits {
+    env.storage()
+        .instance()
+        .get(&FeeKey::GlobalLimits)
+        .unwrap_or_default()
+}
+
+/// Sets the limits applied to the global fee strategy
+pub fn set_global_fee_limits(env: &Env, limits: &FeeLimits) {
+    env.storage().instance().set(&FeeKey::GlobalLimits, limits);
+}
+
+/// Gets a corridor's fee schedule, if configured
+pub fn get_corridor_fee_schedule(env: &Env, currency: &String, country: &String) -> Option<FeeSchedule> {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::Corridor(currency.clone(), country.clone()))
+}
+
+/// Sets a corridor's fee schedule, or removes it with `None`
+pub fn set_corridor_fee_schedule(env: &Env, currency: &String, country: &String, schedule: Option<&FeeSchedule>) {
+    let key = FeeKey::Corridor(currency.clone(), country.clone());
+    match schedule {
+        Some(schedule) => env.storage().persistent().set(&key, schedule),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Gets a token's fee schedule, if configured
+pub fn get_token_fee_schedule(env: &Env, token: &Address) -> Option<FeeSchedule> {
+    env.storage().persistent().get(&FeeKey::Token(token.clone()))
+}
+
+/// Sets a token's fee schedule, or removes it with `None`
+pub fn set_token_fee_schedule(env: &Env, token: &Address, schedule: Option<&FeeSchedule>) {
+    let key = FeeKey::Token(token.clone());
+    match schedule {
+        Some(schedule) => env.storage().persistent().set(&key, schedule),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns the global fee strategy with the global limits
+pub fn get_global_fee_schedule(env: &Env) -> FeeSchedule {
+    FeeSchedule {
+        strategy: get_fee_strategy(env),
+        limits: get_global_fee_limits(env),
+    }
+}
+
+/// Returns the schedule that prices remittances in a token and corridor.
+///
+/// The corridor's schedule takes precedence over the token's, and either
+/// over the global strategy. Without a corridor only the token's and the
+/// global schedule are considered.
+pub fn resolve_fee_schedule(env: &Env, token: &Address, corridor: Option<(&String, &String)>) -> FeeSchedule {
+    corridor
+        .and_then(|(currency, country)| get_corridor_fee_schedule(env, currency, country))
+        .or_else(|| get_token_fee_schedule(env, token))
+        .unwrap_or_else(|| get_global_fee_schedule(env))
+}
+
+/// Returns the tier an amount falls in.
+fn select_fee_tier(tiers: &Vec<FeeTier>, amount: i128) -> Result<FeeTier, ContractError> {
+    for tier in tiers.iter() {
+        if amount <= tier.upper_bound {
+            return Ok(tier);
+        }
     }
+    tiers.last().ok_or(ContractError::InvalidFeeTiers)
+}
+
+/// Validates a tiered fee schedule.
+///
+/// Requires between one and `MAX_FEE_TIERS` tiers with positive, strictly
+/// ascending upper bounds, bps of at most 10000 and non-negative flat components.
+pub fn validate_fee_tiers(tiers: &Vec<FeeTier>) -> Result<(), ContractError> {
+    if tiers.is_empty() || tiers.len() > MAX_FEE_TIERS {
+        return Err(ContractError::InvalidFeeTiers);
+    }
+
+    let mut previous_bound = 0;
+    for tier in tiers.iter() {
+        if tier.upper_bound <= previous_bound || tier.flat_component < 0 {
+            return Err(ContractError::InvalidFeeTiers);
+        }
+        if tier.bps > 10000 {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        previous_bound = tier.upper_bound;
+    }
+    Ok(())
+}
+
+/// Calculate the agent's share of a platform fee.
+///
+/// The commission is rounded down, so the platform keeps any remainder and
+/// `fee - commission` is never negative.
+pub fn calculate_commission(fee: i128, commission_bps: u32) -> Result<i128, ContractError> {
+    if commission_bps > 10000 {
+        return Err(ContractError::InvalidFeeBps);
+    }
+    fee.checked_mul(commission_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(10000)
+        .ok_or(ContractError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::Env;
+    use soroban_sdk::{vec, Env};
+
+    fn tier(upper_bound: i128, bps: u32, flat_component: i128) -> FeeTier {
+        FeeTier {
+            upper_bound,
+            bps,
+            flat_component,
+        }
+    }
 
     #[test]
     fn test_percentage_strategy() {
@@ -93,4 +299,88 @@ mod tests {
         // >10000: 1%
         assert_eq!(calculate_fee(&env, &strategy, 20000).unwrap(), 200);
     }
+
+    #[test]
+    fn test_tiered_strategy_boundaries() {
+        let env = Env::default();
+        let strategy = FeeStrategy::Tiered(vec![
+            &env,
+            tier(10_000_000_000, 300, 0),
+            tier(100_000_000_000, 150, 10_000_000),
+            tier(i128::MAX, 50, 50_000_000),
+        ]);
+
+        // Upper bounds are inclusive
+        assert_eq!(calculate_fee(&env, &strategy, 10_000_000_000).unwrap(), 300_000_000);
+        assert_eq!(calculate_fee(&env, &strategy, 10_000_000_001).unwrap(), 160_000_000);
+        assert_eq!(calculate_fee(&env, &strategy, 100_000_000_000).unwrap(), 1_510_000_000);
+        assert_eq!(calculate_fee(&env, &strategy, 100_000_000_001).unwrap(), 550_000_000);
+    }
+
+    #[test]
+    fn test_tiered_strategy_above_last_bound_uses_last_tier() {
+        let env = Env::default();
+        let strategy = FeeStrategy::Tiered(vec![&env, tier(1000, 200, 0), tier(5000, 100, 3)]);
+
+        assert_eq!(calculate_fee(&env, &strategy, 5000).unwrap(), 53);
+        assert_eq!(calculate_fee(&env, &strategy, 20000).unwrap(), 203);
+    }
+
+    #[test]
+    fn test_fee_tier_validation() {
+        let env = Env::default();
+
+        assert_eq!(validate_fee_tiers(&vec![&env, tier(1000, 200, 0), tier(5000, 100, 3)]), Ok(()));
+        assert_eq!(validate_fee_tiers(&Vec::new(&env)), Err(ContractError::InvalidFeeTiers));
+        assert_eq!(
+            validate_fee_tiers(&vec![&env, tier(5000, 200, 0), tier(5000, 100, 0)]),
+            Err(ContractError::InvalidFeeTiers)
+        );
+        assert_eq!(
+            validate_fee_tiers(&vec![&env, tier(5000, 200, 0), tier(1000, 100, 0)]),
+            Err(ContractError::InvalidFeeTiers)
+        );
+        assert_eq!(validate_fee_tiers(&vec![&env, tier(0, 200, 0)]), Err(ContractError::InvalidFeeTiers));
+        assert_eq!(validate_fee_tiers(&vec![&env, tier(1000, 200, -1)]), Err(ContractError::InvalidFeeTiers));
+        assert_eq!(validate_fee_tiers(&vec![&env, tier(1000, 10001, 0)]), Err(ContractError::InvalidFeeBps));
+    }
+
+    #[test]
+    fn test_fee_limits_clamp_any_strategy() {
+        let env = Env::default();
+        let limits = FeeLimits {
+            min_fee: Some(5),
+            max_fee: Some(100),
+        };
+        let percentage = FeeSchedule {
+            strategy: FeeStrategy::Percentage(250),
+            limits: limits.clone(),
+        };
+        let tiered = FeeSchedule {
+            strategy: FeeStrategy::Tiered(vec![&env, tier(1000, 300, 0), tier(i128::MAX, 200, 0)]),
+            limits,
+        };
+
+        assert_eq!(calculate_scheduled_fee(&env, &percentage, 100).unwrap(), 5);
+        assert_eq!(calculate_scheduled_fee(&env, &percentage, 1000).unwrap(), 25);
+        assert_eq!(calculate_scheduled_fee(&env, &percentage, 10000).unwrap(), 100);
+        assert_eq!(calculate_scheduled_fee(&env, &tiered, 0).unwrap(), 5);
+        assert_eq!(calculate_scheduled_fee(&env, &tiered, 50000).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_fee_limits_are_optional() {
+        assert_eq!(apply_fee_limits(&FeeLimits::default(), 0), 0);
+        assert_eq!(apply_fee_limits(&FeeLimits { min_fee: Some(10), max_fee: None }, 1_000_000), 1_000_000);
+        assert_eq!(apply_fee_limits(&FeeLimits { min_fee: None, max_fee: Some(10) }, 3), 3);
+    }
+
+    #[test]
+    fn test_commission_split() {
+        // 40% of a 25 fee rounds down to 10
+        assert_eq!(calculate_commission(25, 4000).unwrap(), 10);
+        assert_eq!(calculate_commission(25, 0).unwrap(), 0);
+        assert_eq!(calculate_commission(25, 10000).unwrap(), 25);
+        assert_eq!(calculate_commission(25, 10001), Err(ContractError::InvalidFeeBps));
+    }
 }
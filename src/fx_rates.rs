@@ -0,0 +1,292 @@
+//! On-chain FX rate registry.
+//!
+//! Addresses holding the `RateFeeder` role post prices for currency pairs
+//! such as USD/NGN, each with the timestamp at which the price was observed.
+//! The registry keeps the latest submission per feeder and pair and serves
+//! the median of the submissions that are no older than the maximum rate age,
+//! so a single faulty or stale feeder cannot move the published rate on its
+//! own. A median needs a configurable minimum number of fresh submissions.
+//! Rates use the same fixed-point scale as quotes (`FX_RATE_SCALE`).
+//!
+//! Quotes are checked against the median for the pair formed by the token's
+//! configured currency and the destination currency, and rejected when the
+//! quoted rate deviates from it by more than the quote rate tolerance.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{has_role, ContractError, Role};
+
+/// Default maximum age of a rate submission (1 hour)
+pub const DEFAULT_MAX_RATE_AGE: u64 = 3600;
+
+/// Default minimum number of fresh submissions a median is taken over
+pub const DEFAULT_MIN_RATE_SOURCES: u32 = 1;
+
+/// Default maximum deviation of a quoted rate from the median (1%, in basis points)
+pub const DEFAULT_QUOTE_RATE_TOLERANCE_BPS: u32 = 100;
+
+/// Latest price posted by one feeder for a currency pair.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateSubmission {
+    /// Quote units per base unit, scaled by `FX_RATE_SCALE`
+    pub rate: i128,
+    /// Timestamp at which the feeder observed the price
+    pub timestamp: u64,
+}
+
+/// Median rate for a currency pair across fresh submissions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FxRate {
+    /// Base currency of the pair (e.g., "USD")
+    pub base: String,
+    /// Quote currency of the pair (e.g., "NGN")
+    pub quote: String,
+    /// Median quote units per base unit, scaled by `FX_RATE_SCALE`
+    pub rate: i128,
+    /// Number of fresh submissions the median was taken over
+    pub sources: u32,
+    /// Timestamp of the oldest submission included
+    pub oldest_timestamp: u64,
+}
+
+/// Storage keys for the FX rate registry.
+#[contracttype]
+#[derive(Clone)]
+pub enum FxRateKey {
+    /// Maximum submission age in seconds (instance storage)
+    MaxAge,
+    /// Minimum fresh submissions a median is taken over (instance storage)
+    MinSources,
+    /// Maximum deviation of quoted rates from the median in basis points (instance storage)
+    QuoteTolerance,
+    /// Currency a token is priced in for rate lookups, indexed by token (persistent storage)
+    TokenCurrency(Address),
+    /// Feeders that have posted to a pair, indexed by (base, quote) (persistent storage)
+    Feeders(String, String),
+    /// Latest submission indexed by (base, quote, feeder) (persistent storage)
+    Submission(String, String, Address),
+}
+
+/// Gets the maximum submission age in seconds (defaults to 1 hour)
+pub fn get_max_rate_age(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&FxRateKey::MaxAge)
+        .unwrap_or(DEFAULT_MAX_RATE_AGE)
+}
+
+/// Sets the maximum submission age in seconds
+pub fn set_max_rate_age(env: &Env, max_age: u64) {
+    env.storage().instance().set(&FxRateKey::MaxAge, &max_age);
+}
+
+/// Gets the minimum number of fresh submissions a median needs (defaults to 1)
+pub fn get_min_rate_sources(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FxRateKey::MinSources)
+        .unwrap_or(DEFAULT_MIN_RATE_SOURCES)
+}
+
+/// Sets the minimum number of fresh submissions a median needs
+pub fn set_min_rate_sources(env: &Env, min_sources: u32) {
+    env.storage().instance().set(&FxRateKey::MinSources, &min_sources);
+}
+
+/// Gets the maximum deviation of quoted rates from the median in basis points (defaults to 1%)
+pub fn get_quote_rate_tolerance(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FxRateKey::QuoteTolerance)
+        .unwrap_or(DEFAULT_QUOTE_RATE_TOLERANCE_BPS)
+}
+
+/// Sets the maximum deviation of quoted rates from the median in basis points
+pub fn set_quote_rate_tolerance(env: &Env, tolerance_bps: u32) {
+    env.storage().instance().set(&FxRateKey::QuoteTolerance, &tolerance_bps);
+}
+
+/// Gets the currency a token is priced in, if configured
+pub fn get_token_currency(env: &Env, token: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&FxRateKey::TokenCurrency(token.clone()))
+}
+
+/// Sets the currency a token is priced in
+pub fn set_token_currency(env: &Env, token: &Address, currency: &String) {
+    env.storage()
+        .persistent()
+        .set(&FxRateKey::TokenCurrency(token.clone()), currency);
+}
+
+/// Gets a feeder's latest submission for a pair, if any
+pub fn get_rate_submission(env: &Env, base: &String, quote: &String, feeder: &Address) -> Option<RateSubmission> {
+    env.storage()
+        .persistent()
+        .get(&FxRateKey::Submission(base.clone(), quote.clone(), feeder.clone()))
+}
+
+fn get_pair_feeders(env: &Env, base: &String, quote: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&FxRateKey::Feeders(base.clone(), quote.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a feeder's submission for a pair, replacing its previous one.
+pub fn record_rate_submission(
+    env: &Env,
+    base: &String,
+    quote: &String,
+    feeder: &Address,
+    submission: &RateSubmission,
+) {
+    let mut feeders = get_pair_feeders(env, base, quote);
+    if !feeders.contains(feeder) {
+        feeders.push_back(feeder.clone());
+        env.storage()
+            .persistent()
+            .set(&FxRateKey::Feeders(base.clone(), quote.clone()), &feeders);
+    }
+    env.storage().persistent().set(
+        &FxRateKey::Submission(base.clone(), quote.clone(), feeder.clone()),
+        submission,
+    );
+}
+
+/// Returns whether a submission is within the maximum rate age.
+pub fn is_rate_fresh(env: &Env, timestamp: u64) -> bool {
+    env.ledger().timestamp().saturating_sub(timestamp) <= get_max_rate_age(env)
+}
+
+/// Computes the median rate for a pair over fresh submissions.
+///
+/// Submissions from addresses that no longer hold the `RateFeeder` role are
+/// ignored.
+///
+/// # Returns
+///
+/// * `Ok(FxRate)` - Median over every fresh submission
+/// * `Err(ContractError::RateNotFound)` - No current feeder has posted to the pair
+/// * `Err(ContractError::StaleRate)` - Every submission is older than the maximum rate age
+/// * `Err(ContractError::InsufficientRateSources)` - Fewer fresh submissions than the minimum
+pub fn get_median_rate(env: &Env, base: &String, quote: &String) -> Result<FxRate, ContractError> {
+    let mut rates: Vec<i128> = Vec::new(env);
+    let mut oldest_timestamp = u64::MAX;
+    let mut found = false;
+
+    for feeder in get_pair_feeders(env, base, quote).iter() {
+        if !has_role(env, &feeder, &Role::RateFeeder) {
+            continue;
+        }
+        let Some(submission) = get_rate_submission(env, base, quote, &feeder) else {
+            continue;
+        };
+        found = true;
+        if !is_rate_fresh(env, submission.timestamp) {
+            continue;
+        }
+        insert_sorted(&mut rates, submission.rate);
+        oldest_timestamp = oldest_timestamp.min(submission.timestamp);
+    }
+
+    if !found {
+        return Err(ContractError::RateNotFound);
+    }
+    let rate = median(&rates).ok_or(ContractError::StaleRate)?;
+    if rates.len() < get_min_rate_sources(env) {
+        return Err(ContractError::InsufficientRateSources);
+    }
+
+    Ok(FxRate {
+        base: base.clone(),
+        quote: quote.clone(),
+        rate,
+        sources: rates.len(),
+        oldest_timestamp,
+    })
+}
+
+/// Checks a quoted rate against the median from the token's currency to `dest_currency`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Quoted rate is within the quote rate tolerance of the median
+/// * `Err(ContractError::TokenCurrencyNotSet)` - Token has no currency configured
+/// * `Err(ContractError::FxRateOutOfTolerance)` - Quoted rate deviates too far from the median
+/// * Any error of `get_median_rate` for the pair
+pub fn check_quoted_rate(env: &Env, token: &Address, dest_currency: &String, fx_rate: i128) -> Result<(), ContractError> {
+    let base = get_token_currency(env, token).ok_or(ContractError::TokenCurrencyNotSet)?;
+    let median = get_median_rate(env, &base, dest_currency)?;
+
+    let deviation = fx_rate
+        .checked_sub(median.rate)
+        .ok_or(ContractError::Overflow)?
+        .checked_abs()
+        .ok_or(ContractError::Overflow)?;
+    let allowed = median
+        .rate
+        .checked_mul(get_quote_rate_tolerance(env) as i128)
+        .ok_or(ContractError::Overflow)?;
+    if deviation.checked_mul(10000).ok_or(ContractError::Overflow)? > allowed {
+        return Err(ContractError::FxRateOutOfTolerance);
+    }
+    Ok(())
+}
+
+/// Inserts `value` into an ascending vector, keeping it sorted.
+fn insert_sorted(values: &mut Vec<i128>, value: i128) {
+    let position = values.iter().position(|v| v > value).unwrap_or(values.len() as usize);
+    values.insert(position as u32, value);
+}
+
+/// Median of an ascending vector; the mean of the two middle values for an even count.
+fn median(sorted: &Vec<i128>) -> Option<i128> {
+    let len = sorted.len();
+    if len == 0 {
+        return None;
+    }
+    let upper = sorted.get_unchecked(len / 2);
+    if len % 2 == 1 {
+        return Some(upper);
+    }
+    let lower = sorted.get_unchecked(len / 2 - 1);
+    Some(lower + (upper - lower) / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(env: &Env, values: &[i128]) -> Vec<i128> {
+        let mut out = Vec::new(env);
+        for value in values {
+            insert_sorted(&mut out, *value);
+        }
+        out
+    }
+
+    #[test]
+    fn test_insert_sorted_orders_values() {
+        let env = Env::default();
+        assert_eq!(sorted(&env, &[30, 10, 20, 10]), Vec::from_array(&env, [10, 10, 20, 30]));
+    }
+
+    #[test]
+    fn test_median_odd_and_even() {
+        let env = Env::default();
+        assert_eq!(median(&sorted(&env, &[])), None);
+        assert_eq!(median(&sorted(&env, &[7])), Some(7));
+        assert_eq!(median(&sorted(&env, &[300, 100, 200])), Some(200));
+        assert_eq!(median(&sorted(&env, &[100, 400, 200, 300])), Some(250));
+    }
+
+    #[test]
+    fn test_median_ignores_single_outlier() {
+        let env = Env::default();
+        assert_eq!(median(&sorted(&env, &[1500, 1502, 999_999])), Some(1502));
+    }
+}
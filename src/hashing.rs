@@ -34,7 +34,7 @@
 //! 3. Computing SHA-256 hash of the serialized bytes
 //! 4. Using the resulting 32-byte hash as the settlement ID
 
-use soroban_sdk::{Address, Bytes, BytesN, Env};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
 
 /// Canonical field ordering version — increment if ordering ever changes.
 /// External systems should record this alongside stored settlement IDs.
@@ -109,6 +109,89 @@ pub fn compute_settlement_id_from_remittance(
     )
 }
 
+/// Generate a deterministic hash of a `create_remittance` request payload.
+///
+/// Used by idempotency protection to detect a key being reused with a
+/// different payload. Follows the same serialization rules as
+/// `compute_settlement_id`, over the fields known before a remittance exists:
+///
+/// 1. `sender`   — Address, XDR-encoded bytes
+/// 2. `agent`    — Address, XDR-encoded bytes
+/// 3. `amount`   — i128, big-endian 16 bytes
+/// 4. `token`    — Address, XDR-encoded bytes
+/// 5. `currency` — String, XDR-encoded bytes
+/// 6. `country`  — String, XDR-encoded bytes
+/// 7. `expiry`   — u64, big-endian 8 bytes (0 if None)
+pub fn compute_request_hash(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+    token: &Address,
+    currency: &String,
+    country: &String,
+    expiry: Option<u64>,
+) -> BytesN<32> {
+    use soroban_sdk::xdr::ToXdr;
+
+    let mut buf = Bytes::new(env);
+
+    buf.append(&address_to_bytes(env, sender));
+    buf.append(&address_to_bytes(env, agent));
+    buf.extend_from_array(&amount.to_be_bytes());
+    buf.append(&address_to_bytes(env, token));
+    buf.append(&currency.clone().to_xdr(env));
+    buf.append(&country.clone().to_xdr(env));
+
+    let expiry_val: u64 = expiry.unwrap_or(0);
+    buf.extend_from_array(&expiry_val.to_be_bytes());
+
+    env.crypto().sha256(&buf).into()
+}
+
+/// Generate the deterministic hash an oracle signs to issue a quote.
+///
+/// Follows the same serialization rules as `compute_settlement_id`, over the
+/// fields the oracle attests to:
+///
+/// 1. `sender`        — Address, XDR-encoded bytes
+/// 2. `amount`        — i128, big-endian 16 bytes
+/// 3. `token`         — Address, XDR-encoded bytes
+/// 4. `dest_currency` — String, XDR-encoded bytes
+/// 5. `dest_country`  — String, XDR-encoded bytes
+/// 6. `fx_rate`       — i128, big-endian 16 bytes
+/// 7. `valid_until`   — u64, big-endian 8 bytes
+/// 8. `nonce`         — u64, big-endian 8 bytes
+///
+/// The nonce lets an oracle sign otherwise identical quotes; each hash can
+/// issue only one quote.
+pub fn compute_quote_hash(
+    env: &Env,
+    sender: &Address,
+    amount: i128,
+    token: &Address,
+    dest_currency: &String,
+    dest_country: &String,
+    fx_rate: i128,
+    valid_until: u64,
+    nonce: u64,
+) -> BytesN<32> {
+    use soroban_sdk::xdr::ToXdr;
+
+    let mut buf = Bytes::new(env);
+
+    buf.append(&address_to_bytes(env, sender));
+    buf.extend_from_array(&amount.to_be_bytes());
+    buf.append(&address_to_bytes(env, token));
+    buf.append(&dest_currency.clone().to_xdr(env));
+    buf.append(&dest_country.clone().to_xdr(env));
+    buf.extend_from_array(&fx_rate.to_be_bytes());
+    buf.extend_from_array(&valid_until.to_be_bytes());
+    buf.extend_from_array(&nonce.to_be_bytes());
+
+    env.crypto().sha256(&buf).into()
+}
+
 /// Serialize an Address to its canonical byte representation.
 /// Uses Soroban's XDR encoding for deterministic, cross-platform compatibility.
 ///
@@ -170,60 +253,58 @@ mod tests {
 
         assert_eq!(hash_none, hash_zero, "None and Some(0) must produce identical hashes");
     }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
 
     #[test]
-    fn test_deterministic_hash_same_inputs() {
+    fn test_request_hash_deterministic() {
         let env = Env::default();
         let sender = Address::generate(&env);
         let agent = Address::generate(&env);
 
-        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
-        let hash2 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
-
-        assert_eq!(hash1, hash2, "Same inputs must produce identical hashes");
-    }
+        let token = Address::generate(&env);
+        let currency = String::from_str(&env, "NGN");
+        let country = String::from_str(&env, "NG");
 
-    #[test]
-    fn test_deterministic_hash_different_inputs() {
-        let env = Env::default();
-        let sender = Address::generate(&env);
-        let agent = Address::generate(&env);
+        let hash1 = compute_request_hash(&env, &sender, &agent, 1000, &token, &currency, &country, Some(3600));
+        let hash2 = compute_request_hash(&env, &sender, &agent, 1000, &token, &currency, &country, Some(3600));
 
-        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
-        let hash2 = compute_settlement_id(&env, 2, &sender, &agent, 1000, 25, Some(1234567890));
-
-        assert_ne!(hash1, hash2, "Different remittance IDs must produce different hashes");
+        assert_eq!(hash1, hash2, "Same payload must produce identical request hashes");
     }
 
     #[test]
-    fn test_deterministic_hash_field_order_matters() {
+    fn test_request_hash_covers_every_field() {
         let env = Env::default();
         let sender = Address::generate(&env);
         let agent = Address::generate(&env);
-
-        // Swapping sender and agent should produce different hash
-        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, None);
-        let hash2 = compute_settlement_id(&env, 1, &agent, &sender, 1000, 25, None);
-
-        assert_ne!(hash1, hash2, "Field order must affect hash output");
+        let token = Address::generate(&env);
+        let ngn = String::from_str(&env, "NGN");
+        let ng = String::from_str(&env, "NG");
+        let base = compute_request_hash(&env, &sender, &agent, 1000, &token, &ngn, &ng, Some(3600));
+
+        assert_ne!(base, compute_request_hash(&env, &agent, &sender, 1000, &token, &ngn, &ng, Some(3600)));
+        assert_ne!(base, compute_request_hash(&env, &sender, &agent, 1001, &token, &ngn, &ng, Some(3600)));
+        assert_ne!(base, compute_request_hash(&env, &sender, &agent, 1000, &Address::generate(&env), &ngn, &ng, Some(3600)));
+        assert_ne!(base, compute_request_hash(&env, &sender, &agent, 1000, &token, &String::from_str(&env, "GHS"), &ng, Some(3600)));
+        assert_ne!(base, compute_request_hash(&env, &sender, &agent, 1000, &token, &ngn, &String::from_str(&env, "GH"), Some(3600)));
+        assert_ne!(base, compute_request_hash(&env, &sender, &agent, 1000, &token, &ngn, &ng, Some(3601)));
     }
 
     #[test]
-    fn test_deterministic_hash_expiry_none_vs_zero() {
+    fn test_quote_hash_covers_every_field() {
         let env = Env::default();
         let sender = Address::generate(&env);
-        let agent = Address::generate(&env);
-
-        let hash_none = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, None);
-        let hash_zero = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(0));
-
-        assert_eq!(hash_none, hash_zero, "None and Some(0) must produce identical hashes");
+        let token = Address::generate(&env);
+        let ngn = String::from_str(&env, "NGN");
+        let ng = String::from_str(&env, "NG");
+        let base = compute_quote_hash(&env, &sender, 1000, &token, &ngn, &ng, 15_000_000, 3600, 1);
+
+        assert_eq!(base, compute_quote_hash(&env, &sender, 1000, &token, &ngn, &ng, 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &Address::generate(&env), 1000, &token, &ngn, &ng, 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1001, &token, &ngn, &ng, 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &Address::generate(&env), &ngn, &ng, 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &token, &String::from_str(&env, "GHS"), &ng, 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &token, &ngn, &String::from_str(&env, "GH"), 15_000_000, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &token, &ngn, &ng, 15_000_001, 3600, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &token, &ngn, &ng, 15_000_000, 3601, 1));
+        assert_ne!(base, compute_quote_hash(&env, &sender, 1000, &token, &ngn, &ng, 15_000_000, 3600, 2));
     }
 }
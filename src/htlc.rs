@@ -0,0 +1,57 @@
+//! Hash time-locked remittances.
+//!
+//! An HTLC remittance is an ordinary `Remittance` that additionally stores a
+//! SHA-256 hashlock. Its timelock is the remittance `expiry`: the assigned
+//! agent can settle it only by revealing the preimage at or before the
+//! timelock, and once the timelock has passed it can only be refunded to the
+//! sender. Because settlement publishes the preimage, an anchor can lock the
+//! matching leg on its own ledger under the same hash and unlock it as soon as
+//! the remittance settles.
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env};
+
+use crate::ContractError;
+
+/// Storage keys for hash time-locked remittances.
+#[contracttype]
+#[derive(Clone)]
+pub enum HtlcKey {
+    /// SHA-256 hashlock indexed by remittance ID (persistent storage)
+    Hashlock(u64),
+}
+
+/// Gets the hashlock of an HTLC remittance, if any
+pub fn get_hashlock(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&HtlcKey::Hashlock(remittance_id))
+}
+
+/// Stores the hashlock of an HTLC remittance
+pub fn set_hashlock(env: &Env, remittance_id: u64, hashlock: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&HtlcKey::Hashlock(remittance_id), hashlock);
+}
+
+/// Returns whether a remittance is hash time-locked
+pub fn is_htlc(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&HtlcKey::Hashlock(remittance_id))
+}
+
+/// Checks a revealed preimage against the remittance hashlock.
+///
+/// # Returns
+///
+/// * `Ok(())` - The preimage hashes to the stored hashlock
+/// * `Err(ContractError::InvalidPreimage)` - The remittance is not an HTLC or the preimage does not match
+pub fn verify_preimage(env: &Env, remittance_id: u64, preimage: &Bytes) -> Result<(), ContractError> {
+    let hashlock = get_hashlock(env, remittance_id).ok_or(ContractError::InvalidPreimage)?;
+    let revealed: BytesN<32> = env.crypto().sha256(preimage).into();
+    if revealed != hashlock {
+        return Err(ContractError::InvalidPreimage);
+    }
+    Ok(())
+}
@@ -0,0 +1,274 @@
+//! Sender KYC registry and tier limits.
+//!
+//! Addresses holding the `ComplianceOfficer` role record each sender's KYC
+//! tier together with an expiry and the jurisdiction that verified them, and
+//! configure per-transaction, daily and monthly caps for every tier and
+//! token. A sender without a record, or whose record has expired, is treated
+//! as `KycTier::None`. Tiers without caps configured for a token are not
+//! limited in it.
+//!
+//! Daily and monthly caps cover every remittance and escrow the sender creates
+//! in the token. Usage is bucketed: the daily cap applies to the current and
+//! previous 23 hours, the monthly cap to the current and previous 29 days.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{ContractError, DAILY_LIMIT_WINDOW};
+
+/// Length of an hourly usage bucket in seconds
+pub const KYC_HOUR: u64 = 3600;
+
+/// Number of hourly buckets summed for daily KYC caps
+pub const KYC_HOURLY_BUCKETS: u64 = 24;
+
+/// Number of daily buckets summed for monthly KYC caps
+pub const KYC_DAILY_BUCKETS: u64 = 30;
+
+/// Verification level of a sender.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KycTier {
+    /// Not verified, or verification expired
+    None,
+    /// Basic identity checks
+    Basic,
+    /// Full identity and address verification
+    Full,
+    /// Verified business entity
+    Business,
+}
+
+/// KYC record of a sender.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycRecord {
+    /// Verified tier
+    pub tier: KycTier,
+    /// Timestamp after which the sender falls back to `KycTier::None`
+    pub expiry: u64,
+    /// Jurisdiction the verification was performed under (e.g., "NG")
+    pub jurisdiction: String,
+    /// Compliance officer that recorded the verification
+    pub officer: Address,
+    /// Timestamp at which the record was last updated
+    pub updated_at: u64,
+}
+
+/// Amount caps for a KYC tier in one token (in token units).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycLimits {
+    /// Maximum amount of a single remittance or escrow
+    pub per_transaction: i128,
+    /// Maximum total over the last 24 hourly buckets
+    pub daily: i128,
+    /// Maximum total over the last 30 daily buckets
+    pub monthly: i128,
+}
+
+/// Amount a sender transferred during one period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycUsageBucket {
+    /// Period number (timestamp divided by the bucket length)
+    pub period: u64,
+    /// Total transferred during the period
+    pub amount: i128,
+}
+
+/// Sender's recent usage in one token, as fixed-size rings of buckets.
+///
+/// The bucket of period `p` lives at position `p % slots`; a slot holding an
+/// older period is stale and counts as zero.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycUsage {
+    /// Hourly buckets (at most `KYC_HOURLY_BUCKETS`)
+    pub hourly: Vec<KycUsageBucket>,
+    /// Daily buckets (at most `KYC_DAILY_BUCKETS`)
+    pub daily: Vec<KycUsageBucket>,
+}
+
+/// How much a sender can still send under their KYC tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycHeadroom {
+    /// Effective tier of the sender
+    pub tier: KycTier,
+    /// Per-transaction cap of the tier
+    pub per_transaction: i128,
+    /// Amount left under the daily cap (never negative)
+    pub daily_remaining: i128,
+    /// Amount left under the monthly cap (never negative)
+    pub monthly_remaining: i128,
+}
+
+/// Storage keys for the KYC registry.
+#[contracttype]
+#[derive(Clone)]
+pub enum KycKey {
+    /// KYC record indexed by sender (persistent storage)
+    Record(Address),
+    /// Caps indexed by tier and token (persistent storage)
+    Limits(KycTier, Address),
+    /// Sender's bucketed usage indexed by sender and token (persistent storage)
+    Usage(Address, Address),
+}
+
+/// Gets a sender's KYC record, if any
+pub fn get_kyc_record(env: &Env, address: &Address) -> Option<KycRecord> {
+    env.storage()
+        .persistent()
+        .get(&KycKey::Record(address.clone()))
+}
+
+/// Stores a sender's KYC record
+pub fn set_kyc_record(env: &Env, address: &Address, record: &KycRecord) {
+    env.storage()
+        .persistent()
+        .set(&KycKey::Record(address.clone()), record);
+}
+
+/// Removes a sender's KYC record
+pub fn remove_kyc_record(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&KycKey::Record(address.clone()));
+}
+
+/// Gets the caps configured for a tier in a token, if any
+pub fn get_kyc_limits(env: &Env, tier: KycTier, token: &Address) -> Option<KycLimits> {
+    env.storage()
+        .persistent()
+        .get(&KycKey::Limits(tier, token.clone()))
+}
+
+/// Sets the caps for a tier in a token
+pub fn set_kyc_limits(env: &Env, tier: KycTier, token: &Address, limits: &KycLimits) {
+    env.storage()
+        .persistent()
+        .set(&KycKey::Limits(tier, token.clone()), limits);
+}
+
+/// Removes the caps for a tier in a token, leaving it unlimited there
+pub fn remove_kyc_limits(env: &Env, tier: KycTier, token: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&KycKey::Limits(tier, token.clone()));
+}
+
+/// Returns a sender's tier, falling back to `KycTier::None` without a current record.
+pub fn get_effective_kyc_tier(env: &Env, address: &Address) -> KycTier {
+    match get_kyc_record(env, address) {
+        Some(record) if env.ledger().timestamp() <= record.expiry => record.tier,
+        _ => KycTier::None,
+    }
+}
+
+/// Adds a transfer to the bucket of `period`, restarting a slot left over from an older period.
+fn add_to_bucket(buckets: &mut Vec<KycUsageBucket>, slots: u64, period: u64, amount: i128) -> Result<(), ContractError> {
+    let slot = (period % slots) as u32;
+    let mut bucket = buckets.get(slot).unwrap_or(KycUsageBucket { period, amount: 0 });
+    if bucket.period != period {
+        bucket = KycUsageBucket { period, amount: 0 };
+    }
+    bucket.amount = bucket.amount.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    // Slots are filled in order, so a missing slot is always the next one
+    if slot < buckets.len() {
+        buckets.set(slot, bucket);
+    } else {
+        buckets.push_back(bucket);
+    }
+    Ok(())
+}
+
+/// Sums the buckets of the last `slots` periods up to and including `period`.
+fn sum_buckets(buckets: &Vec<KycUsageBucket>, slots: u64, period: u64) -> Result<i128, ContractError> {
+    let mut total: i128 = 0;
+    for bucket in buckets.iter() {
+        if bucket.period <= period && period - bucket.period < slots {
+            total = total.checked_add(bucket.amount).ok_or(ContractError::Overflow)?;
+        }
+    }
+    Ok(total)
+}
+
+/// Gets a sender's bucketed usage in a token
+fn get_kyc_usage(env: &Env, address: &Address, token: &Address) -> KycUsage {
+    env.storage()
+        .persistent()
+        .get(&KycKey::Usage(address.clone(), token.clone()))
+        .unwrap_or(KycUsage {
+            hourly: Vec::new(env),
+            daily: Vec::new(env),
+        })
+}
+
+/// Sums a sender's usage as `(daily, monthly)` totals.
+fn sum_kyc_usage(env: &Env, usage: &KycUsage) -> Result<(i128, i128), ContractError> {
+    let current_time = env.ledger().timestamp();
+    let daily = sum_buckets(&usage.hourly, KYC_HOURLY_BUCKETS, current_time / KYC_HOUR)?;
+    let monthly = sum_buckets(&usage.daily, KYC_DAILY_BUCKETS, current_time / DAILY_LIMIT_WINDOW)?;
+    Ok((daily, monthly))
+}
+
+/// Returns how much a sender can still send in a token under their tier.
+///
+/// * `None` - The sender's tier has no caps configured for the token
+/// * `Some(headroom)` - Caps and remaining amounts of the sender's tier
+pub fn get_kyc_headroom(env: &Env, address: &Address, token: &Address) -> Result<Option<KycHeadroom>, ContractError> {
+    let tier = get_effective_kyc_tier(env, address);
+    let limits = match get_kyc_limits(env, tier, token) {
+        Some(limits) => limits,
+        None => return Ok(None),
+    };
+
+    let (daily, monthly) = sum_kyc_usage(env, &get_kyc_usage(env, address, token))?;
+
+    Ok(Some(KycHeadroom {
+        tier,
+        per_transaction: limits.per_transaction,
+        daily_remaining: limits.daily.saturating_sub(daily).max(0),
+        monthly_remaining: limits.monthly.saturating_sub(monthly).max(0),
+    }))
+}
+
+/// Checks a transfer against the sender's tier caps for its token and records it.
+///
+/// Usage is kept in at most `KYC_HOURLY_BUCKETS` hourly and `KYC_DAILY_BUCKETS`
+/// daily buckets, so the stored record has a fixed size however often the
+/// sender transfers.
+pub fn check_and_record_kyc_usage(
+    env: &Env,
+    address: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let limits = match get_kyc_limits(env, get_effective_kyc_tier(env, address), token) {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
+
+    if amount > limits.per_transaction {
+        return Err(ContractError::KycTransactionLimitExceeded);
+    }
+
+    let mut usage = get_kyc_usage(env, address, token);
+    let (daily, monthly) = sum_kyc_usage(env, &usage)?;
+    if daily.checked_add(amount).ok_or(ContractError::Overflow)? > limits.daily {
+        return Err(ContractError::KycDailyLimitExceeded);
+    }
+    if monthly.checked_add(amount).ok_or(ContractError::Overflow)? > limits.monthly {
+        return Err(ContractError::KycMonthlyLimitExceeded);
+    }
+
+    let current_time = env.ledger().timestamp();
+    add_to_bucket(&mut usage.hourly, KYC_HOURLY_BUCKETS, current_time / KYC_HOUR, amount)?;
+    add_to_bucket(&mut usage.daily, KYC_DAILY_BUCKETS, current_time / DAILY_LIMIT_WINDOW, amount)?;
+    env.storage()
+        .persistent()
+        .set(&KycKey::Usage(address.clone(), token.clone()), &usage);
+
+    Ok(())
+}
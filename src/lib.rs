@@ -4,18 +4,37 @@
 //! with built-in duplicate settlement protection and expiry mechanisms.
 
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
+mod agent_profiles;
+mod agent_stats;
 mod asset_verification;
+mod bonds;
+mod capacity;
+mod claims;
+mod commissions;
+mod debug;
+mod disputes;
 mod errors;
 mod events;
 mod fee_strategy;
+mod fx_rates;
 mod hashing;
+mod htlc;
+mod kyc;
 mod migration;
 mod netting;
+mod operators;
+mod quotes;
 mod rate_limit;
+mod routing;
+mod sanctions;
+mod standing_orders;
 mod storage;
+mod transitions;
 mod types;
 mod validation;
+mod verification;
 #[cfg(test)]
 mod test;
 #[cfg(test)]
@@ -30,20 +49,86 @@ mod test_transfer_state;
 mod test_protocol_fee;
 #[cfg(test)]
 mod test_property; 
+#[cfg(test)]
+mod test_idempotency;
+#[cfg(test)]
+mod test_proof_validation;
+#[cfg(test)]
+mod test_daily_limit;
+#[cfg(test)]
+mod test_history;
+#[cfg(test)]
+mod test_expiry;
+#[cfg(test)]
+mod test_transitions;
+#[cfg(test)]
+mod test_disputes;
+#[cfg(test)]
+mod test_claims;
+#[cfg(test)]
+mod test_htlc;
+#[cfg(test)]
+mod test_standing_orders;
+#[cfg(test)]
+mod test_multi_token;
+#[cfg(test)]
+mod test_quotes;
+#[cfg(test)]
+mod test_fx_rates;
+#[cfg(test)]
+mod test_kyc;
+#[cfg(test)]
+mod test_sanctions;
+#[cfg(test)]
+mod test_agent_stats;
+#[cfg(test)]
+mod test_bonds;
+#[cfg(test)]
+mod test_capacity;
+#[cfg(test)]
+mod test_agent_profiles;
+#[cfg(test)]
+mod test_routing;
+#[cfg(test)]
+mod test_operators;
+#[cfg(test)]
+mod test_commissions;
+#[cfg(test)]
+mod test_integrators;
+#[cfg(test)]
+mod test_fixtures;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, String, Vec};
 
+pub use agent_profiles::*;
+pub use agent_stats::*;
 pub use asset_verification::*;
+pub use bonds::*;
+pub use capacity::*;
+pub use claims::*;
+pub use commissions::*;
+pub use debug::*;
+pub use disputes::*;
 pub use errors::ContractError;
 pub use events::*;
 pub use fee_strategy::*;
+pub use fx_rates::*;
 pub use hashing::*;
+pub use htlc::*;
+pub use kyc::*;
 pub use migration::*;
 pub use netting::*;
+pub use operators::*;
+pub use quotes::*;
 pub use rate_limit::*;
+pub use routing::*;
+pub use sanctions::*;
+pub use standing_orders::*;
 pub use storage::*;
+pub use transitions::*;
 pub use types::*;
 pub use validation::*;
+pub use verification::*;
 
 /// Maximum number of remittances that can be settled in a single batch
 const MAX_BATCH_SIZE: u32 = 100;
@@ -97,7 +182,7 @@ impl SwiftRemitContract {
     ///
     /// * `env` - The contract execution environment
     /// * `admin` - Address that will have administrative privileges
-    /// * `usdc_token` - Address of the USDC token contract used for transactions
+    /// * `usdc_token` - Address of the default (USDC) token contract; must be whitelisted
     /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
     ///
     /// # Returns
@@ -136,7 +221,7 @@ impl SwiftRemitContract {
         set_usdc_token(&env, &usdc_token);
         set_platform_fee_bps(&env, fee_bps);
         set_remittance_counter(&env, 0);
-        set_accumulated_fees(&env, 0);
+        set_accumulated_fees(&env, &usdc_token, 0);
         set_rate_limit_cooldown(&env, rate_limit_cooldown);
         set_escrow_counter(&env, 0);
         
@@ -155,7 +240,8 @@ impl SwiftRemitContract {
     /// Registers a new agent authorized to receive remittance payouts.
     ///
     /// Only the contract admin can register agents. Registered agents can confirm
-    /// payouts for remittances assigned to them.
+    /// payouts for remittances assigned to them. Once registered, an agent can
+    /// post the bond it needs for remittances above the bond threshold.
     ///
     /// # Arguments
     ///
@@ -187,6 +273,8 @@ impl SwiftRemitContract {
     ///
     /// Only the contract admin can remove agents. Removed agents cannot confirm
     /// new payouts, but existing remittances assigned to them remain valid.
+    /// The agent's active bond in every known token starts unbonding and can be
+    /// withdrawn once the unbonding delay has passed.
     ///
     /// # Arguments
     ///
@@ -207,6 +295,15 @@ impl SwiftRemitContract {
 
         set_agent_registered(&env, &agent, false);
 
+        // Removed agents start unbonding so their collateral stays slashable until the delay passes
+        for token in get_known_tokens(&env).iter() {
+            let bonded = get_agent_bond(&env, &agent, &token).bonded;
+            if bonded > 0 {
+                let withdrawable_at = start_unbonding(&env, &agent, &token, bonded)?;
+                emit_bond_unbonding(&env, agent.clone(), token, bonded, withdrawable_at);
+            }
+        }
+
         // Event: Agent removed - Fires when admin removes an agent from the approved list
         // Used by off-chain systems to revoke payout confirmation privileges
         emit_agent_removed(&env, agent, caller);
@@ -259,856 +356,3545 @@ impl SwiftRemitContract {
     /// * `env` - The contract execution environment
     /// * `sender` - Address initiating the remittance
     /// * `agent` - Address of the registered agent who will receive the payout
-    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `currency` - Payout currency of the corridor (e.g., "NGN")
+    /// * `country` - Destination country of the corridor (e.g., "NG")
     /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `integrator` - Optional integrator the remittance was created through; its fee is
+    ///   charged to the sender on top of `amount`
     ///
     /// # Returns
     ///
     /// * `Ok(remittance_id)` - Unique ID of the created remittance
     /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
     /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::IntegratorNotRegistered)` - Specified integrator is not registered
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::InvalidCorridor)` - Currency or country is empty
+    /// * `Err(ContractError::DailySendLimitExceeded)` - Sender's rolling 24h total for the corridor would exceed its limit
+    /// * `Err(ContractError::KycTransactionLimitExceeded)` - Amount exceeds the sender's KYC tier per-transaction cap
+    /// * `Err(ContractError::KycDailyLimitExceeded)` - Sender's 24h total would exceed their KYC tier daily cap
+    /// * `Err(ContractError::KycMonthlyLimitExceeded)` - Sender's 30-day total would exceed their KYC tier monthly cap
+    /// * `Err(ContractError::AgentCorridorNotServed)` - Agent's profile does not list the currency and country
+    /// * `Err(ContractError::AgentBondRequired)` - Amount is above the bond threshold and the agent is not bonded enough
+    /// * `Err(ContractError::AgentCapacityExceeded)` - Agent's outstanding remittances would exceed its declared capacity
+    /// * `Err(ContractError::FeesExceedAmount)` - Platform and protocol fees together exceed the amount
     /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
     ///
     /// # Authorization
     ///
     /// Requires authentication from the sender address.
-   pub fn create_remittance(
-    env: Env,
-    sender: Address,
-    agent: Address,
-    amount: i128,
-    expiry: Option<u64>,
-) -> Result<u64, ContractError> {
-    validate_create_remittance_request(&env, &sender, &agent, amount)?;
-
-    sender.require_auth();
+    pub fn create_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        expiry: Option<u64>,
+        integrator: Option<Address>,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount, &token, &currency, &country)?;
 
-    // Use configured fee strategy
-    let strategy = get_fee_strategy(&env);
-    let fee = calculate_fee(&env, &strategy, amount)?;
+        sender.require_auth();
 
-    let usdc_token = get_usdc_token(&env)?;
-    let token_client = token::Client::new(&env, &usdc_token);
-    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+        execute_create_remittance(
+            &env,
+            &sender,
+            &agent,
+            amount,
+            &token,
+            &currency,
+            &country,
+            expiry,
+            integrator.as_ref(),
+            None,
+        )
+    }
 
-    let counter = get_remittance_counter(&env)?;
-    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+    /// Creates an open-claim remittance that any settler agent can redeem with a claim code.
+    ///
+    /// Instead of naming an agent, the sender commits to the SHA-256 hash of a
+    /// one-time claim code and shares the code with the recipient. Fees, daily
+    /// limits and expiry behave as in `create_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address sending the remittance (must authorize)
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `currency` - Payout currency of the corridor (e.g., "NGN")
+    /// * `country` - Destination country of the corridor (e.g., "NG")
+    /// * `claim_hash` - SHA-256 hash of the claim code
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * Any error returned by `create_remittance` other than agent validation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_open_remittance(
+        env: Env,
+        sender: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        claim_hash: BytesN<32>,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_create_open_remittance_request(&env, &sender, amount, &token, &currency, &country)?;
 
-    let remittance = Remittance {
-        id: remittance_id,
-        sender: sender.clone(),
-        agent: agent.clone(),
-        amount,
-        fee,
-        status: RemittanceStatus::Pending,
-        expiry,
-    };
+        sender.require_auth();
 
-    set_remittance(&env, remittance_id, &remittance);
-    set_remittance_counter(&env, remittance_id);
-    
-    // Set initial transfer state
-    set_transfer_state(&env, remittance_id, TransferState::Initiated)?;
+        let unassigned = env.current_contract_address();
+        let remittance_id = execute_create_remittance(
+            &env,
+            &sender,
+            &unassigned,
+            amount,
+            &token,
+            &currency,
+            &country,
+            expiry,
+            None,
+            None,
+        )?;
+        set_claim_hash(&env, remittance_id, &claim_hash);
+
+        Ok(remittance_id)
+    }
 
-    Ok(remittance_id)  // ← capital O
-}
-    /// Confirms a remittance payout to the agent.
+    /// Commits an agent to the claim code of an open-claim remittance.
     ///
-    /// Transfers the remittance amount (minus platform fee) to the agent and marks
-    /// the remittance as completed. Includes duplicate settlement protection and
-    /// expiry validation.
+    /// The agent submits `sha256(claim_code ‖ agent)` without revealing the
+    /// code, then calls `claim_remittance` in a later ledger. Committing again
+    /// replaces the previous commitment and restarts the wait.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `agent` - Registered settler agent intending to pay out the cash
+    /// * `remittance_id` - ID of the open-claim remittance
+    /// * `commitment` - SHA-256 hash of the claim code followed by the agent's XDR-encoded address
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Payout successfully confirmed and transferred
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
-    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
-    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
-    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Ok(())` - Commitment recorded
+    /// * `Err(ContractError::InvalidClaimCode)` - Remittance is not an open claim
+    /// * `Err(ContractError::InvalidStatus)` - Remittance can no longer be claimed
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the agent address assigned to the remittance.
-    /// Requires Settler role.
-    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic (returns remittance to avoid re-read)
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
-
-        remittance.agent.require_auth();
-        
-        // Require Settler role
-        require_role_settler(&env, &remittance.agent)?;
-        
-        // Transition to Processing state
-        set_transfer_state(&env, remittance_id, TransferState::Processing)?;
-
-        // Check rate limit for sender
-        check_settlement_rate_limit(&env, &remittance.sender)?;
-
-        // Calculate protocol fee
-        let protocol_fee_bps = get_protocol_fee_bps(&env);
-        let protocol_fee = remittance
-            .amount
-            .checked_mul(protocol_fee_bps as i128)
-            .ok_or(ContractError::Overflow)?
-            .checked_div(10000)
-            .ok_or(ContractError::Overflow)?;
-
-        // Calculate payout after platform and protocol fees
-        let payout_amount = remittance
-            .amount
-            .checked_sub(remittance.fee)
-            .ok_or(ContractError::Overflow)?
-            .checked_sub(protocol_fee)
-            .ok_or(ContractError::Overflow)?;
+    /// Requires authentication from the agent. Requires Settler role.
+    pub fn commit_claim(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
+        require_role_settler(&env, &agent)?;
 
-        // Batch read storage values
-        let usdc_token = get_usdc_token(&env)?;
-        let current_fees = get_accumulated_fees(&env)?;
-        let current_time = env.ledger().timestamp();
-        
-        let token_client = token::Client::new(&env, &usdc_token);
-        
-        // Transfer payout to agent
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.agent,
-            &payout_amount,
-        );
-        
-        // Transfer protocol fee to treasury if needed
-        if protocol_fee > 0 {
-            let treasury = get_treasury(&env)?;
-            token_client.transfer(
-                &env.current_contract_address(),
-                &treasury,
-                &protocol_fee,
-            );
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        if get_claim_hash(&env, remittance.id).is_none() {
+            return Err(ContractError::InvalidClaimCode);
         }
+        validate_agent_registered(&env, &agent)?;
 
-        // Update accumulated fees
-        let new_fees = current_fees
-            .checked_add(remittance.fee)
-            .ok_or(ContractError::Overflow)?;
-        set_accumulated_fees(&env, new_fees);
-
-        // Update remittance status
-        remittance.status = RemittanceStatus::Completed;
-        set_remittance(&env, remittance_id, &remittance);
-        
-        // Transition to Completed state
-        set_transfer_state(&env, remittance_id, TransferState::Completed)?;
-
-        // Mark settlement as executed to prevent duplicates
-        set_settlement_hash(&env, remittance_id);
-        
-        // Update last settlement time for rate limiting
-        set_last_settlement_time(&env, &remittance.sender, current_time);
-
-        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
-        // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone());
-        
-        // Event: Settlement completed - Fires with final executed settlement values
-        // Used by off-chain systems for reconciliation and audit trails of completed transactions
-        emit_settlement_completed(&env, remittance_id, remittance.sender, remittance.agent, usdc_token, payout_amount);
-
-        log_confirm_payout(&env, remittance_id, payout_amount);
-
+        set_claim_commitment(&env, remittance_id, &agent, &commitment);
         Ok(())
     }
 
-    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        let remittance = get_remittance(&env, remittance_id)?;
-
-        // Verify remittance is in a valid state (Completed)
-        if remittance.status != RemittanceStatus::Completed {
-            return Err(ContractError::InvalidStateTransition);
+    /// Pays out an open-claim remittance to the agent presenting its claim code.
+    ///
+    /// The agent must have committed to the code with `commit_claim` in an
+    /// earlier ledger. Assigns the remittance to `agent` and then follows the
+    /// `confirm_payout` flow: fees, protocol fee, events and settlement
+    /// deduplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered settler agent paying out the cash
+    /// * `remittance_id` - ID of the open-claim remittance
+    /// * `claim_code` - Claim code read out by the recipient
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Claim accepted and payout transferred to the agent
+    /// * `Err(ContractError::InvalidClaimCode)` - Code does not match, the agent's commitment does not match, or remittance is not an open claim
+    /// * `Err(ContractError::ClaimNotCommitted)` - Agent did not commit to the code in an earlier ledger
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::AgentCorridorNotServed)` - Agent's profile does not list the remittance corridor
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent. Requires Settler role.
+    pub fn claim_remittance(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        claim_code: Bytes,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        verify_claim_code(&env, remittance_id, &agent, &claim_code)?;
+        validate_agent_registered(&env, &agent)?;
+        validate_agent_serves_corridor(&env, &agent, &remittance.currency, &remittance.country)?;
+        check_agent_bond(&env, &agent, &remittance.token, remittance.amount)?;
+        add_agent_exposure(&env, &agent, &remittance.token, remittance.amount)?;
+
+        remittance.agent = agent.clone();
+        if requires_proof(&env, &remittance) {
+            return Err(ContractError::MissingProof);
         }
+        index_remittance_agent(&env, &agent, remittance_id);
+        emit_remittance_claimed(&env, remittance_id, agent);
 
-        // Remittance is already completed, no further action needed
-        Ok(())
+        execute_confirm_payout(&env, remittance)
     }
 
-    /// Cancels a pending remittance and refunds the sender.
+    /// Creates a hash time-locked remittance for atomic hand-off with an anchor.
     ///
-    /// Returns the full remittance amount to the sender and marks the remittance
-    /// as cancelled. Can only be called by the original sender.
+    /// The remittance is assigned to `agent` like `create_remittance`, but it
+    /// can only be settled through `settle_htlc` by revealing the preimage of
+    /// `hashlock` at or before `timelock`. After the timelock it can only be
+    /// refunded to the sender, and the sender cannot cancel it before then.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to cancel
+    /// * `sender` - Address sending the remittance (must authorize)
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `currency` - Payout currency of the corridor (e.g., "NGN")
+    /// * `country` - Destination country of the corridor (e.g., "NG")
+    /// * `hashlock` - SHA-256 hash of the secret preimage
+    /// * `timelock` - Timestamp (seconds since epoch) after which the remittance refunds; stored as its expiry
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Remittance successfully cancelled and refunded
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::InvalidTimelock)` - Timelock is not in the future
+    /// * Any error returned by `create_remittance`
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address who created the remittance.
-    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic (returns remittance to avoid re-read)
-        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
-
-        remittance.sender.require_auth();
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
-
-        remittance.status = RemittanceStatus::Cancelled;
-        set_remittance(&env, remittance_id, &remittance);
-        
-        // Transition to Refunded state
-        set_transfer_state(&env, remittance_id, TransferState::Refunded)?;
-
-        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
-        // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(&env, remittance_id, remittance.sender, remittance.agent, usdc_token, remittance.amount);
+    /// Requires authentication from the sender address.
+    pub fn create_htlc_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        hashlock: BytesN<32>,
+        timelock: u64,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount, &token, &currency, &country)?;
+        validate_timelock(&env, timelock)?;
 
-        log_cancel_remittance(&env, remittance_id);
+        sender.require_auth();
 
-        Ok(())
+        let remittance_id = execute_create_remittance(
+            &env,
+            &sender,
+            &agent,
+            amount,
+            &token,
+            &currency,
+            &country,
+            Some(timelock),
+            None,
+            None,
+        )?;
+        set_hashlock(&env, remittance_id, &hashlock);
+
+        emit_htlc_created(&env, remittance_id, hashlock, timelock);
+
+        Ok(remittance_id)
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    /// Settles a hash time-locked remittance by revealing its preimage.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// Follows the `confirm_payout` flow and then emits the preimage so the
+    /// counterparty can unlock its side of the swap.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
+    /// * `remittance_id` - ID of the HTLC remittance
+    /// * `preimage` - Secret whose SHA-256 hash is the remittance hashlock
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
-    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// * `Ok(())` - Preimage accepted and payout transferred to the agent
+    /// * `Err(ContractError::InvalidPreimage)` - Preimage does not match or remittance is not an HTLC
+    /// * `Err(ContractError::SettlementExpired)` - Timelock has passed
+    /// * Any error returned by `confirm_payout`
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        // Centralized validation before business logic (returns fees to avoid re-read)
-        let fees = validate_withdraw_fees_request(&env, &to)?;
-        
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
+    /// Requires authentication from the agent assigned to the remittance.
+    /// Requires Settler role.
+    pub fn settle_htlc(env: Env, remittance_id: u64, preimage: Bytes) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        verify_preimage(&env, remittance_id, &preimage)?;
 
-        set_accumulated_fees(&env, 0);
+        if requires_proof(&env, &remittance) {
+            return Err(ContractError::MissingProof);
+        }
 
-        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
-        // Used by off-chain systems to track revenue collection and maintain financial records
-        emit_fees_withdrawn(&env, caller, to, usdc_token, fees);
+        let agent = remittance.agent.clone();
+        execute_confirm_payout(&env, remittance)?;
 
-        log_withdraw_fees(&env, &to, fees);
+        emit_htlc_settled(&env, remittance_id, agent, preimage);
 
         Ok(())
     }
 
-    /// Retrieves a remittance record by ID.
+    /// Refunds a hash time-locked remittance to its sender once its timelock has passed.
+    ///
+    /// Permissionless, since funds can only go back to the sender. The
+    /// remittance is marked Expired, exactly as `expire_remittances` would,
+    /// or Frozen if one of its parties is sanctioned.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to retrieve
+    /// * `remittance_id` - ID of the HTLC remittance
     ///
     /// # Returns
     ///
-    /// * `Ok(Remittance)` - The remittance record
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
-        get_remittance(&env, remittance_id)
-    }
+    /// * `Ok(())` - Remittance refunded to the sender
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not an HTLC or is no longer pending
+    /// * `Err(ContractError::TimelockActive)` - Timelock has not passed yet
+    pub fn refund_htlc(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if !is_htlc(&env, remittance_id) {
+            return Err(ContractError::InvalidStatus);
+        }
 
+        if remittance.status == RemittanceStatus::Processing {
+            revert_lapsed_acceptance(&env, &mut remittance)?;
+        }
+        validate_remittance_pending(&remittance)?;
+        if !is_expirable(&env, &remittance) {
+            return Err(ContractError::TimelockActive);
+        }
 
-    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
-        get_accumulated_fees(&env)
+        execute_expire_remittance(&env, remittance)?;
+        Ok(())
     }
 
-    /// Checks if an address is registered as an agent.
+    /// Gets the hashlock of a hash time-locked remittance, if it is one.
+    pub fn get_hashlock(env: Env, remittance_id: u64) -> Option<BytesN<32>> {
+        get_hashlock(&env, remittance_id)
+    }
+
+    /// Creates a new remittance protected by a client-provided idempotency key.
+    ///
+    /// Behaves like `create_remittance`, but records the key together with a hash
+    /// of the request payload. Retrying with the same key and payload before the
+    /// record expires returns the original remittance ID without transferring
+    /// tokens or creating a new remittance.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `agent` - Address to check
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `currency` - Payout currency of the corridor (e.g., "NGN")
+    /// * `country` - Destination country of the corridor (e.g., "NG")
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `idempotency_key` - Client-provided key identifying this logical request for this sender (1-255 bytes of `[A-Za-z0-9_-]`)
     ///
     /// # Returns
     ///
-    /// * `true` - Address is a registered agent
-    /// * `false` - Address is not registered
-    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
-        is_agent_registered(&env, &agent)
+    /// * `Ok(remittance_id)` - ID of the created (or previously created) remittance
+    /// * `Err(ContractError::InvalidIdempotencyKey)` - Key is empty, longer than 255 bytes or has invalid characters
+    /// * `Err(ContractError::IdempotencyConflict)` - Key was already used with a different payload
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance_idempotent(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        expiry: Option<u64>,
+        idempotency_key: String,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount, &token, &currency, &country)?;
+        validate_idempotency_key(&idempotency_key)?;
+
+        sender.require_auth();
+
+        let request_hash =
+            compute_request_hash(&env, &sender, &agent, amount, &token, &currency, &country, expiry);
+        let current_time = env.ledger().timestamp();
+
+        if let Some(record) = get_idempotency_record(&env, &sender, &idempotency_key) {
+            if current_time <= record.expires_at {
+                if record.request_hash != request_hash {
+                    return Err(ContractError::IdempotencyConflict);
+                }
+                return Ok(record.remittance_id);
+            }
+        }
+
+        let remittance_id = execute_create_remittance(
+            &env,
+            &sender,
+            &agent,
+            amount,
+            &token,
+            &currency,
+            &country,
+            expiry,
+            None,
+            None,
+        )?;
+
+        let expires_at = current_time
+            .checked_add(get_idempotency_ttl(&env))
+            .ok_or(ContractError::Overflow)?;
+        set_idempotency_record(
+            &env,
+            &IdempotencyRecord {
+                sender,
+                key: idempotency_key,
+                request_hash,
+                remittance_id,
+                expires_at,
+            },
+        );
+
+        Ok(remittance_id)
     }
 
-    /// Retrieves the current platform fee rate.
+    /// Updates how long idempotency records stay valid (Admin only)
+    pub fn update_idempotency_ttl(env: Env, caller: Address, ttl_seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_idempotency_ttl(&env, ttl_seconds);
+        Ok(())
+    }
+
+    /// Gets the idempotency record TTL in seconds
+    pub fn get_idempotency_ttl(env: Env) -> u64 {
+        get_idempotency_ttl(&env)
+    }
+
+    /// Gets the idempotency record a sender stored under a key, if any
+    pub fn get_idempotency_record(env: Env, sender: Address, idempotency_key: String) -> Option<IdempotencyRecord> {
+        get_idempotency_record(&env, &sender, &idempotency_key)
+    }
+
+    /// Confirms a remittance payout to the agent.
+    ///
+    /// Transfers the remittance amount (minus platform fee) to the agent and marks
+    /// the remittance as completed. Includes duplicate settlement protection and
+    /// expiry validation.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
     ///
     /// # Returns
     ///
-    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
-        get_platform_fee_bps(&env)
-    }
+    /// * `Ok(())` - Payout successfully confirmed and transferred
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    /// Requires Settler role.
+    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        // Centralized validation before business logic (returns remittance to avoid re-read)
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
 
-    /// Computes the deterministic settlement hash for a remittance.
-    /// 
-    /// This function allows external systems (banks, anchors, APIs) to compute
-    /// the same settlement hash that the contract uses internally. The hash is
-    /// computed using the canonical ordering specified in DETERMINISTIC_HASHING_SPEC.md.
-    /// 
-    /// External systems can use this to:
-    /// - Pre-compute settlement IDs before submission
-    /// - Verify on-chain settlement IDs match expected values
-    /// - Enable cross-system reconciliation using deterministic IDs
-    /// 
+        // Hash time-locked remittances must go through settle_htlc
+        if is_htlc(&env, remittance_id) {
+            return Err(ContractError::PreimageRequired);
+        }
+
+        // Oracle-attested settlements must go through confirm_payout_with_proof
+        if requires_proof(&env, &remittance) {
+            return Err(ContractError::MissingProof);
+        }
+
+        execute_confirm_payout(&env, remittance)
+    }
+
+    /// Confirms a remittance payout on the agent's behalf as one of its operators.
+    ///
+    /// The payout still goes to the agent assigned to the remittance. The
+    /// operator needs a current grant from that agent covering the remittance
+    /// amount; settlements that need a hashlock preimage or an oracle proof
+    /// cannot go through an operator.
+    ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - The remittance ID to compute hash for
+    /// * `operator` - Operator address the agent granted settlement rights to
+    /// * `remittance_id` - ID of the remittance to confirm
     ///
     /// # Returns
     ///
-    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// 
-    /// # Hash Input Ordering (Canonical)
-    /// 
-    /// 1. remittance_id (u64, big-endian)
-    /// 2. sender (Address, XDR-encoded)
-    /// 3. agent (Address, XDR-encoded)
-    /// 4. amount (i128, big-endian)
-    /// 5. fee (i128, big-endian)
-    /// 6. expiry (u64, big-endian, 0 if None)
-    /// 
-    /// # Examples
-    /// 
-    /// ```ignore
-    /// let settlement_hash = contract.compute_settlement_hash(&env, remittance_id)?;
-    /// // External system can verify this matches their computed hash
-    /// ```
-    pub fn compute_settlement_hash(env: Env, remittance_id: u64) -> Result<soroban_sdk::BytesN<32>, ContractError> {
-        let remittance = get_remittance(&env, remittance_id)?;
-        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+    /// * `Ok(())` - Payout transferred to the agent
+    /// * `Err(ContractError::OperatorNotFound)` - The agent made no grant to the operator
+    /// * `Err(ContractError::OperatorExpired)` - The operator's grant has expired
+    /// * `Err(ContractError::OperatorLimitExceeded)` - Remittance amount exceeds the grant's maximum
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the operator. The agent must hold the Settler role.
+    pub fn confirm_payout_as_operator(env: Env, operator: Address, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        if is_htlc(&env, remittance_id) {
+            return Err(ContractError::PreimageRequired);
+        }
+        if requires_proof(&env, &remittance) {
+            return Err(ContractError::MissingProof);
+        }
+
+        operator.require_auth();
+        validate_not_sanctioned(&env, &operator)?;
+        check_operator_grant(&env, &remittance.agent, &operator, remittance.amount)?;
+
+        settle_remittance(&env, remittance, Some(operator))
     }
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Confirms a remittance payout backed by a signed oracle proof.
+    ///
+    /// Verifies that `proof` is an ed25519 signature by a registered oracle over
+    /// the remittance's settlement ID (see `compute_settlement_hash`) and then
+    /// settles exactly like `confirm_payout`. Required for remittances or agents
+    /// configured to require proof; optional for all others.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `proof` - Oracle public key and signature over the settlement ID
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof accepted and payout transferred
+    /// * `Err(ContractError::OracleNotRegistered)` - Proof key is not a registered oracle
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    /// Requires Settler role. An invalid signature aborts the invocation.
+    pub fn confirm_payout_with_proof(
+        env: Env,
+        remittance_id: u64,
+        proof: ProofData,
+    ) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        if is_htlc(&env, remittance_id) {
+            return Err(ContractError::PreimageRequired);
+        }
 
-        set_paused(&env, true);
-        emit_paused(&env, caller);
-        Ok(())
+        verify_proof(&env, &remittance, &proof)?;
+        emit_proof_verified(&env, remittance_id, proof.oracle);
+
+        execute_confirm_payout(&env, remittance)
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
+    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
+        let remittance = get_remittance(&env, remittance_id)?;
 
-        set_paused(&env, false);
-        emit_unpaused(&env, caller);
+        // Verify remittance is in a valid state (Completed)
+        if remittance.status != RemittanceStatus::Completed {
+            return Err(ContractError::InvalidStateTransition);
+        }
+
+        // Remittance is already completed, no further action needed
         Ok(())
     }
 
-    // ── Escrow Functions ───────────────────────────────────────────
+    /// Cancels a pending remittance and refunds the sender.
+    ///
+    /// Returns the full remittance amount to the sender and marks the remittance
+    /// as cancelled. Can only be called by the original sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully cancelled and refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::RemittanceAccepted)` - An agent holds an unlapsed acceptance
+    /// * `Err(ContractError::TimelockActive)` - Remittance is hash time-locked and its timelock has not passed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        // An agent's acceptance blocks cancellation until it lapses
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status == RemittanceStatus::Processing
+            && !revert_lapsed_acceptance(&env, &mut remittance)?
+        {
+            return Err(ContractError::RemittanceAccepted);
+        }
 
-    pub fn create_escrow(
-        env: Env,
-        sender: Address,
-        recipient: Address,
-        amount: i128,
-    ) -> Result<u64, ContractError> {
-        sender.require_auth();
-        
-        if amount <= 0 {
-            return Err(ContractError::InvalidAmount);
+        // Centralized validation before business logic
+        validate_cancel_remittance_request(&env, remittance_id)?;
+
+        // Hash time-locked remittances stay locked until their timelock passes
+        if is_htlc(&env, remittance_id) && !is_expirable(&env, &remittance) {
+            return Err(ContractError::TimelockActive);
         }
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+        remittance.sender.require_auth();
+        validate_not_sanctioned(&env, &remittance.sender)?;
 
-        let counter = get_escrow_counter(&env)?;
-        let transfer_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &remittance.amount,
+        );
+        refund_integrator_fee(&env, &remittance);
 
-        let escrow = Escrow {
-            transfer_id,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            amount,
-            status: EscrowStatus::Pending,
-        };
+        // Transition to Cancelled (Refunded in the transfer registry)
+        transition_remittance(&env, &mut remittance, RemittanceStatus::Cancelled)?;
+        if remittance.agent != env.current_contract_address() {
+            release_agent_exposure(&env, &remittance.agent, &remittance.token, remittance.amount);
+            record_agent_cancellation(&env, &remittance.agent)?;
+        }
 
-        set_escrow(&env, transfer_id, &escrow);
-        set_escrow_counter(&env, transfer_id);
+        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
+        // Used by off-chain systems to track cancellations and update transaction status
+        emit_remittance_cancelled(&env, remittance_id, remittance.sender, remittance.agent, remittance.token, remittance.amount);
 
-        emit_escrow_created(&env, transfer_id, sender, recipient, amount);
+        log_cancel_remittance(&env, remittance_id);
 
-        Ok(transfer_id)
+        Ok(())
     }
 
-    pub fn release_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
-        let mut escrow = get_escrow(&env, transfer_id)?;
-        
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Accepts a pending remittance, locking it while the agent pays out.
+    ///
+    /// Moves the remittance to Processing for the configured acceptance timeout.
+    /// While the acceptance holds, the sender cannot cancel. Once it lapses the
+    /// remittance returns to Pending the next time it is touched, or through
+    /// `revert_lapsed_acceptance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to accept
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(deadline)` - Timestamp at which the acceptance lapses
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending
+    /// * `Err(ContractError::RemittanceAccepted)` - Remittance is already accepted
+    /// * `Err(ContractError::SettlementExpired)` - Remittance has expired
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent assigned to the remittance.
+    pub fn accept_remittance(env: Env, remittance_id: u64) -> Result<u64, ContractError> {
+        validate_not_paused(&env)?;
+        let mut remittance = get_remittance(&env, remittance_id)?;
 
-        if escrow.status != EscrowStatus::Pending {
-            return Err(ContractError::InvalidEscrowStatus);
+        remittance.agent.require_auth();
+
+        if remittance.status == RemittanceStatus::Processing
+            && !revert_lapsed_acceptance(&env, &mut remittance)?
+        {
+            return Err(ContractError::RemittanceAccepted);
         }
+        validate_remittance_pending(&remittance)?;
+        validate_settlement_not_expired(&env, remittance.expiry)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &escrow.recipient, &escrow.amount);
+        transition_remittance(&env, &mut remittance, RemittanceStatus::Processing)?;
 
-        escrow.status = EscrowStatus::Released;
-        set_escrow(&env, transfer_id, &escrow);
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .checked_add(get_acceptance_timeout(&env))
+            .ok_or(ContractError::Overflow)?;
+        set_acceptance_deadline(&env, remittance_id, deadline);
 
-        emit_escrow_released(&env, transfer_id, escrow.recipient, escrow.amount);
+        emit_remittance_accepted(&env, remittance_id, remittance.agent, deadline);
 
-        Ok(())
+        Ok(deadline)
     }
 
-    pub fn refund_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
-        let mut escrow = get_escrow(&env, transfer_id)?;
-        
-        escrow.sender.require_auth();
+    /// Reports that an accepted remittance could not be paid out and refunds the sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the accepted remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance marked Failed and sender refunded
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Processing status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent assigned to the remittance.
+    pub fn fail_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
 
-        if escrow.status != EscrowStatus::Pending {
-            return Err(ContractError::InvalidEscrowStatus);
-        }
+        remittance.agent.require_auth();
+        validate_not_sanctioned(&env, &remittance.sender)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &escrow.sender, &escrow.amount);
+        transition_remittance(&env, &mut remittance, RemittanceStatus::Failed)?;
+        remove_acceptance_deadline(&env, remittance_id);
+        release_agent_exposure(&env, &remittance.agent, &remittance.token, remittance.amount);
 
-        escrow.status = EscrowStatus::Refunded;
-        set_escrow(&env, transfer_id, &escrow);
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &remittance.amount,
+        );
+        refund_integrator_fee(&env, &remittance);
 
-        emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
+        emit_remittance_failed(&env, remittance_id, remittance.sender, remittance.agent, remittance.amount);
 
         Ok(())
     }
 
-    pub fn get_escrow(env: Env, transfer_id: u64) -> Result<Escrow, ContractError> {
-        get_escrow(&env, transfer_id)
+    /// Returns a remittance whose agent acceptance lapsed to Pending.
+    ///
+    /// Permissionless, so keepers can release remittances abandoned by agents.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance is Pending again
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Processing status
+    /// * `Err(ContractError::RemittanceAccepted)` - The acceptance has not lapsed yet
+    pub fn revert_lapsed_acceptance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Processing {
+            return Err(ContractError::InvalidStatus);
+        }
+        if !revert_lapsed_acceptance(&env, &mut remittance)? {
+            return Err(ContractError::RemittanceAccepted);
+        }
+        Ok(())
     }
 
-
-    pub fn is_paused(env: Env) -> bool {
-        crate::storage::is_paused(&env)
+    /// Gets the timestamp at which an agent's acceptance of a remittance lapses, if accepted.
+    pub fn get_acceptance_deadline(env: Env, remittance_id: u64) -> Option<u64> {
+        get_acceptance_deadline(&env, remittance_id)
     }
-    
-    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
-
-        set_rate_limit_cooldown(&env, cooldown_seconds);
 
+    /// Updates how long an agent acceptance stays valid (Admin only)
+    pub fn update_acceptance_timeout(env: Env, caller: Address, timeout_seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        if timeout_seconds == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        set_acceptance_timeout(&env, timeout_seconds);
         Ok(())
     }
-    
-    pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
-        get_rate_limit_cooldown(&env)
-    }
-    
-    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
-        get_last_settlement_time(&env, &sender)
-    }
 
-    pub fn get_version(env: Env) -> soroban_sdk::String {
-        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    /// Gets the agent acceptance timeout in seconds
+    pub fn get_acceptance_timeout(env: Env) -> u64 {
+        get_acceptance_timeout(&env)
     }
 
-    /// Batch settle multiple remittances with net settlement optimization.
-    /// 
-    /// This function processes multiple remittances in a single transaction and applies
-    /// net settlement logic to offset opposing transfers between the same parties.
-    /// Only the net difference is executed on-chain, reducing total token transfers.
-    /// 
-    /// # Benefits
-    /// - Reduces on-chain transfer count by offsetting opposing flows
-    /// - Preserves all fees and accounting integrity
-    /// - Deterministic and order-independent results
-    /// - Gas-efficient batch processing
-    /// 
-    /// # Example
-    /// If batch contains:
-    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
-    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
+    /// Expires and refunds a list of remittances that passed their expiry unsettled.
+    ///
+    /// Permissionless so that keepers can release stale funds. IDs that are not
+    /// pending or not yet expired are skipped, so a batch never fails because
+    /// one remittance was settled or cancelled in the meantime.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_ids` - IDs to expire (at most `MAX_BATCH_SIZE`)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs that were expired and refunded
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `Err(ContractError::RemittanceNotFound)` - An ID does not exist
+    pub fn expire_remittances(env: Env, remittance_ids: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+        if remittance_ids.is_empty() || remittance_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut expired_ids = Vec::new(&env);
+        for remittance_id in remittance_ids.iter() {
+            let mut remittance = get_remittance(&env, remittance_id)?;
+            if remittance.status == RemittanceStatus::Processing {
+                revert_lapsed_acceptance(&env, &mut remittance)?;
+            }
+            if is_expirable(&env, &remittance) && execute_expire_remittance(&env, remittance)? {
+                expired_ids.push_back(remittance_id);
+            }
+        }
+
+        Ok(expired_ids)
+    }
+
+    /// Scans pending remittances and expires those past their expiry.
+    ///
+    /// Permissionless. Walks the pending status index starting at `cursor`,
+    /// examining at most `limit` entries (capped at `MAX_BATCH_SIZE`). Keepers
+    /// pass the returned `next_cursor` to continue and start again from 0 once
+    /// `done` is true.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SweepResult)` - Expired IDs and the cursor to resume from
+    /// * `Err(ContractError::InvalidAmount)` - `limit` is zero
+    pub fn sweep_expired(env: Env, cursor: u32, limit: u32) -> Result<SweepResult, ContractError> {
+        if limit == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        let limit = limit.min(MAX_BATCH_SIZE);
+
+        let mut expired_ids = Vec::new(&env);
+        let mut position = cursor;
+        let mut examined = 0;
+        while examined < limit && position < get_status_index_len(&env, &RemittanceStatus::Pending) {
+            let remittance_id = get_status_index_entry(&env, &RemittanceStatus::Pending, position);
+            let remittance = get_remittance(&env, remittance_id)?;
+            if is_expirable(&env, &remittance) {
+                // Expiring or freezing swaps the last pending entry into this
+                // position, so the position is examined again on the next iteration.
+                if execute_expire_remittance(&env, remittance)? {
+                    expired_ids.push_back(remittance_id);
+                }
+            } else {
+                position += 1;
+            }
+            examined += 1;
+        }
+
+        Ok(SweepResult {
+            expired_ids,
+            next_cursor: position,
+            done: position >= get_status_index_len(&env, &RemittanceStatus::Pending),
+        })
+    }
+
+    /// Withdraws accumulated platform fees in one token to a specified address.
+    ///
+    /// Transfers all fees accumulated in `token` to the recipient address and
+    /// resets that token's fee counter to zero. Only the contract admin can
+    /// withdraw fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the withdrawn fees
+    /// * `token` - Token whose accumulated fees are withdrawn
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
+    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_fees(env: Env, to: Address, token: Address) -> Result<(), ContractError> {
+        // Centralized validation before business logic (returns fees to avoid re-read)
+        let fees = validate_withdraw_fees_request(&env, &to, &token)?;
+        
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        validate_not_sanctioned(&env, &to)?;
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &to, &fees);
+
+        set_accumulated_fees(&env, &token, 0);
+
+        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
+        // Used by off-chain systems to track revenue collection and maintain financial records
+        emit_fees_withdrawn(&env, caller, to.clone(), token, fees);
+
+        log_withdraw_fees(&env, &to, fees);
+
+        Ok(())
+    }
+
+    /// Retrieves a remittance record by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Retrieves a page of remittances created by a sender, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender address to filter by
+    /// * `page` - 1-based page number
+    /// * `limit` - Page size, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RemittancePage)` - Remittances on the page with paging metadata
+    /// * `Err(ContractError::InvalidPagination)` - `page` or `limit` is zero
+    pub fn get_remittances_by_sender(
+        env: Env,
+        sender: Address,
+        page: u32,
+        limit: u32,
+    ) -> Result<RemittancePage, ContractError> {
+        get_remittances_by_sender(&env, &sender, page, limit)
+    }
+
+    /// Retrieves a page of remittances assigned to an agent, oldest first.
+    ///
+    /// See `get_remittances_by_sender` for paging semantics.
+    pub fn get_remittances_by_agent(
+        env: Env,
+        agent: Address,
+        page: u32,
+        limit: u32,
+    ) -> Result<RemittancePage, ContractError> {
+        get_remittances_by_agent(&env, &agent, page, limit)
+    }
+
+    /// Retrieves a page of remittances currently in a status.
+    ///
+    /// See `get_remittances_by_sender` for paging semantics. Ordering within a
+    /// status is not stable as remittances move between statuses.
+    pub fn get_remittances_by_status(
+        env: Env,
+        status: RemittanceStatus,
+        page: u32,
+        limit: u32,
+    ) -> Result<RemittancePage, ContractError> {
+        get_remittances_by_status(&env, &status, page, limit)
+    }
+
+
+    /// Gets the platform fees accumulated in a token and not yet withdrawn
+    pub fn get_accumulated_fees(env: Env, token: Address) -> i128 {
+        get_accumulated_fees(&env, &token)
+    }
+
+    /// Checks if an address is registered as an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address to check
+    ///
+    /// # Returns
+    ///
+    /// * `true` - Address is a registered agent
+    /// * `false` - Address is not registered
+    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
+        is_agent_registered(&env, &agent)
+    }
+
+    /// Retrieves the current platform fee rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
+        get_platform_fee_bps(&env)
+    }
+
+    /// Computes the deterministic settlement hash for a remittance.
     /// 
-    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
+    /// This function allows external systems (banks, anchors, APIs) to compute
+    /// the same settlement hash that the contract uses internally. The hash is
+    /// computed using the canonical ordering specified in DETERMINISTIC_HASHING_SPEC.md.
     /// 
-    /// # Parameters
-    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    /// External systems can use this to:
+    /// - Pre-compute settlement IDs before submission
+    /// - Verify on-chain settlement IDs match expected values
+    /// - Enable cross-system reconciliation using deterministic IDs
     /// 
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - The remittance ID to compute hash for
+    ///
     /// # Returns
-    /// BatchSettlementResult with list of successfully settled remittance IDs
+    ///
+    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
     /// 
-    /// # Errors
-    /// - ContractPaused: Contract is in paused state
-    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
-    /// - RemittanceNotFound: One or more remittance IDs don't exist
-    /// - InvalidStatus: One or more remittances are not in Pending status
-    /// - DuplicateSettlement: Duplicate remittance IDs in batch
-    /// - Overflow: Arithmetic overflow in calculations
-    pub fn batch_settle_with_netting(
-        env: Env,
-        entries: Vec<BatchSettlementEntry>,
-    ) -> Result<BatchSettlementResult, ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::ContractPaused);
-        }
+    /// # Hash Input Ordering (Canonical)
+    /// 
+    /// 1. remittance_id (u64, big-endian)
+    /// 2. sender (Address, XDR-encoded)
+    /// 3. agent (Address, XDR-encoded)
+    /// 4. amount (i128, big-endian)
+    /// 5. fee (i128, big-endian)
+    /// 6. expiry (u64, big-endian, 0 if None)
+    /// 
+    /// # Examples
+    /// 
+    /// ```ignore
+    /// let settlement_hash = contract.compute_settlement_hash(&env, remittance_id)?;
+    /// // External system can verify this matches their computed hash
+    /// ```
+    pub fn compute_settlement_hash(env: Env, remittance_id: u64) -> Result<soroban_sdk::BytesN<32>, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+    }
 
-        // Validate batch size
-        let batch_size = entries.len();
-        if batch_size == 0 {
+    pub fn pause(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_paused(&env, true);
+        emit_paused(&env, caller);
+        Ok(())
+    }
+
+    pub fn unpause(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_paused(&env, false);
+        emit_unpaused(&env, caller);
+        Ok(())
+    }
+
+    // ── Escrow Functions ───────────────────────────────────────────
+
+    pub fn create_escrow(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        amount: i128,
+        token: Address,
+    ) -> Result<u64, ContractError> {
+        sender.require_auth();
+        
+        if amount <= 0 {
             return Err(ContractError::InvalidAmount);
         }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidAmount);
+        validate_token_whitelisted(&env, &token)?;
+        validate_not_sanctioned(&env, &sender)?;
+        validate_not_sanctioned(&env, &recipient)?;
+        check_and_record_kyc_usage(&env, &sender, &token, amount)?;
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+
+        let counter = get_escrow_counter(&env)?;
+        let transfer_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+        let escrow = Escrow {
+            transfer_id,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            amount,
+            token,
+            status: EscrowStatus::Pending,
+        };
+
+        set_escrow(&env, transfer_id, &escrow);
+        set_escrow_counter(&env, transfer_id);
+
+        emit_escrow_created(&env, transfer_id, sender, recipient, amount);
+
+        Ok(transfer_id)
+    }
+
+    pub fn release_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
+        
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ContractError::InvalidEscrowStatus);
         }
+        validate_not_sanctioned(&env, &escrow.sender)?;
+        validate_not_sanctioned(&env, &escrow.recipient)?;
 
-        // Load all remittances and validate
-        let mut remittances = Vec::new(&env);
-        let mut seen_ids = Vec::new(&env);
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &escrow.recipient, &escrow.amount);
 
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
+        escrow.status = EscrowStatus::Released;
+        set_escrow(&env, transfer_id, &escrow);
+
+        emit_escrow_released(&env, transfer_id, escrow.recipient, escrow.amount);
+
+        Ok(())
+    }
+
+    pub fn refund_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
+        
+        escrow.sender.require_auth();
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ContractError::InvalidEscrowStatus);
+        }
+        validate_not_sanctioned(&env, &escrow.sender)?;
+
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &escrow.sender, &escrow.amount);
+
+        escrow.status = EscrowStatus::Refunded;
+        set_escrow(&env, transfer_id, &escrow);
+
+        emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
+
+        Ok(())
+    }
+
+    pub fn get_escrow(env: Env, transfer_id: u64) -> Result<Escrow, ContractError> {
+        get_escrow(&env, transfer_id)
+    }
+
+
+    pub fn is_paused(env: Env) -> bool {
+        crate::storage::is_paused(&env)
+    }
+    
+    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        set_rate_limit_cooldown(&env, cooldown_seconds);
+
+        Ok(())
+    }
+    
+    pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
+        get_rate_limit_cooldown(&env)
+    }
+    
+    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
+        get_last_settlement_time(&env, &sender)
+    }
+
+    pub fn get_version(env: Env) -> soroban_sdk::String {
+        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Batch settle multiple remittances with net settlement optimization.
+    /// 
+    /// This function processes multiple remittances in a single transaction and applies
+    /// net settlement logic to offset opposing transfers between the same parties.
+    /// Only the net difference is executed on-chain, reducing total token transfers.
+    /// 
+    /// # Benefits
+    /// - Reduces on-chain transfer count by offsetting opposing flows
+    /// - Preserves all fees and accounting integrity
+    /// - Deterministic and order-independent results
+    /// - Gas-efficient batch processing
+    /// 
+    /// # Example
+    /// If batch contains:
+    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
+    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
+    /// 
+    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
+    /// 
+    /// # Parameters
+    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    /// - `token`: Token every remittance in the batch is denominated in
+    /// 
+    /// # Returns
+    /// BatchSettlementResult with list of successfully settled remittance IDs
+    /// 
+    /// # Errors
+    /// - ContractPaused: Contract is in paused state
+    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
+    /// - RemittanceNotFound: One or more remittance IDs don't exist
+    /// - InvalidStatus: One or more remittances are not in Pending status
+    /// - DuplicateSettlement: Duplicate remittance IDs in batch
+    /// - TokenMismatch: A remittance is denominated in another token
+    /// - ClaimCodeRequired: A remittance is an unclaimed open claim
+    /// - Overflow: Arithmetic overflow in calculations
+    pub fn batch_settle_with_netting(
+        env: Env,
+        entries: Vec<BatchSettlementEntry>,
+        token: Address,
+    ) -> Result<BatchSettlementResult, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
+
+        // Validate batch size
+        let batch_size = entries.len();
+        if batch_size == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if batch_size > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Load all remittances and validate
+        let mut remittances = Vec::new(&env);
+        let mut seen_ids = Vec::new(&env);
+
+        for i in 0..batch_size {
+            let entry = entries.get_unchecked(i);
             let remittance_id = entry.remittance_id;
 
-            // Check for duplicate IDs in batch
-            for j in 0..seen_ids.len() {
-                if seen_ids.get_unchecked(j) == remittance_id {
-                    return Err(ContractError::DuplicateSettlement);
-                }
-            }
-            seen_ids.push_back(remittance_id);
+            // Check for duplicate IDs in batch
+            for j in 0..seen_ids.len() {
+                if seen_ids.get_unchecked(j) == remittance_id {
+                    return Err(ContractError::DuplicateSettlement);
+                }
+            }
+            seen_ids.push_back(remittance_id);
+
+            // Load and validate remittance
+            let remittance = get_remittance(&env, remittance_id)?;
+
+            // Verify remittance is pending or accepted
+            validate_remittance_settleable(&remittance)?;
+
+            // Netting only offsets flows within a single token
+            if remittance.token != token {
+                return Err(ContractError::TokenMismatch);
+            }
+
+            // Hash time-locked remittances settle only by revealing their preimage
+            if is_htlc(&env, remittance_id) {
+                return Err(ContractError::PreimageRequired);
+            }
+
+            // Open claims are paid out only to the agent presenting the claim code
+            if get_claim_hash(&env, remittance_id).is_some() {
+                return Err(ContractError::ClaimCodeRequired);
+            }
+
+            // Remittances gated on an oracle proof cannot be netted without one
+            if requires_proof(&env, &remittance) {
+                return Err(ContractError::MissingProof);
+            }
+
+            // Check for duplicate settlement execution
+            if has_settlement_hash(&env, remittance_id) {
+                return Err(ContractError::DuplicateSettlement);
+            }
+
+            // Check expiry
+            if let Some(expiry_time) = remittance.expiry {
+                let current_time = env.ledger().timestamp();
+                if current_time > expiry_time {
+                    return Err(ContractError::SettlementExpired);
+                }
+            }
+
+            // Validate addresses
+            validate_address(&remittance.agent)?;
+            if get_sanctioned_party(&env, &remittance).is_some() {
+                return Err(ContractError::AddressSanctioned);
+            }
+
+            remittances.push_back(remittance);
+        }
+
+        // Compute net settlements
+        let net_transfers = compute_net_settlements(&env, &remittances);
+
+        // Validate net settlement calculations
+        validate_net_settlement(&remittances, &net_transfers)?;
+
+        // Batch read storage values once
+        let mut current_fees = get_accumulated_fees(&env, &token);
+        
+        let token_client = token::Client::new(&env, &token);
+
+        // Execute net transfers
+        for i in 0..net_transfers.len() {
+            let transfer = net_transfers.get_unchecked(i);
+
+            // Determine actual sender and recipient based on net_amount sign
+            let (from, to, amount) = if transfer.net_amount > 0 {
+                // Positive: party_a -> party_b
+                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
+            } else if transfer.net_amount < 0 {
+                // Negative: party_b -> party_a
+                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
+            } else {
+                // Zero: complete offset, no transfer needed
+                continue;
+            };
+
+            // Calculate payout amount (net amount minus fees)
+            let payout_amount = compute_payout_amount(amount, transfer.total_fees, 0)?;
+
+            // Execute the net transfer from contract to recipient
+            token_client.transfer(
+                &env.current_contract_address(),
+                &to,
+                &payout_amount,
+            );
+
+            // Accumulate fees in memory
+            current_fees = current_fees
+                .checked_add(transfer.total_fees)
+                .ok_or(ContractError::Overflow)?;
+
+            // Emit settlement event (using remittance ID from the transfer)
+            // Note: In batch processing, we use the first remittance ID as reference
+            let remittance_id = if i < remittances.len() {
+                remittances.get_unchecked(i).id
+            } else {
+                0
+            };
+            emit_settlement_completed(&env, remittance_id, from, to, token.clone(), payout_amount);
+        }
+
+        // Mark all remittances as completed and set settlement hashes
+        let mut settled_ids = Vec::new(&env);
+
+        for i in 0..remittances.len() {
+            let mut remittance = remittances.get_unchecked(i);
+            if remittance.status == RemittanceStatus::Pending {
+                transition_remittance(&env, &mut remittance, RemittanceStatus::Processing)?;
+            }
+            transition_remittance(&env, &mut remittance, RemittanceStatus::Completed)?;
+            remove_acceptance_deadline(&env, remittance.id);
+            set_settlement_hash(&env, remittance.id);
+            settled_ids.push_back(remittance.id);
+
+            let payout_amount = remittance
+                .amount
+                .checked_sub(remittance.fee)
+                .ok_or(ContractError::Overflow)?;
+            record_agent_settlement(
+                &env,
+                &remittance.agent,
+                &remittance.token,
+                remittance.amount,
+                remittance.created_at,
+            )?;
+            release_agent_exposure(&env, &remittance.agent, &remittance.token, remittance.amount);
+            accrue_integrator_fee(&env, &remittance)?;
+
+            // Netted transfers carry whole fees; pay each agent's commission separately
+            let (commission, commission_paid) = carve_agent_commission(&env, &remittance)?;
+            if commission_paid > 0 {
+                token_client.transfer(&env.current_contract_address(), &remittance.agent, &commission_paid);
+            }
+            current_fees = current_fees
+                .checked_sub(commission)
+                .ok_or(ContractError::Overflow)?;
+            record_settlement(&env, remittance.id, payout_amount, commission);
+
+            // Emit individual remittance completion event
+            emit_remittance_completed(
+                &env,
+                remittance.id,
+                remittance.sender,
+                remittance.agent,
+            );
+        }
+
+        // Write accumulated fees once at the end
+        set_accumulated_fees(&env, &token, current_fees);
+
+        Ok(BatchSettlementResult { settled_ids })
+    }
+
+    /// Add a token to the whitelist. Only admins can call this.
+    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_admin_operation(&env, &caller, &token)?;
+
+        if is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenAlreadyWhitelisted);
+        }
+
+        set_token_whitelisted(&env, &token, true);
+        
+        Ok(())
+    }
+
+    /// Remove a token from the whitelist. Only admins can call this.
+    pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_admin_operation(&env, &caller, &token)?;
+
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+
+        set_token_whitelisted(&env, &token, false);
+        
+        Ok(())
+    }
+
+    /// Check if a token is whitelisted.
+    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
+        is_token_whitelisted(&env, &token)
+    }
+
+    /// Update rate limit configuration. Only admins can call this.
+    /// 
+    /// # Parameters
+    /// - `caller`: Admin address (must be authorized)
+    /// - `max_requests`: Maximum number of requests allowed per window
+    /// - `window_seconds`: Time window in seconds
+    /// - `enabled`: Whether rate limiting is enabled
+    /// 
+    /// # Example
+    /// ```ignore
+    /// // Set rate limit to 50 requests per 30 seconds
+    /// contract.update_rate_limit_config(&admin, 50, 30, true)?;
+    /// ```
+    pub fn update_rate_limit_config(
+        env: Env,
+        caller: Address,
+        max_requests: u32,
+        window_seconds: u64,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        let config = RateLimitConfig {
+            max_requests,
+            window_seconds,
+            enabled,
+        };
+
+        set_rate_limit_config(&env, config);
+
+        Ok(())
+    }
+
+    /// Get current rate limit configuration
+    /// 
+    /// # Returns
+    /// Tuple of (max_requests, window_seconds, enabled)
+    pub fn get_rate_limit_config(env: Env) -> (u32, u64, bool) {
+        let config = get_rate_limit_config(&env);
+        (config.max_requests, config.window_seconds, config.enabled)
+    }
+
+    /// Get rate limit status for a specific address
+    /// 
+    /// # Parameters
+    /// - `address`: Address to check
+    /// 
+    /// # Returns
+    /// Tuple of (current_requests, max_requests, window_seconds)
+    pub fn get_rate_limit_status(env: Env, address: Address) -> (u32, u32, u64) {
+        get_rate_limit_status(&env, &address)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Protocol Fee Management
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Updates the protocol fee (Admin only, max 200 bps)
+    pub fn update_protocol_fee(env: Env, caller: Address, fee_bps: u32) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_admin(&env, &caller)?;
+        set_protocol_fee_bps(&env, fee_bps)?;
+        Ok(())
+    }
+
+    /// Updates the treasury address (Admin only)
+    pub fn update_treasury(env: Env, caller: Address, treasury: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_admin(&env, &caller)?;
+        set_treasury(&env, &treasury);
+        Ok(())
+    }
+
+    /// Gets the current protocol fee in basis points
+    pub fn get_protocol_fee_bps(env: Env) -> u32 {
+        get_protocol_fee_bps(&env)
+    }
+
+    /// Gets the treasury address
+    pub fn get_treasury(env: Env) -> Result<Address, ContractError> {
+        get_treasury(&env)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Role-Based Authorization Functions
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Assigns a role to an address (Admin only)
+    pub fn assign_role(env: Env, caller: Address, address: Address, role: Role) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        assign_role(&env, &address, &role);
+        Ok(())
+    }
+
+    /// Removes a role from an address (Admin only)
+    pub fn remove_role(env: Env, caller: Address, address: Address, role: Role) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        remove_role(&env, &address, &role);
+        Ok(())
+    }
+
+    /// Checks if an address has a specific role
+    pub fn has_role(env: Env, address: Address, role: Role) -> bool {
+        has_role(&env, &address, &role)
+    }
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Strategy Management
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Updates the fee strategy (Admin only)
+    /// 
+    /// Allows switching between different fee calculation methods:
+    /// - Percentage: Fee based on basis points (e.g., 250 = 2.5%)
+    /// - Flat: Fixed fee amount regardless of transaction size
+    /// - Dynamic: Tiered fee that decreases for larger amounts
+    /// - Tiered: Configured schedule of `(upper_bound, bps, flat_component)` tiers
+    /// 
+    /// # Arguments
+    /// * `caller` - Admin address (must be authorized)
+    /// * `strategy` - New fee strategy to apply
+    /// 
+    /// # Errors
+    /// * `InvalidFeeBps` - A rate exceeds 10000 bps
+    /// * `InvalidAmount` - A flat fee is negative
+    /// * `InvalidFeeTiers` - Tiers are empty, too many, not strictly ascending or have a negative flat component
+    /// 
+    /// # Examples
+    /// ```ignore
+    /// // Set 2.5% percentage fee
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Percentage(250))?;
+    /// 
+    /// // Set flat 100 USDC fee
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Flat(100_0000000))?;
+    /// 
+    /// // Set dynamic tiered fee starting at 4%
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Dynamic(400))?;
+    /// 
+    /// // Set 3% up to 1000 USDC, then 1.5% plus 1 USDC above
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Tiered(vec![
+    ///     &env,
+    ///     FeeTier { upper_bound: 1000_0000000, bps: 300, flat_component: 0 },
+    ///     FeeTier { upper_bound: i128::MAX, bps: 150, flat_component: 1_0000000 },
+    /// ]))?;
+    /// ```
+    pub fn update_fee_strategy(env: Env, caller: Address, strategy: FeeStrategy) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_fee_strategy(&strategy)?;
+        set_fee_strategy(&env, &strategy);
+        Ok(())
+    }
+    
+    /// Gets the current fee strategy
+    pub fn get_fee_strategy(env: Env) -> FeeStrategy {
+        get_fee_strategy(&env)
+    }
+
+    /// Sets the minimum and maximum fee the global fee strategy is clamped to (Admin only)
+    ///
+    /// # Errors
+    /// * `InvalidFeeLimits` - A limit is negative or the maximum is below the minimum
+    pub fn set_fee_limits(env: Env, caller: Address, limits: FeeLimits) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_fee_limits(&limits)?;
+        set_global_fee_limits(&env, &limits);
+        Ok(())
+    }
+
+    /// Gets the limits the global fee strategy is clamped to
+    pub fn get_fee_limits(env: Env) -> FeeLimits {
+        get_global_fee_limits(&env)
+    }
+
+    /// Sets the fee schedule for a corridor, overriding the token and global schedules (Admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address (must be authorized)
+    /// * `currency` - Payout currency of the corridor
+    /// * `country` - Destination country of the corridor
+    /// * `schedule` - Strategy and limits pricing remittances in the corridor
+    ///
+    /// # Errors
+    /// * `InvalidCorridor` - Currency or country is empty
+    /// * `InvalidFeeBps`, `InvalidAmount`, `InvalidFeeTiers` - Strategy is invalid
+    /// * `InvalidFeeLimits` - Limits are invalid
+    pub fn set_corridor_fee_schedule(
+        env: Env,
+        caller: Address,
+        currency: String,
+        country: String,
+        schedule: FeeSchedule,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_corridor(&currency, &country)?;
+        validate_fee_schedule(&schedule)?;
+
+        set_corridor_fee_schedule(&env, &currency, &country, Some(&schedule));
+        Ok(())
+    }
+
+    /// Removes a corridor's fee schedule (Admin only)
+    pub fn remove_corridor_fee_schedule(
+        env: Env,
+        caller: Address,
+        currency: String,
+        country: String,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_corridor_fee_schedule(&env, &currency, &country, None);
+        Ok(())
+    }
+
+    /// Gets the fee schedule configured for a corridor, if any
+    pub fn get_corridor_fee_schedule(env: Env, currency: String, country: String) -> Option<FeeSchedule> {
+        get_corridor_fee_schedule(&env, &currency, &country)
+    }
+
+    /// Sets the fee schedule for a token, overriding the global schedule (Admin only)
+    ///
+    /// # Errors
+    /// * `InvalidFeeBps`, `InvalidAmount`, `InvalidFeeTiers` - Strategy is invalid
+    /// * `InvalidFeeLimits` - Limits are invalid
+    pub fn set_token_fee_schedule(
+        env: Env,
+        caller: Address,
+        token: Address,
+        schedule: FeeSchedule,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_fee_schedule(&schedule)?;
+
+        set_token_fee_schedule(&env, &token, Some(&schedule));
+        Ok(())
+    }
+
+    /// Removes a token's fee schedule (Admin only)
+    pub fn remove_token_fee_schedule(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_token_fee_schedule(&env, &token, None);
+        Ok(())
+    }
+
+    /// Gets the fee schedule configured for a token, if any
+    pub fn get_token_fee_schedule(env: Env, token: Address) -> Option<FeeSchedule> {
+        get_token_fee_schedule(&env, &token)
+    }
+
+    /// Gets the fee schedule that prices remittances in a token and corridor
+    ///
+    /// The corridor's schedule applies first, then the token's, then the
+    /// global strategy with the global limits.
+    pub fn get_effective_fee_schedule(env: Env, token: Address, currency: String, country: String) -> FeeSchedule {
+        resolve_fee_schedule(&env, &token, Some((&currency, &country)))
+    }
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Transfer State Registry (Read-Only for Indexers)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Gets the current state of a transfer (read-only for indexers)
+    pub fn get_transfer_state(env: Env, transfer_id: u64) -> Option<TransferState> {
+        get_transfer_state(&env, transfer_id)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Disputes
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Opens a dispute on a completed remittance whose cash was not handed over.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the completed remittance
+    /// * `evidence_hash` - Hash of the off-chain evidence supporting the claim
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Dispute opened
+    /// * `Err(ContractError::DisputeWindowClosed)` - Remittance is not completed or the window has passed
+    /// * `Err(ContractError::DisputeAlreadyExists)` - A dispute was already opened
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn open_dispute(env: Env, remittance_id: u64, evidence_hash: BytesN<32>) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        if get_dispute(&env, remittance_id).is_some() {
+            return Err(ContractError::DisputeAlreadyExists);
+        }
+
+        let settlement = get_settlement_record(&env, remittance_id)
+            .filter(|_| remittance.status == RemittanceStatus::Completed)
+            .ok_or(ContractError::DisputeWindowClosed)?;
+        let closes_at = settlement
+            .completed_at
+            .saturating_add(get_dispute_window(&env));
+        if env.ledger().timestamp() > closes_at {
+            return Err(ContractError::DisputeWindowClosed);
+        }
+
+        let open_disputes = get_open_dispute_count(&env, &remittance.agent);
+        set_open_dispute_count(&env, &remittance.agent, open_disputes + 1);
+        set_dispute(&env, &Dispute {
+            remittance_id,
+            sender: remittance.sender.clone(),
+            agent: remittance.agent.clone(),
+            evidence_hash: evidence_hash.clone(),
+            opened_at: env.ledger().timestamp(),
+            status: DisputeStatus::Open,
+            arbitrator: None,
+            refunded_amount: 0,
+            resolved_at: None,
+        });
+
+        emit_dispute_opened(&env, remittance_id, remittance.sender, remittance.agent, evidence_hash);
+
+        Ok(())
+    }
+
+    /// Rules on an open dispute.
+    ///
+    /// `FeeRefunded` returns the platform's share of the remittance fee, the fee
+    /// minus any commission the agent earned on it, to the sender out of
+    /// accumulated fees. `AgentCharged` pays the sender back the payout the
+    /// agent received out of the agent's bond, which the contract holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `arbitrator` - Address holding the Arbitrator role
+    /// * `remittance_id` - ID of the disputed remittance
+    /// * `outcome` - Ruling to apply
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Ruling recorded and any refund paid
+    /// * `Err(ContractError::Unauthorized)` - Caller lacks the Arbitrator role
+    /// * `Err(ContractError::DisputeNotFound)` - No dispute was opened
+    /// * `Err(ContractError::DisputeAlreadyResolved)` - Dispute was already ruled on
+    /// * `Err(ContractError::InsufficientFees)` - Accumulated fees cannot cover the fee refund
+    /// * `Err(ContractError::InsufficientBond)` - Agent bond in the remittance's token cannot cover the charge
+    pub fn resolve_dispute(
+        env: Env,
+        arbitrator: Address,
+        remittance_id: u64,
+        outcome: DisputeOutcome,
+    ) -> Result<(), ContractError> {
+        arbitrator.require_auth();
+        require_role_arbitrator(&env, &arbitrator)?;
+
+        let mut dispute = get_dispute(&env, remittance_id).ok_or(ContractError::DisputeNotFound)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(ContractError::DisputeAlreadyResolved);
+        }
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        let settlement = get_settlement_record(&env, remittance_id).ok_or(ContractError::DisputeNotFound)?;
+        let token_client = token::Client::new(&env, &remittance.token);
+        let contract_address = env.current_contract_address();
+
+        let refunded_amount = match outcome {
+            DisputeOutcome::Rejected => 0,
+            DisputeOutcome::FeeRefunded => {
+                // The agent's commission never reached accumulated fees
+                let platform_fee = remittance
+                    .fee
+                    .checked_sub(settlement.commission)
+                    .ok_or(ContractError::Overflow)?;
+                let remaining_fees = get_accumulated_fees(&env, &remittance.token)
+                    .checked_sub(platform_fee)
+                    .filter(|remaining| *remaining >= 0)
+                    .ok_or(ContractError::InsufficientFees)?;
+                set_accumulated_fees(&env, &remittance.token, remaining_fees);
+
+                token_client.transfer(&contract_address, &dispute.sender, &platform_fee);
+                emit_dispute_refund(&env, remittance_id, contract_address, dispute.sender.clone(), platform_fee);
+                platform_fee
+            }
+            DisputeOutcome::AgentCharged => {
+                let payout = settlement.payout_amount;
+                deduct_slash(&env, &dispute.agent, &remittance.token, payout)?;
+
+                token_client.transfer(&contract_address, &dispute.sender, &payout);
+                emit_dispute_refund(&env, remittance_id, dispute.agent.clone(), dispute.sender.clone(), payout);
+                payout
+            }
+        };
+
+        dispute.status = DisputeStatus::Resolved(outcome.clone());
+        dispute.arbitrator = Some(arbitrator.clone());
+        dispute.refunded_amount = refunded_amount;
+        dispute.resolved_at = Some(env.ledger().timestamp());
+        set_dispute(&env, &dispute);
+        let open_disputes = get_open_dispute_count(&env, &dispute.agent);
+        set_open_dispute_count(&env, &dispute.agent, open_disputes.saturating_sub(1));
+
+        emit_dispute_resolved(&env, remittance_id, arbitrator, outcome, refunded_amount);
+
+        Ok(())
+    }
+
+    /// Gets the dispute opened for a remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Dispute)` - The dispute record
+    /// * `Err(ContractError::DisputeNotFound)` - No dispute was opened
+    pub fn get_dispute(env: Env, remittance_id: u64) -> Result<Dispute, ContractError> {
+        get_dispute(&env, remittance_id).ok_or(ContractError::DisputeNotFound)
+    }
+
+    /// Gets the settlement record of a completed remittance, if any
+    pub fn get_settlement_record(env: Env, remittance_id: u64) -> Option<SettlementRecord> {
+        get_settlement_record(&env, remittance_id)
+    }
+
+    /// Updates how long after completion a sender can open a dispute (Admin only)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Window updated
+    /// * `Err(ContractError::InvalidUnbondingDelay)` - The window is not shorter than the unbonding delay
+    pub fn update_dispute_window(env: Env, caller: Address, window_seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_unbonding_delay(get_unbonding_delay(&env), window_seconds)?;
+        set_dispute_window(&env, window_seconds);
+        Ok(())
+    }
+
+    /// Gets the dispute window in seconds
+    pub fn get_dispute_window(env: Env) -> u64 {
+        get_dispute_window(&env)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Corridor Daily Limits
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Sets the rolling 24-hour send limit per sender for a corridor (Admin only)
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address (must be authorized)
+    /// * `currency` - Payout currency of the corridor
+    /// * `country` - Destination country of the corridor
+    /// * `limit` - Maximum total amount a sender can remit in the corridor within 24 hours
+    pub fn set_daily_limit(
+        env: Env,
+        caller: Address,
+        currency: String,
+        country: String,
+        limit: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_corridor(&currency, &country)?;
+        validate_amount(limit)?;
+
+        set_daily_limit(&env, &currency, &country, limit);
+        emit_daily_limit_updated(&env, currency, country, limit);
+        Ok(())
+    }
+
+    /// Removes the daily send limit for a corridor (Admin only)
+    pub fn remove_daily_limit(
+        env: Env,
+        caller: Address,
+        currency: String,
+        country: String,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        remove_daily_limit(&env, &currency, &country);
+        emit_daily_limit_updated(&env, currency, country, 0);
+        Ok(())
+    }
+
+    /// Gets the daily send limit configured for a corridor, if any
+    pub fn get_daily_limit(env: Env, currency: String, country: String) -> Option<DailyLimit> {
+        get_daily_limit(&env, &currency, &country)
+    }
+
+    /// Gets how much a sender can still remit of a token in a corridor within the rolling 24-hour window
+    ///
+    /// # Returns
+    /// * `Ok(None)` - No limit is configured for the corridor
+    /// * `Ok(Some(amount))` - Remaining allowance
+    pub fn get_remaining_daily_allowance(
+        env: Env,
+        sender: Address,
+        currency: String,
+        country: String,
+        token: Address,
+    ) -> Result<Option<i128>, ContractError> {
+        get_remaining_daily_allowance(&env, &sender, &currency, &country, &token)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Standing Orders
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Creates a standing order that remits the same amount to the same agent on a schedule.
+    ///
+    /// The sender authorizes the order once. Each installment is pulled from
+    /// the sender through a token allowance, so the sender must `approve` the
+    /// contract for enough to cover the installments it wants executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the installments (must authorize)
+    /// * `agent` - Registered agent assigned to each installment
+    /// * `amount` - Amount of each installment
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `currency` - Payout currency of the corridor (e.g., "NGN")
+    /// * `country` - Destination country of the corridor (e.g., "NG")
+    /// * `interval` - Seconds between installments
+    /// * `first_due` - Timestamp at which the first installment becomes due
+    /// * `count` - Number of installments
+    /// * `end_date` - Optional timestamp after which no further installments execute
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(order_id)` - ID of the new standing order
+    /// * `Err(ContractError::InvalidSchedule)` - Zero interval or count, or end date before the first due date
+    /// * Any validation error returned by `create_remittance`
+    pub fn create_standing_order(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        interval: u64,
+        first_due: u64,
+        count: u32,
+        end_date: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount, &token, &currency, &country)?;
+        validate_standing_order_schedule(interval, first_due, count, end_date)?;
+
+        sender.require_auth();
+
+        let order = StandingOrder {
+            id: next_standing_order_id(&env)?,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            token,
+            currency,
+            country,
+            interval,
+            next_due: first_due,
+            remaining_count: count,
+            end_date,
+            status: StandingOrderStatus::Active,
+        };
+        set_standing_order(&env, &order);
+        index_standing_order(&env, &sender, order.id);
+
+        emit_standing_order_created(&env, order.id, sender, agent, amount, interval);
+
+        Ok(order.id)
+    }
+
+    /// Executes the due installments of a list of standing orders.
+    ///
+    /// Permissionless so that keepers can drive the schedule. Each due order
+    /// creates one remittance through the same fee and daily-limit logic as
+    /// `create_remittance` and advances `next_due` by one interval. Orders that
+    /// are not due and repeated IDs are skipped, as are installments the
+    /// sender's allowance, balance or daily limit cannot cover; those stay due
+    /// for a later call.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `order_ids` - Standing order IDs to execute (at most `MAX_BATCH_SIZE`)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs of the remittances created
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `Err(ContractError::StandingOrderNotFound)` - An ID does not exist
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    pub fn execute_due_orders(env: Env, order_ids: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+        validate_not_paused(&env)?;
+        if order_ids.is_empty() || order_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut remittance_ids = Vec::new(&env);
+        let mut seen_ids = Vec::new(&env);
+        for order_id in order_ids.iter() {
+            // Each order executes at most one installment per call
+            if seen_ids.contains(order_id) {
+                continue;
+            }
+            seen_ids.push_back(order_id);
+
+            let mut order = get_standing_order(&env, order_id)?;
+
+            if order.status == StandingOrderStatus::Active && is_past_end_date(&env, &order) {
+                order.status = StandingOrderStatus::Completed;
+                set_standing_order(&env, &order);
+                emit_standing_order_status(&env, order_id, StandingOrderStatus::Completed);
+                continue;
+            }
+            if !is_order_due(&env, &order) {
+                continue;
+            }
+            if !can_fund_installment(&env, &order)? {
+                emit_standing_order_skipped(&env, order_id);
+                continue;
+            }
+
+            let remittance_id = execute_standing_order_installment(&env, &order)?;
+            remittance_ids.push_back(remittance_id);
+
+            order.next_due = order
+                .next_due
+                .checked_add(order.interval)
+                .ok_or(ContractError::Overflow)?;
+            order.remaining_count -= 1;
+            set_standing_order(&env, &order);
+            emit_standing_order_executed(&env, order_id, remittance_id, order.remaining_count);
+
+            if order.remaining_count == 0 {
+                order.status = StandingOrderStatus::Completed;
+                set_standing_order(&env, &order);
+                emit_standing_order_status(&env, order_id, StandingOrderStatus::Completed);
+            }
+        }
+
+        Ok(remittance_ids)
+    }
+
+    /// Pauses an active standing order (sender only)
+    ///
+    /// # Returns
+    /// * `Err(ContractError::InvalidStatus)` - Order is not active
+    pub fn pause_standing_order(env: Env, order_id: u64) -> Result<(), ContractError> {
+        update_standing_order_status(
+            &env,
+            order_id,
+            StandingOrderStatus::Active,
+            StandingOrderStatus::Paused,
+        )
+    }
+
+    /// Resumes a paused standing order (sender only)
+    ///
+    /// Installments that fell due while paused are skipped: `next_due` moves
+    /// to the first installment after the current time.
+    ///
+    /// # Returns
+    /// * `Err(ContractError::InvalidStatus)` - Order is not paused
+    pub fn resume_standing_order(env: Env, order_id: u64) -> Result<(), ContractError> {
+        let mut order = get_standing_order(&env, order_id)?;
+        order.sender.require_auth();
+
+        if order.status != StandingOrderStatus::Paused {
+            return Err(ContractError::InvalidStatus);
+        }
+        order.status = StandingOrderStatus::Active;
+        skip_missed_installments(&env, &mut order)?;
+        set_standing_order(&env, &order);
+
+        emit_standing_order_status(&env, order_id, StandingOrderStatus::Active);
+        Ok(())
+    }
+
+    /// Cancels an active or paused standing order (sender only)
+    ///
+    /// Remittances already created by the order are not affected.
+    ///
+    /// # Returns
+    /// * `Err(ContractError::InvalidStatus)` - Order is already cancelled or completed
+    pub fn cancel_standing_order(env: Env, order_id: u64) -> Result<(), ContractError> {
+        let mut order = get_standing_order(&env, order_id)?;
+        order.sender.require_auth();
+
+        if !matches!(order.status, StandingOrderStatus::Active | StandingOrderStatus::Paused) {
+            return Err(ContractError::InvalidStatus);
+        }
+        order.status = StandingOrderStatus::Cancelled;
+        set_standing_order(&env, &order);
+
+        emit_standing_order_status(&env, order_id, StandingOrderStatus::Cancelled);
+        Ok(())
+    }
+
+    /// Gets a standing order by ID
+    pub fn get_standing_order(env: Env, order_id: u64) -> Result<StandingOrder, ContractError> {
+        get_standing_order(&env, order_id)
+    }
+
+    /// Gets a page of standing orders created by a sender, oldest first
+    ///
+    /// # Arguments
+    /// * `sender` - Sender whose orders to list
+    /// * `page` - 1-based page number
+    /// * `limit` - Page size, capped at `MAX_PAGE_SIZE`
+    pub fn get_standing_orders_by_sender(
+        env: Env,
+        sender: Address,
+        page: u32,
+        limit: u32,
+    ) -> Result<StandingOrderPage, ContractError> {
+        get_standing_orders_by_sender(&env, &sender, page, limit)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Quotes
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Locks a quote for a sender's remittance at a given FX rate.
+    ///
+    /// The rate must be within the quote rate tolerance of the published
+    /// median from the token's currency to the destination currency, see
+    /// `get_fx_rate`. Computes the platform fee from the corridor's fee schedule and the
+    /// protocol fee from the current protocol fee rate, then converts what is
+    /// left into the destination currency. The quote can be consumed once by
+    /// `create_remittance_from_quote` until `valid_until`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `signer` - Admin address, or a registered oracle's signature over `compute_quote_hash`
+    /// * `sender` - Address the quote is issued to
+    /// * `amount` - Source amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `dest_currency` - Currency the recipient is paid in (e.g., "NGN")
+    /// * `dest_country` - Destination country of the corridor (e.g., "NG")
+    /// * `fx_rate` - Destination units per source unit, scaled by `FX_RATE_SCALE`
+    /// * `valid_until` - Timestamp after which the quote can no longer be used
+    /// * `nonce` - Oracle-chosen value making each signed quote hash unique
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(quote_id)` - ID of the new quote
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::InvalidCorridor)` - Destination currency or country is empty
+    /// * `Err(ContractError::InvalidFxRate)` - FX rate is zero or negative
+    /// * `Err(ContractError::QuoteExpired)` - Deadline is not in the future
+    /// * `Err(ContractError::Unauthorized)` - Admin signer is not an admin
+    /// * `Err(ContractError::OracleNotRegistered)` - Oracle signer is not registered
+    /// * `Err(ContractError::QuoteAlreadyUsed)` - The oracle-signed hash already issued a quote
+    /// * `Err(ContractError::TokenCurrencyNotSet)` - Token has no currency configured
+    /// * `Err(ContractError::FxRateOutOfTolerance)` - FX rate deviates too far from the median
+    /// * `Err(ContractError::RateNotFound)` / `StaleRate` / `InsufficientRateSources` - No usable median for the pair
+    /// * `Err(ContractError::FeesExceedAmount)` - Platform and protocol fees together exceed the amount
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the admin, or a valid oracle signature.
+    pub fn create_quote(
+        env: Env,
+        signer: QuoteSigner,
+        sender: Address,
+        amount: i128,
+        token: Address,
+        dest_currency: String,
+        dest_country: String,
+        fx_rate: i128,
+        valid_until: u64,
+        nonce: u64,
+    ) -> Result<u64, ContractError> {
+        validate_address(&sender)?;
+        validate_amount(amount)?;
+        validate_token_whitelisted(&env, &token)?;
+        validate_quote_terms(&env, &dest_currency, &dest_country, fx_rate, valid_until)?;
+
+        match &signer {
+            QuoteSigner::Admin(admin) => require_admin(&env, admin)?,
+            QuoteSigner::Oracle(proof) => {
+                let quote_hash = compute_quote_hash(
+                    &env,
+                    &sender,
+                    amount,
+                    &token,
+                    &dest_currency,
+                    &dest_country,
+                    fx_rate,
+                    valid_until,
+                    nonce,
+                );
+                if is_quote_hash_used(&env, &quote_hash) {
+                    return Err(ContractError::QuoteAlreadyUsed);
+                }
+                verify_quote_signature(&env, proof, &quote_hash)?;
+                set_quote_hash_used(&env, &quote_hash);
+            }
+        }
+        check_quoted_rate(&env, &token, &dest_currency, fx_rate)?;
+
+        let schedule = resolve_fee_schedule(&env, &token, Some((&dest_currency, &dest_country)));
+        let platform_fee = calculate_scheduled_fee(&env, &schedule, amount)?;
+        let protocol_fee = compute_protocol_fee(&env, amount)?;
+        let payout_amount = compute_payout_amount(amount, platform_fee, protocol_fee)?;
+        let dest_amount = compute_destination_amount(payout_amount, fx_rate)?;
+
+        let quote = Quote {
+            id: next_quote_id(&env)?,
+            sender: sender.clone(),
+            amount,
+            token,
+            platform_fee,
+            protocol_fee,
+            payout_amount,
+            fx_rate,
+            dest_currency: dest_currency.clone(),
+            dest_country,
+            dest_amount,
+            valid_until,
+            remittance_id: None,
+        };
+        set_quote(&env, &quote);
+
+        emit_quote_created(&env, quote.id, sender, amount, dest_currency, dest_amount, valid_until);
+
+        Ok(quote.id)
+    }
+
+    /// Creates a remittance from a quote, consuming it.
+    ///
+    /// The remittance uses the quoted amount, token and corridor, keeps the
+    /// quoted platform and protocol fees and records the quoted destination
+    /// amount, which the agent is bound to pay out.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `quote_id` - ID of the quote to consume
+    /// * `agent` - Address of the registered agent who will pay out
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::QuoteNotFound)` - Quote does not exist
+    /// * `Err(ContractError::QuoteAlreadyUsed)` - Quote was already consumed
+    /// * `Err(ContractError::QuoteExpired)` - Quote deadline has passed
+    /// * Any other error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the quote's sender.
+    pub fn create_remittance_from_quote(
+        env: Env,
+        quote_id: u64,
+        agent: Address,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        let mut quote = get_quote(&env, quote_id)?;
+        validate_quote_usable(&env, &quote)?;
+        validate_create_remittance_request(
+            &env,
+            &quote.sender,
+            &agent,
+            quote.amount,
+            &quote.token,
+            &quote.dest_currency,
+            &quote.dest_country,
+        )?;
+
+        quote.sender.require_auth();
+
+        let remittance_id = execute_create_remittance(
+            &env,
+            &quote.sender,
+            &agent,
+            quote.amount,
+            &quote.token,
+            &quote.dest_currency,
+            &quote.dest_country,
+            expiry,
+            None,
+            Some(&quote),
+        )?;
+
+        quote.remittance_id = Some(remittance_id);
+        set_quote(&env, &quote);
+
+        emit_quote_used(&env, quote_id, remittance_id);
+
+        Ok(remittance_id)
+    }
+
+    /// Gets a quote by ID, including the remittance that consumed it, if any
+    pub fn get_quote(env: Env, quote_id: u64) -> Result<Quote, ContractError> {
+        get_quote(&env, quote_id)
+    }
+
+    /// Computes the hash an oracle signs to issue a quote with `QuoteSigner::Oracle`.
+    ///
+    /// See `hashing::compute_quote_hash` for the canonical field ordering.
+    pub fn compute_quote_hash(
+        env: Env,
+        sender: Address,
+        amount: i128,
+        token: Address,
+        dest_currency: String,
+        dest_country: String,
+        fx_rate: i128,
+        valid_until: u64,
+        nonce: u64,
+    ) -> BytesN<32> {
+        compute_quote_hash(
+            &env,
+            &sender,
+            amount,
+            &token,
+            &dest_currency,
+            &dest_country,
+            fx_rate,
+            valid_until,
+            nonce,
+        )
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // FX Rate Registry
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Posts a rate feeder's price for a currency pair.
+    ///
+    /// Replaces the feeder's previous submission for the pair. The published
+    /// rate is the median over all fresh submissions, see `get_fx_rate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `feeder` - Address holding the RateFeeder role
+    /// * `base` - Base currency of the pair (e.g., "USD")
+    /// * `quote` - Quote currency of the pair (e.g., "NGN")
+    /// * `rate` - Quote units per base unit, scaled by `FX_RATE_SCALE`
+    /// * `timestamp` - Timestamp at which the feeder observed the price
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Submission recorded
+    /// * `Err(ContractError::Unauthorized)` - Caller lacks the RateFeeder role
+    /// * `Err(ContractError::InvalidCorridor)` - Base or quote currency is empty
+    /// * `Err(ContractError::InvalidFxRate)` - Rate is zero or negative
+    /// * `Err(ContractError::InvalidRateTimestamp)` - Timestamp is in the future or older than the feeder's last submission
+    /// * `Err(ContractError::StaleRate)` - Timestamp is older than the maximum rate age
+    pub fn post_fx_rate(
+        env: Env,
+        feeder: Address,
+        base: String,
+        quote: String,
+        rate: i128,
+        timestamp: u64,
+    ) -> Result<(), ContractError> {
+        feeder.require_auth();
+        require_role_rate_feeder(&env, &feeder)?;
+
+        let previous = get_rate_submission(&env, &base, &quote, &feeder);
+        validate_rate_submission(&env, &base, &quote, rate, timestamp, previous)?;
+
+        record_rate_submission(&env, &base, &quote, &feeder, &RateSubmission { rate, timestamp });
+
+        emit_rate_posted(&env, base, quote, feeder, rate, timestamp);
+        Ok(())
+    }
+
+    /// Gets the median rate for a currency pair.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FxRate)` - Median over the fresh submissions of current rate feeders
+    /// * `Err(ContractError::RateNotFound)` - No current rate feeder has posted to the pair
+    /// * `Err(ContractError::StaleRate)` - Every submission is older than the maximum rate age
+    /// * `Err(ContractError::InsufficientRateSources)` - Fewer fresh submissions than the configured minimum
+    pub fn get_fx_rate(env: Env, base: String, quote: String) -> Result<FxRate, ContractError> {
+        get_median_rate(&env, &base, &quote)
+    }
+
+    /// Gets a feeder's latest submission for a currency pair, if any
+    pub fn get_rate_submission(env: Env, base: String, quote: String, feeder: Address) -> Option<RateSubmission> {
+        get_rate_submission(&env, &base, &quote, &feeder)
+    }
+
+    /// Updates the maximum age of rate submissions included in the median (Admin only)
+    pub fn update_max_rate_age(env: Env, caller: Address, max_age: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_max_rate_age(&env, max_age);
+        Ok(())
+    }
+
+    /// Gets the maximum age of rate submissions in seconds
+    pub fn get_max_rate_age(env: Env) -> u64 {
+        get_max_rate_age(&env)
+    }
+
+    /// Updates the minimum number of fresh submissions a median needs (Admin only)
+    pub fn update_min_rate_sources(env: Env, caller: Address, min_sources: u32) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_min_rate_sources(&env, min_sources);
+        Ok(())
+    }
+
+    /// Gets the minimum number of fresh submissions a median needs
+    pub fn get_min_rate_sources(env: Env) -> u32 {
+        get_min_rate_sources(&env)
+    }
+
+    /// Updates how far a quoted rate may deviate from the median, in basis points (Admin only)
+    pub fn update_quote_rate_tolerance(env: Env, caller: Address, tolerance_bps: u32) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_quote_rate_tolerance(&env, tolerance_bps);
+        Ok(())
+    }
+
+    /// Gets how far a quoted rate may deviate from the median, in basis points
+    pub fn get_quote_rate_tolerance(env: Env) -> u32 {
+        get_quote_rate_tolerance(&env)
+    }
+
+    /// Sets the currency a token is priced in when checking quotes (Admin only)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin address
+    /// * `token` - Whitelisted token
+    /// * `currency` - Base currency of the token's rate pairs (e.g., "USD")
+    pub fn set_token_currency(env: Env, caller: Address, token: Address, currency: String) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_token_whitelisted(&env, &token)?;
+        if currency.is_empty() {
+            return Err(ContractError::InvalidCorridor);
+        }
+        set_token_currency(&env, &token, &currency);
+        Ok(())
+    }
+
+    /// Gets the currency a token is priced in, if configured
+    pub fn get_token_currency(env: Env, token: Address) -> Option<String> {
+        get_token_currency(&env, &token)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // KYC Registry
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Records a sender's KYC tier (ComplianceOfficer only)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `officer` - Address holding the ComplianceOfficer role
+    /// * `address` - Sender being verified
+    /// * `tier` - Verified tier
+    /// * `expiry` - Timestamp after which the sender falls back to `KycTier::None`
+    /// * `jurisdiction` - Jurisdiction the verification was performed under (e.g., "NG")
+    pub fn set_kyc_record(
+        env: Env,
+        officer: Address,
+        address: Address,
+        tier: KycTier,
+        expiry: u64,
+        jurisdiction: String,
+    ) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        let record = KycRecord {
+            tier,
+            expiry,
+            jurisdiction,
+            officer: officer.clone(),
+            updated_at: env.ledger().timestamp(),
+        };
+        set_kyc_record(&env, &address, &record);
+
+        emit_kyc_updated(&env, address, tier, expiry, officer);
+        Ok(())
+    }
+
+    /// Removes a sender's KYC record, returning them to `KycTier::None` (ComplianceOfficer only)
+    pub fn remove_kyc_record(env: Env, officer: Address, address: Address) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        remove_kyc_record(&env, &address);
+
+        emit_kyc_updated(&env, address, KycTier::None, 0, officer);
+        Ok(())
+    }
+
+    /// Sets the per-transaction, daily and monthly caps of a KYC tier in a token (ComplianceOfficer only)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Caps updated
+    /// * `Err(ContractError::Unauthorized)` - Caller lacks the ComplianceOfficer role
+    /// * `Err(ContractError::InvalidKycLimits)` - Caps are negative or not nested (per-transaction ≤ daily ≤ monthly)
+    pub fn set_kyc_limits(
+        env: Env,
+        officer: Address,
+        tier: KycTier,
+        token: Address,
+        per_transaction: i128,
+        daily: i128,
+        monthly: i128,
+    ) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        let limits = KycLimits {
+            per_transaction,
+            daily,
+            monthly,
+        };
+        validate_kyc_limits(&limits)?;
+        set_kyc_limits(&env, tier, &token, &limits);
+
+        emit_kyc_limits_updated(&env, tier, token, officer);
+        Ok(())
+    }
+
+    /// Removes the caps of a KYC tier in a token, leaving it unlimited there (ComplianceOfficer only)
+    pub fn remove_kyc_limits(env: Env, officer: Address, tier: KycTier, token: Address) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        remove_kyc_limits(&env, tier, &token);
+
+        emit_kyc_limits_updated(&env, tier, token, officer);
+        Ok(())
+    }
+
+    /// Gets a sender's KYC record, if any
+    pub fn get_kyc_record(env: Env, address: Address) -> Option<KycRecord> {
+        get_kyc_record(&env, &address)
+    }
+
+    /// Gets a sender's current tier, accounting for expiry
+    pub fn get_kyc_tier(env: Env, address: Address) -> KycTier {
+        get_effective_kyc_tier(&env, &address)
+    }
+
+    /// Gets the caps configured for a KYC tier in a token, if any
+    pub fn get_kyc_limits(env: Env, tier: KycTier, token: Address) -> Option<KycLimits> {
+        get_kyc_limits(&env, tier, &token)
+    }
+
+    /// Gets how much a sender can still send in a token under their KYC tier
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(None)` - The sender's tier has no caps configured for the token
+    /// * `Ok(Some(headroom))` - Per-transaction cap and remaining daily and monthly amounts
+    pub fn get_kyc_headroom(env: Env, address: Address, token: Address) -> Result<Option<KycHeadroom>, ContractError> {
+        get_kyc_headroom(&env, &address, &token)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Sanctions Screening
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Adds an address to the sanctions denylist (ComplianceOfficer only)
+    ///
+    /// Pending and accepted remittances involving the address are frozen rather
+    /// than refunded. Listing examines the address's `MAX_BATCH_SIZE` most recent
+    /// remittances as sender and then as agent; older ones are frozen when they
+    /// expire, or earlier through `freeze_sanctioned_remittances`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `officer` - Address holding the ComplianceOfficer role
+    /// * `address` - Address to list
+    /// * `reason_code` - Compliance reason code recorded with the listing
+    pub fn add_to_denylist(env: Env, officer: Address, address: Address, reason_code: u32) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        let entry = DenylistEntry {
+            reason_code,
+            listed_at: env.ledger().timestamp(),
+            officer: officer.clone(),
+        };
+        set_denylist_entry(&env, &address, &entry);
+        emit_address_denylisted(&env, address.clone(), reason_code, officer);
+
+        for remittance_id in get_recent_party_remittance_ids(&env, &address, MAX_BATCH_SIZE).iter() {
+            let mut remittance = get_remittance(&env, remittance_id)?;
+            if can_settle(&remittance.status) {
+                freeze_remittance(&env, &mut remittance, address.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes an address from the sanctions denylist (ComplianceOfficer only)
+    ///
+    /// Remittances frozen while the address was listed stay frozen until
+    /// released with `release_frozen_remittance`.
+    pub fn remove_from_denylist(env: Env, officer: Address, address: Address) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        remove_denylist_entry(&env, &address);
+
+        emit_address_delisted(&env, address, officer);
+        Ok(())
+    }
+
+    /// Freezes pending or accepted remittances whose sender or agent is sanctioned.
+    ///
+    /// Permissionless, so compliance tooling can freeze everything involving a
+    /// newly listed address, found through `get_remittances_by_sender` and
+    /// `get_remittances_by_agent`. IDs that are not settleable or involve no
+    /// sanctioned party are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs that were frozen
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `Err(ContractError::RemittanceNotFound)` - An ID does not exist
+    pub fn freeze_sanctioned_remittances(env: Env, remittance_ids: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+        if remittance_ids.is_empty() || remittance_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut frozen_ids = Vec::new(&env);
+        for remittance_id in remittance_ids.iter() {
+            let mut remittance = get_remittance(&env, remittance_id)?;
+            if !can_settle(&remittance.status) {
+                continue;
+            }
+            if let Some(party) = get_sanctioned_party(&env, &remittance) {
+                freeze_remittance(&env, &mut remittance, party)?;
+                frozen_ids.push_back(remittance_id);
+            }
+        }
+
+        Ok(frozen_ids)
+    }
+
+    /// Returns a frozen remittance to Pending once none of its parties is sanctioned (ComplianceOfficer only)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance is Pending again
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not frozen
+    /// * `Err(ContractError::AddressSanctioned)` - The sender or agent is still listed
+    pub fn release_frozen_remittance(env: Env, officer: Address, remittance_id: u64) -> Result<(), ContractError> {
+        officer.require_auth();
+        require_role_compliance_officer(&env, &officer)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Frozen {
+            return Err(ContractError::InvalidStatus);
+        }
+        if get_sanctioned_party(&env, &remittance).is_some() {
+            return Err(ContractError::AddressSanctioned);
+        }
+        transition_remittance(&env, &mut remittance, RemittanceStatus::Pending)?;
+
+        emit_remittance_unfrozen(&env, remittance_id, officer);
+        Ok(())
+    }
+
+    /// Gets the denylist record of an address, if listed
+    pub fn get_denylist_entry(env: Env, address: Address) -> Option<DenylistEntry> {
+        get_denylist_entry(&env, &address)
+    }
+
+    /// Returns whether an address is on the sanctions denylist
+    pub fn is_denylisted(env: Env, address: Address) -> bool {
+        is_denylisted(&env, &address)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Stats
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Gets an agent's settlement and cancellation counters
+    ///
+    /// Counters are all zero for agents that never settled or had a remittance cancelled.
+    pub fn get_agent_stats(env: Env, agent: Address) -> AgentStats {
+        get_agent_stats(&env, &agent)
+    }
+
+    /// Gets the total amount an agent settled in a token
+    pub fn get_agent_settled_volume(env: Env, agent: Address, token: Address) -> i128 {
+        get_agent_settled_volume(&env, &agent, &token)
+    }
+
+    /// Gets a page of the agents that settled in a token, ranked by descending settled volume
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Token the volumes are counted in
+    /// * `page` - 1-based page number
+    /// * `limit` - Page size, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AgentLeaderboardPage)` - Rankings on the page with pagination totals
+    /// * `Err(ContractError::InvalidPagination)` - `page` or `limit` is zero
+    pub fn get_agent_leaderboard(
+        env: Env,
+        token: Address,
+        page: u32,
+        limit: u32,
+    ) -> Result<AgentLeaderboardPage, ContractError> {
+        get_agent_leaderboard(&env, &token, page, limit)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Bonds
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Sets the agent bond requirement (Admin only)
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Remittance amount above which agents must be bonded, in the remittance's token
+    /// * `min_bond` - Minimum active bond in the remittance's token required above the threshold
+    /// * `unbonding_delay` - Seconds between unbonding and withdrawing collateral
+    /// * `insurance_pool` - Recipient of slashed collateral routed to insurance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Requirement stored
+    /// * `Err(ContractError::InvalidAmount)` - Threshold or minimum bond is negative
+    /// * `Err(ContractError::InvalidUnbondingDelay)` - Delay is not longer than the dispute window
+    pub fn set_bond_config(
+        env: Env,
+        caller: Address,
+        threshold: i128,
+        min_bond: i128,
+        unbonding_delay: u64,
+        insurance_pool: Option<Address>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        let config = BondConfig {
+            threshold,
+            min_bond,
+            unbonding_delay,
+            insurance_pool,
+        };
+        validate_bond_config(&config)?;
+        validate_unbonding_delay(unbonding_delay, get_dispute_window(&env))?;
+        set_bond_config(&env, &config);
+        Ok(())
+    }
+
+    /// Gets the agent bond requirement, if configured
+    pub fn get_bond_config(env: Env) -> Option<BondConfig> {
+        get_bond_config(&env)
+    }
+
+    /// Posts collateral in a settlement token to an agent's bond in that token
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Collateral transferred and added to the active bond
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::AddressSanctioned)` - Agent is on the sanctions denylist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn post_bond(env: Env, agent: Address, token: Address, amount: i128) -> Result<(), ContractError> {
+        agent.require_auth();
+        validate_amount(amount)?;
+        validate_agent_registered(&env, &agent)?;
+        validate_token_whitelisted(&env, &token)?;
+        validate_not_sanctioned(&env, &agent)?;
+
+        token::Client::new(&env, &token).transfer(&agent, &env.current_contract_address(), &amount);
+
+        let mut bond = get_agent_bond(&env, &agent, &token);
+        bond.bonded = bond.bonded.checked_add(amount).ok_or(ContractError::Overflow)?;
+        set_agent_bond(&env, &agent, &token, &bond);
+
+        emit_bond_posted(&env, agent, token, amount, bond.bonded);
+        Ok(())
+    }
+
+    /// Starts unbonding part of an agent's active bond in a token
+    ///
+    /// Unbonding restarts the delay for all collateral already unbonding.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - Timestamp from which the unbonding collateral can be withdrawn
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientBond)` - Amount exceeds the active bond
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn request_unbond(env: Env, agent: Address, token: Address, amount: i128) -> Result<u64, ContractError> {
+        agent.require_auth();
+        validate_amount(amount)?;
+
+        let withdrawable_at = start_unbonding(&env, &agent, &token, amount)?;
+        emit_bond_unbonding(&env, agent, token, amount, withdrawable_at);
+        Ok(withdrawable_at)
+    }
+
+    /// Withdraws an agent's unbonded collateral in a token once the unbonding delay has passed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount returned to the agent
+    /// * `Err(ContractError::UnbondingNotReady)` - Nothing is unbonding, the delay has not passed or a dispute against the agent is open
+    /// * `Err(ContractError::AddressSanctioned)` - Agent is on the sanctions denylist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn withdraw_unbonded(env: Env, agent: Address, token: Address) -> Result<i128, ContractError> {
+        agent.require_auth();
+        validate_not_sanctioned(&env, &agent)?;
+
+        let mut bond = get_agent_bond(&env, &agent, &token);
+        if bond.unbonding == 0
+            || env.ledger().timestamp() < bond.withdrawable_at
+            || get_open_dispute_count(&env, &agent) > 0
+        {
+            return Err(ContractError::UnbondingNotReady);
+        }
+        let amount = bond.unbonding;
+        bond.unbonding = 0;
+        set_agent_bond(&env, &agent, &token, &bond);
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &agent, &amount);
+
+        emit_bond_withdrawn(&env, agent, token, amount);
+        Ok(amount)
+    }
+
+    /// Slashes an agent's bond to compensate the sender of a disputed remittance (Arbitrator or Admin)
+    ///
+    /// The remittance must have a dispute that was not rejected. Collateral is
+    /// taken from the active bond first, then from unbonding collateral. Rulings
+    /// and slashes together never return more than the remittance amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Arbitrator or admin
+    /// * `remittance_id` - ID of the disputed remittance
+    /// * `amount` - Collateral to slash
+    /// * `destination` - Whether the sender or the insurance pool receives it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Collateral transferred to the recipient
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither admin nor arbitrator
+    /// * `Err(ContractError::DisputeNotFound)` - No dispute was opened for the remittance
+    /// * `Err(ContractError::InvalidStatus)` - The dispute was rejected
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive or exceeds the sender's remaining loss
+    /// * `Err(ContractError::InsurancePoolNotSet)` - No insurance pool is configured
+    /// * `Err(ContractError::InsufficientBond)` - The agent's collateral cannot cover the amount
+    pub fn slash_agent_bond(
+        env: Env,
+        caller: Address,
+        remittance_id: u64,
+        amount: i128,
+        destination: SlashDestination,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !is_admin(&env, &caller) {
+            require_role_arbitrator(&env, &caller)?;
+        }
+        validate_amount(amount)?;
+
+        let dispute = get_dispute(&env, remittance_id).ok_or(ContractError::DisputeNotFound)?;
+        if dispute.status == DisputeStatus::Resolved(DisputeOutcome::Rejected) {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        let slashed = get_slashed_amount(&env, remittance_id)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        let compensated = slashed
+            .checked_add(dispute.refunded_amount)
+            .ok_or(ContractError::Overflow)?;
+        if compensated > remittance.amount {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let recipient = match destination {
+            SlashDestination::Sender => dispute.sender,
+            SlashDestination::InsurancePool => get_bond_config(&env)
+                .and_then(|config| config.insurance_pool)
+                .ok_or(ContractError::InsurancePoolNotSet)?,
+        };
+        validate_not_sanctioned(&env, &recipient)?;
+
+        deduct_slash(&env, &dispute.agent, &remittance.token, amount)?;
+        set_slashed_amount(&env, remittance_id, slashed);
+
+        token::Client::new(&env, &remittance.token).transfer(&env.current_contract_address(), &recipient, &amount);
+
+        emit_bond_slashed(&env, remittance_id, dispute.agent, recipient, amount, caller);
+        Ok(())
+    }
+
+    /// Gets an agent's bond in a token, all zero if the agent never posted one
+    pub fn get_agent_bond(env: Env, agent: Address, token: Address) -> AgentBond {
+        get_agent_bond(&env, &agent, &token)
+    }
+
+    /// Gets the number of unresolved disputes against an agent
+    pub fn get_open_dispute_count(env: Env, agent: Address) -> u32 {
+        get_open_dispute_count(&env, &agent)
+    }
+
+    /// Gets the collateral already slashed for a disputed remittance
+    pub fn get_slashed_amount(env: Env, remittance_id: u64) -> i128 {
+        get_slashed_amount(&env, remittance_id)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Capacity
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Declares the maximum outstanding amount an agent can be assigned in a token (Admin or the agent)
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Admin or the agent itself
+    /// * `agent` - Agent whose capacity is declared
+    /// * `token` - Token the capacity applies to
+    /// * `capacity` - Maximum exposure in token units, or `None` to remove the limit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Capacity stored
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither admin nor the agent
+    /// * `Err(ContractError::InvalidAmount)` - Capacity is negative
+    pub fn set_agent_capacity(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        token: Address,
+        capacity: Option<i128>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if caller != agent && !is_admin(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+        if capacity.is_some_and(|capacity| capacity < 0) {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_agent_capacity_limit(&env, &agent, &token, capacity);
+        emit_agent_capacity_updated(&env, agent, token, capacity, caller);
+        Ok(())
+    }
+
+    /// Gets an agent's declared capacity, outstanding exposure and remaining capacity in a token
+    pub fn get_agent_capacity(env: Env, agent: Address, token: Address) -> AgentCapacity {
+        get_agent_capacity(&env, &agent, &token)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Profiles
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Sets the corridors, currencies and payout methods an agent serves (Admin only)
+    ///
+    /// Replaces any previous profile. Once set, the agent can only be assigned
+    /// remittances in one of its currencies to one of its countries.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Admin address
+    /// * `agent` - Registered agent
+    /// * `countries` - Destination countries served
+    /// * `currencies` - Payout currencies offered
+    /// * `payout_methods` - Payout methods offered
+    /// * `name_hash` - Hash of the agent's display name, if published
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Profile stored and corridor index updated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidAgentProfile)` - No countries or currencies, an empty entry, or too many entries
+    pub fn set_agent_profile(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        countries: Vec<String>,
+        currencies: Vec<String>,
+        payout_methods: Vec<PayoutMethod>,
+        name_hash: Option<BytesN<32>>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_agent_registered(&env, &agent)?;
+
+        let profile = AgentProfile {
+            countries,
+            currencies,
+            payout_methods,
+            updated_at: env.ledger().timestamp(),
+        };
+        validate_agent_profile(&profile)?;
+        set_agent_profile(&env, &agent, &profile);
+        set_agent_name_hash(&env, &agent, &name_hash);
+
+        emit_agent_profile_updated(&env, agent, caller);
+        Ok(())
+    }
 
-            // Load and validate remittance
-            let remittance = get_remittance(&env, remittance_id)?;
+    /// Gets an agent's service profile, if any
+    pub fn get_agent_profile(env: Env, agent: Address) -> Option<AgentProfile> {
+        get_agent_profile(&env, &agent)
+    }
 
-            // Verify remittance is pending
-            if remittance.status != RemittanceStatus::Pending {
-                return Err(ContractError::InvalidStatus);
-            }
+    /// Gets the hash of an agent's display name, if published
+    pub fn get_agent_name_hash(env: Env, agent: Address) -> Option<BytesN<32>> {
+        get_agent_name_hash(&env, &agent)
+    }
 
-            // Check for duplicate settlement execution
-            if has_settlement_hash(&env, remittance_id) {
-                return Err(ContractError::DuplicateSettlement);
-            }
+    /// Gets a page of the registered agents serving a corridor
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Payout currency (e.g., "NGN")
+    /// * `country` - Destination country (e.g., "NG")
+    /// * `page` - 1-based page number
+    /// * `limit` - Page size, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AgentPage)` - Agents on the page with pagination totals
+    /// * `Err(ContractError::InvalidPagination)` - `page` or `limit` is zero
+    pub fn get_agents_by_corridor(
+        env: Env,
+        currency: String,
+        country: String,
+        page: u32,
+        limit: u32,
+    ) -> Result<AgentPage, ContractError> {
+        get_agents_by_corridor(&env, &currency, &country, page, limit)
+    }
 
-            // Check expiry
-            if let Some(expiry_time) = remittance.expiry {
-                let current_time = env.ledger().timestamp();
-                if current_time > expiry_time {
-                    return Err(ContractError::SettlementExpired);
-                }
-            }
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Routing
+    // ═══════════════════════════════════════════════════════════════════════════
 
-            // Validate addresses
-            validate_address(&remittance.agent)?;
+    /// Creates a remittance routed to an agent chosen by the contract.
+    ///
+    /// The agent is selected among the registered agents whose profile serves
+    /// the corridor, skipping suspended and sanctioned agents and agents whose
+    /// bond or capacity cannot take the amount. Otherwise behaves like
+    /// `create_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address sending the remittance (must authorize)
+    /// * `corridor` - Payout currency and destination country
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RoutedRemittance)` - ID of the created remittance and the selected agent
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::NoEligibleAgent)` - No eligible agent serves the corridor
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_routed_remittance(
+        env: Env,
+        sender: Address,
+        corridor: Corridor,
+        amount: i128,
+        token: Address,
+        expiry: Option<u64>,
+    ) -> Result<RoutedRemittance, ContractError> {
+        validate_amount(amount)?;
+        validate_token_whitelisted(&env, &token)?;
+        validate_corridor(&corridor.currency, &corridor.country)?;
+        let agent = select_agent(&env, &corridor, &token, amount)?;
+        validate_create_remittance_request(&env, &sender, &agent, amount, &token, &corridor.currency, &corridor.country)?;
 
-            remittances.push_back(remittance);
-        }
+        sender.require_auth();
 
-        // Compute net settlements
-        let net_transfers = compute_net_settlements(&env, &remittances);
+        let remittance_id = execute_create_remittance(
+            &env,
+            &sender,
+            &agent,
+            amount,
+            &token,
+            &corridor.currency,
+            &corridor.country,
+            expiry,
+            None,
+            None,
+        )?;
+
+        emit_remittance_routed(&env, remittance_id, agent.clone(), get_routing_policy(&env));
+        Ok(RoutedRemittance { remittance_id, agent })
+    }
 
-        // Validate net settlement calculations
-        validate_net_settlement(&remittances, &net_transfers)?;
+    /// Sets the policy used to select agents for routed remittances (Admin only)
+    pub fn set_routing_policy(env: Env, caller: Address, policy: RoutingPolicy) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_routing_policy(&env, policy);
+        Ok(())
+    }
 
-        // Batch read storage values once
-        let usdc_token = get_usdc_token(&env)?;
-        let mut current_fees = get_accumulated_fees(&env)?;
-        
-        let token_client = token::Client::new(&env, &usdc_token);
+    /// Gets the routing policy (defaults to round-robin)
+    pub fn get_routing_policy(env: Env) -> RoutingPolicy {
+        get_routing_policy(&env)
+    }
 
-        // Execute net transfers
-        for i in 0..net_transfers.len() {
-            let transfer = net_transfers.get_unchecked(i);
+    /// Suspends an agent from routing (Admin only)
+    ///
+    /// Suspended agents keep their registration and existing remittances but
+    /// are skipped by `create_routed_remittance`.
+    pub fn suspend_agent(env: Env, caller: Address, agent: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_agent_suspended(&env, &agent, true);
+        emit_agent_suspended(&env, agent, caller);
+        Ok(())
+    }
 
-            // Determine actual sender and recipient based on net_amount sign
-            let (from, to, amount) = if transfer.net_amount > 0 {
-                // Positive: party_a -> party_b
-                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
-            } else if transfer.net_amount < 0 {
-                // Negative: party_b -> party_a
-                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
-            } else {
-                // Zero: complete offset, no transfer needed
-                continue;
-            };
+    /// Lifts an agent's routing suspension (Admin only)
+    pub fn reinstate_agent(env: Env, caller: Address, agent: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_agent_suspended(&env, &agent, false);
+        emit_agent_reinstated(&env, agent, caller);
+        Ok(())
+    }
 
-            // Calculate payout amount (net amount minus fees)
-            let payout_amount = amount
-                .checked_sub(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+    /// Returns whether an agent is suspended from routing
+    pub fn is_agent_suspended(env: Env, agent: Address) -> bool {
+        is_agent_suspended(&env, &agent)
+    }
 
-            // Execute the net transfer from contract to recipient
-            token_client.transfer(
-                &env.current_contract_address(),
-                &to,
-                &payout_amount,
-            );
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Operators
+    // ═══════════════════════════════════════════════════════════════════════════
 
-            // Accumulate fees in memory
-            current_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+    /// Grants an operator the right to confirm payouts on the agent's behalf
+    ///
+    /// Replaces any previous grant to the same operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent` - Registered agent granting the right
+    /// * `operator` - Operator address, such as a teller
+    /// * `max_amount` - Largest remittance amount the operator can settle
+    /// * `expiry` - Timestamp after which the grant no longer applies, if any
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Grant stored
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidAmount)` - Maximum amount is not positive
+    /// * `Err(ContractError::OperatorExpired)` - Expiry is already in the past
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn add_operator(
+        env: Env,
+        agent: Address,
+        operator: Address,
+        max_amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
+        validate_agent_registered(&env, &agent)?;
+        validate_amount(max_amount)?;
 
-            // Emit settlement event (using remittance ID from the transfer)
-            // Note: In batch processing, we use the first remittance ID as reference
-            let remittance_id = if i < remittances.len() {
-                remittances.get_unchecked(i).id
-            } else {
-                0
-            };
-            emit_settlement_completed(&env, remittance_id, from, to, usdc_token.clone(), payout_amount);
+        let now = env.ledger().timestamp();
+        if expiry.is_some_and(|expiry| expiry <= now) {
+            return Err(ContractError::OperatorExpired);
         }
 
-        // Write accumulated fees once at the end
-        set_accumulated_fees(&env, current_fees);
-
-        // Mark all remittances as completed and set settlement hashes
-        let mut settled_ids = Vec::new(&env);
+        set_operator_grant(
+            &env,
+            &agent,
+            &operator,
+            &OperatorGrant {
+                max_amount,
+                expiry,
+                granted_at: now,
+            },
+        );
 
-        for i in 0..remittances.len() {
-            let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Completed;
-            set_remittance(&env, remittance.id, &remittance);
-            set_settlement_hash(&env, remittance.id);
-            settled_ids.push_back(remittance.id);
+        emit_operator_added(&env, agent, operator, max_amount, expiry);
+        Ok(())
+    }
 
-            // Emit individual remittance completion event
-            let payout_amount = remittance
-                .amount
-                .checked_sub(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
-            emit_remittance_completed(
-                &env,
-                remittance.id,
-                remittance.sender,
-                remittance.agent,
-            );
+    /// Revokes an operator's right to confirm payouts for the agent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Grant removed
+    /// * `Err(ContractError::OperatorNotFound)` - The agent made no grant to the operator
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn revoke_operator(env: Env, agent: Address, operator: Address) -> Result<(), ContractError> {
+        agent.require_auth();
+        if get_operator_grant(&env, &agent, &operator).is_none() {
+            return Err(ContractError::OperatorNotFound);
         }
 
-        Ok(BatchSettlementResult { settled_ids })
+        remove_operator_grant(&env, &agent, &operator);
+        emit_operator_revoked(&env, agent, operator);
+        Ok(())
     }
 
-    /// Add a token to the whitelist. Only admins can call this.
-    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+    /// Gets the grant an agent made to an operator, if any
+    pub fn get_operator_grant(env: Env, agent: Address, operator: Address) -> Option<OperatorGrant> {
+        get_operator_grant(&env, &agent, &operator)
+    }
 
-        if is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenAlreadyWhitelisted);
-        }
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Commissions
+    // ═══════════════════════════════════════════════════════════════════════════
 
-        set_token_whitelisted(&env, &token, true);
-        
+    /// Sets the default agent commission and how it is paid (Admin only)
+    ///
+    /// The commission is a share of each settled remittance's platform fee,
+    /// in basis points. Agents with their own rate keep it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Configuration stored
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidFeeBps)` - Rate exceeds 10000 bps
+    pub fn set_commission_config(
+        env: Env,
+        caller: Address,
+        default_bps: u32,
+        payout: CommissionPayout,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_fee_bps(default_bps)?;
+
+        set_default_commission_bps(&env, default_bps);
+        set_commission_payout(&env, payout);
         Ok(())
     }
 
-    /// Remove a token from the whitelist. Only admins can call this.
-    pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
-
-        if !is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenNotWhitelisted);
+    /// Sets an agent's own commission rate, or clears it with `None` (Admin only)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate stored or cleared
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidFeeBps)` - Rate exceeds 10000 bps
+    pub fn set_agent_commission(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        bps: Option<u32>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_agent_registered(&env, &agent)?;
+        if let Some(bps) = bps {
+            validate_fee_bps(bps)?;
         }
 
-        set_token_whitelisted(&env, &token, false);
-        
+        set_agent_commission_bps(&env, &agent, bps);
         Ok(())
     }
 
-    /// Check if a token is whitelisted.
-    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
-        is_token_whitelisted(&env, &token)
+    /// Returns the commission rate that applies to an agent, in basis points
+    pub fn get_commission_bps(env: Env, agent: Address) -> u32 {
+        get_commission_bps(&env, &agent)
     }
 
-    /// Update rate limit configuration. Only admins can call this.
-    /// 
-    /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
-    /// - `max_requests`: Maximum number of requests allowed per window
-    /// - `window_seconds`: Time window in seconds
-    /// - `enabled`: Whether rate limiting is enabled
-    /// 
-    /// # Example
-    /// ```ignore
-    /// // Set rate limit to 50 requests per 30 seconds
-    /// contract.update_rate_limit_config(&admin, 50, 30, true)?;
-    /// ```
-    pub fn update_rate_limit_config(
+    /// Returns how agent commission is paid
+    pub fn get_commission_payout(env: Env) -> CommissionPayout {
+        get_commission_payout(&env)
+    }
+
+    /// Returns an agent's accrued, unclaimed commission in a token
+    pub fn get_commission_balance(env: Env, agent: Address, token: Address) -> i128 {
+        get_commission_balance(&env, &agent, &token)
+    }
+
+    /// Transfers an agent's accrued commission in a token to the agent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(amount)` - Commission claimed
+    /// * `Err(ContractError::AddressSanctioned)` - Agent is on the denylist
+    /// * `Err(ContractError::NoFeesToWithdraw)` - Nothing has accrued
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn claim_commission(env: Env, agent: Address, token: Address) -> Result<i128, ContractError> {
+        agent.require_auth();
+        validate_not_sanctioned(&env, &agent)?;
+
+        let amount = get_commission_balance(&env, &agent, &token);
+        if amount <= 0 {
+            return Err(ContractError::NoFeesToWithdraw);
+        }
+        set_commission_balance(&env, &agent, &token, 0);
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &agent, &amount);
+
+        emit_commission_claimed(&env, agent, token, amount);
+        Ok(amount)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Integrators
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Registers an integrator or updates its terms (Admin only)
+    ///
+    /// Integrators are white-label partners. Remittances created through one
+    /// pay its fee on top of the amount, credited to the integrator once the
+    /// remittance settles.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Admin address
+    /// * `integrator` - Integrator address
+    /// * `fee_bps` - Fee charged on top of remittances in basis points
+    /// * `withdrawal_address` - Address receiving the integrator's fee withdrawals
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Integrator registered
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds 10000 bps
+    pub fn register_integrator(
         env: Env,
         caller: Address,
-        max_requests: u32,
-        window_seconds: u64,
-        enabled: bool,
+        integrator: Address,
+        fee_bps: u32,
+        withdrawal_address: Address,
     ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
+        validate_fee_bps(fee_bps)?;
+        validate_address(&withdrawal_address)?;
 
-        let config = RateLimitConfig {
-            max_requests,
-            window_seconds,
-            enabled,
-        };
+        set_integrator_fee_bps(&env, &integrator, fee_bps);
+        set_integrator_withdrawal_address(&env, &integrator, &withdrawal_address);
+
+        emit_integrator_updated(&env, integrator, fee_bps, withdrawal_address);
+        Ok(())
+    }
+
+    /// Deregisters an integrator (Admin only)
+    ///
+    /// New remittances can no longer name the integrator. Fees it already
+    /// earned, and those of its remittances that settle later, stay withdrawable.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Integrator deregistered
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::IntegratorNotRegistered)` - Integrator is not registered
+    pub fn remove_integrator(env: Env, caller: Address, integrator: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        if get_integrator_fee_bps(&env, &integrator).is_none() {
+            return Err(ContractError::IntegratorNotRegistered);
+        }
+
+        remove_integrator_fee_bps(&env, &integrator);
+        emit_integrator_removed(&env, integrator);
+        Ok(())
+    }
 
-        set_rate_limit_config(&env, config);
+    /// Gets an integrator's fee terms, if registered
+    pub fn get_integrator(env: Env, integrator: Address) -> Option<Integrator> {
+        let fee_bps = get_integrator_fee_bps(&env, &integrator)?;
+        let withdrawal_address = get_integrator_withdrawal_address(&env, &integrator)?;
+        Some(Integrator {
+            fee_bps,
+            withdrawal_address,
+        })
+    }
 
-        Ok(())
+    /// Gets an integrator's accumulated, unwithdrawn fees in a token
+    pub fn get_integrator_fees(env: Env, integrator: Address, token: Address) -> i128 {
+        get_accumulated_integrator_fees(&env, &integrator, &token)
     }
 
-    /// Get current rate limit configuration
-    /// 
-    /// # Returns
-    /// Tuple of (max_requests, window_seconds, enabled)
-    pub fn get_rate_limit_config(env: Env) -> (u32, u64, bool) {
-        let config = get_rate_limit_config(&env);
-        (config.max_requests, config.window_seconds, config.enabled)
+    /// Gets the integrator fee charged on a remittance, if it was created through an integrator
+    pub fn get_integrator_charge(env: Env, remittance_id: u64) -> Option<IntegratorCharge> {
+        get_integrator_charge(&env, remittance_id)
     }
 
-    /// Get rate limit status for a specific address
-    /// 
-    /// # Parameters
-    /// - `address`: Address to check
-    /// 
+    /// Transfers an integrator's accumulated fees in a token to its withdrawal address
+    ///
     /// # Returns
-    /// Tuple of (current_requests, max_requests, window_seconds)
-    pub fn get_rate_limit_status(env: Env, address: Address) -> (u32, u32, u64) {
-        get_rate_limit_status(&env, &address)
+    ///
+    /// * `Ok(amount)` - Fees withdrawn
+    /// * `Err(ContractError::IntegratorNotRegistered)` - Address was never registered as an integrator
+    /// * `Err(ContractError::AddressSanctioned)` - The withdrawal address is on the denylist
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees have accumulated
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the integrator.
+    pub fn withdraw_integrator_fees(env: Env, integrator: Address, token: Address) -> Result<i128, ContractError> {
+        integrator.require_auth();
+
+        let to = get_integrator_withdrawal_address(&env, &integrator)
+            .ok_or(ContractError::IntegratorNotRegistered)?;
+        validate_not_sanctioned(&env, &to)?;
+
+        let fees = get_accumulated_integrator_fees(&env, &integrator, &token);
+        if fees <= 0 {
+            return Err(ContractError::NoFeesToWithdraw);
+        }
+        set_accumulated_integrator_fees(&env, &integrator, &token, 0);
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &fees);
+
+        emit_integrator_fees_withdrawn(&env, integrator, to, token, fees);
+        Ok(fees)
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
-    // Protocol Fee Management
+    // Oracle Proof Validation
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Updates the protocol fee (Admin only, max 200 bps)
-    pub fn update_protocol_fee(env: Env, caller: Address, fee_bps: u32) -> Result<(), ContractError> {
-        caller.require_auth();
+    /// Registers an oracle ed25519 public key trusted to sign settlement proofs (Admin only)
+    pub fn register_oracle(env: Env, caller: Address, oracle: BytesN<32>) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        set_protocol_fee_bps(&env, fee_bps)?;
+        set_oracle_registered(&env, &oracle, true);
+        emit_oracle_registered(&env, oracle);
         Ok(())
     }
 
-    /// Updates the treasury address (Admin only)
-    pub fn update_treasury(env: Env, caller: Address, treasury: Address) -> Result<(), ContractError> {
-        caller.require_auth();
+    /// Removes an oracle public key from the registry (Admin only)
+    pub fn remove_oracle(env: Env, caller: Address, oracle: BytesN<32>) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        set_treasury(&env, &treasury);
+        set_oracle_registered(&env, &oracle, false);
+        emit_oracle_removed(&env, oracle);
         Ok(())
     }
 
-    /// Gets the current protocol fee in basis points
-    pub fn get_protocol_fee_bps(env: Env) -> u32 {
-        get_protocol_fee_bps(&env)
+    /// Checks if an oracle public key is registered
+    pub fn is_oracle_registered(env: Env, oracle: BytesN<32>) -> bool {
+        is_oracle_registered(&env, &oracle)
     }
 
-    /// Gets the treasury address
-    pub fn get_treasury(env: Env) -> Result<Address, ContractError> {
-        get_treasury(&env)
+    /// Requires oracle proofs for every settlement of an agent's remittances (Admin only)
+    pub fn set_agent_proof_required(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        required: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        set_agent_proof_required(&env, &agent, required);
+        Ok(())
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Role-Based Authorization Functions
-    // ═══════════════════════════════════════════════════════════════════════════
+    /// Requires an oracle proof to settle a pending remittance.
+    ///
+    /// Once set, the remittance can only be settled through
+    /// `confirm_payout_with_proof`. The requirement cannot be lifted.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn require_remittance_proof(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+        validate_remittance_pending(&remittance)?;
 
-    /// Assigns a role to an address (Admin only)
-    pub fn assign_role(env: Env, caller: Address, address: Address, role: Role) -> Result<(), ContractError> {
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        assign_role(&env, &address, &role);
+        set_remittance_proof_required(&env, remittance_id);
         Ok(())
     }
 
-    /// Removes a role from an address (Admin only)
-    pub fn remove_role(env: Env, caller: Address, address: Address, role: Role) -> Result<(), ContractError> {
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        remove_role(&env, &address, &role);
-        Ok(())
+    /// Checks if settling a remittance requires an oracle proof
+    pub fn is_proof_required(env: Env, remittance_id: u64) -> Result<bool, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        Ok(requires_proof(&env, &remittance))
     }
 
-    /// Checks if an address has a specific role
-    pub fn has_role(env: Env, address: Address, role: Role) -> bool {
-        has_role(&env, &address, &role)
-    }
-    
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Fee Strategy Management
-    // ═══════════════════════════════════════════════════════════════════════════
-    
-    /// Updates the fee strategy (Admin only)
-    /// 
-    /// Allows switching between different fee calculation methods:
-    /// - Percentage: Fee based on basis points (e.g., 250 = 2.5%)
-    /// - Flat: Fixed fee amount regardless of transaction size
-    /// - Dynamic: Tiered fee that decreases for larger amounts
-    /// 
-    /// # Arguments
-    /// * `caller` - Admin address (must be authorized)
-    /// * `strategy` - New fee strategy to apply
-    /// 
-    /// # Examples
-    /// ```ignore
-    /// // Set 2.5% percentage fee
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Percentage(250))?;
-    /// 
-    /// // Set flat 100 USDC fee
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Flat(100_0000000))?;
-    /// 
-    /// // Set dynamic tiered fee starting at 4%
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Dynamic(400))?;
-    /// ```
-    pub fn update_fee_strategy(env: Env, caller: Address, strategy: FeeStrategy) -> Result<(), ContractError> {
-        caller.require_auth();
-        require_admin(&env, &caller)?;
-        set_fee_strategy(&env, &strategy);
-        Ok(())
-    }
-    
-    /// Gets the current fee strategy
-    pub fn get_fee_strategy(env: Env) -> FeeStrategy {
-        get_fee_strategy(&env)
-    }
-    
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Transfer State Registry (Read-Only for Indexers)
-    // ═══════════════════════════════════════════════════════════════════════════
-    
-    /// Gets the current state of a transfer (read-only for indexers)
-    pub fn get_transfer_state(env: Env, transfer_id: u64) -> Option<TransferState> {
-        get_transfer_state(&env, transfer_id)
+    /// Verifies an oracle proof for a remittance without settling it.
+    ///
+    /// Lets oracles and agents check a proof before submitting
+    /// `confirm_payout_with_proof`. An invalid signature aborts the invocation.
+    pub fn verify_proof(env: Env, remittance_id: u64, proof: ProofData) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        verify_proof(&env, &remittance, &proof)
     }
 
     // ========== Asset Verification Functions ==========
@@ -1235,3 +4021,467 @@ impl SwiftRemitContract {
         Ok(())
     }
 }
+
+/// Shared remittance creation path used by every `create_remittance` variant.
+///
+/// Assumes the request has been validated and the sender has authorized it.
+/// Enforces the corridor's daily limit, pulls `amount` from the sender,
+/// applies the configured fee strategy, or the fee locked in by `quote`, and
+/// stores the new pending remittance.
+fn execute_create_remittance(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+    token: &Address,
+    currency: &String,
+    country: &String,
+    expiry: Option<u64>,
+    integrator: Option<&Address>,
+    quote: Option<&Quote>,
+) -> Result<u64, ContractError> {
+    validate_not_sanctioned(env, sender)?;
+    validate_not_sanctioned(env, agent)?;
+    check_and_record_kyc_usage(env, sender, token, amount)?;
+    check_and_record_daily_limit(env, sender, currency, country, token, amount)?;
+
+    let token_client = token::Client::new(env, token);
+    token_client.transfer(sender, &env.current_contract_address(), &amount);
+
+    record_new_remittance(env, sender, agent, amount, token, currency, country, expiry, integrator, quote)
+}
+
+/// Creates the remittance for one standing order installment.
+///
+/// Like `execute_create_remittance`, but pulls the installment through the
+/// sender's token allowance since the sender is not part of the invocation.
+fn execute_standing_order_installment(env: &Env, order: &StandingOrder) -> Result<u64, ContractError> {
+    check_and_record_kyc_usage(env, &order.sender, &order.token, order.amount)?;
+    check_and_record_daily_limit(env, &order.sender, &order.currency, &order.country, &order.token, order.amount)?;
+
+    let token_client = token::Client::new(env, &order.token);
+    let contract_address = env.current_contract_address();
+    token_client.transfer_from(&contract_address, &order.sender, &contract_address, &order.amount);
+
+    record_new_remittance(
+        env,
+        &order.sender,
+        &order.agent,
+        order.amount,
+        &order.token,
+        &order.currency,
+        &order.country,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Returns whether the sender's allowance, balance, daily limit and KYC caps cover the next installment.
+fn can_fund_installment(env: &Env, order: &StandingOrder) -> Result<bool, ContractError> {
+    if !is_agent_registered(env, &order.agent) || !is_token_whitelisted(env, &order.token) {
+        return Ok(false);
+    }
+    if is_denylisted(env, &order.sender) || is_denylisted(env, &order.agent) {
+        return Ok(false);
+    }
+    if !agent_serves_corridor(env, &order.agent, &order.currency, &order.country)
+        || check_agent_bond(env, &order.agent, &order.token, order.amount).is_err()
+        || check_agent_capacity(env, &order.agent, &order.token, order.amount).is_err()
+    {
+        return Ok(false);
+    }
+    if let Some(remaining) = get_remaining_daily_allowance(env, &order.sender, &order.currency, &order.country, &order.token)? {
+        if remaining < order.amount {
+            return Ok(false);
+        }
+    }
+    if let Some(headroom) = get_kyc_headroom(env, &order.sender, &order.token)? {
+        if order.amount > headroom.per_transaction.min(headroom.daily_remaining).min(headroom.monthly_remaining) {
+            return Ok(false);
+        }
+    }
+
+    let token_client = token::Client::new(env, &order.token);
+    Ok(token_client.allowance(&order.sender, &env.current_contract_address()) >= order.amount
+        && token_client.balance(&order.sender) >= order.amount)
+}
+
+/// Moves a standing order between statuses on behalf of its sender.
+fn update_standing_order_status(
+    env: &Env,
+    order_id: u64,
+    from: StandingOrderStatus,
+    to: StandingOrderStatus,
+) -> Result<(), ContractError> {
+    let mut order = get_standing_order(env, order_id)?;
+    order.sender.require_auth();
+
+    if order.status != from {
+        return Err(ContractError::InvalidStatus);
+    }
+    order.status = to.clone();
+    set_standing_order(env, &order);
+
+    emit_standing_order_status(env, order_id, to);
+    Ok(())
+}
+
+/// Applies the fee strategy to funds already held by the contract, stores
+/// the new pending remittance, charges any integrator fee and emits the
+/// creation event.
+///
+/// The integrator fee is pulled from the sender, so `integrator` is only
+/// passed when the sender authorized the invocation.
+fn record_new_remittance(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+    token: &Address,
+    currency: &String,
+    country: &String,
+    expiry: Option<u64>,
+    integrator: Option<&Address>,
+    quote: Option<&Quote>,
+) -> Result<u64, ContractError> {
+    // Open-claim remittances are checked and counted when an agent claims them
+    if *agent != env.current_contract_address() {
+        validate_agent_serves_corridor(env, agent, currency, country)?;
+        check_agent_bond(env, agent, token, amount)?;
+        add_agent_exposure(env, agent, token, amount)?;
+    }
+
+    // Use the quoted fee, or the corridor's, token's or global fee schedule
+    let fee = match quote {
+        Some(quote) => quote.platform_fee,
+        None => {
+            let schedule = resolve_fee_schedule(env, token, Some((currency, country)));
+            calculate_scheduled_fee(env, &schedule, amount)?
+        }
+    };
+    let protocol_fee = match quote {
+        Some(quote) => quote.protocol_fee,
+        None => compute_protocol_fee(env, amount)?,
+    };
+    compute_payout_amount(amount, fee, protocol_fee)?;
+
+    let counter = get_remittance_counter(env)?;
+    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+    let remittance = Remittance {
+        id: remittance_id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        amount,
+        fee,
+        token: token.clone(),
+        status: RemittanceStatus::Pending,
+        expiry,
+        currency: currency.clone(),
+        country: country.clone(),
+        dest_amount: quote.map(|quote| quote.dest_amount),
+        protocol_fee: quote.map(|quote| quote.protocol_fee),
+        created_at: env.ledger().timestamp(),
+    };
+
+    set_remittance(env, remittance_id, &remittance);
+    set_remittance_counter(env, remittance_id);
+    index_remittance(env, &remittance);
+
+    // Set initial transfer state
+    set_transfer_state(env, remittance_id, TransferState::Initiated)?;
+
+    let integrator_fee = match integrator {
+        Some(integrator) => charge_integrator_fee(env, remittance_id, sender, integrator, amount, token)?,
+        None => 0,
+    };
+    emit_remittance_created(env, remittance_id, sender.clone(), agent.clone(), amount, fee, integrator_fee);
+
+    Ok(remittance_id)
+}
+
+/// Applies the current protocol fee rate to `amount`.
+fn compute_protocol_fee(env: &Env, amount: i128) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(get_protocol_fee_bps(env) as i128)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(10000)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Deducts the platform and protocol fees from `amount`.
+///
+/// Fails with `FeesExceedAmount` rather than returning a negative payout.
+fn compute_payout_amount(amount: i128, fee: i128, protocol_fee: i128) -> Result<i128, ContractError> {
+    let payout_amount = amount
+        .checked_sub(fee)
+        .ok_or(ContractError::Overflow)?
+        .checked_sub(protocol_fee)
+        .ok_or(ContractError::Overflow)?;
+    if payout_amount < 0 {
+        return Err(ContractError::FeesExceedAmount);
+    }
+    Ok(payout_amount)
+}
+
+/// Charges a remittance's sender the integrator fee on top of the amount.
+///
+/// The fee is held by the contract until the remittance settles, and
+/// refunded with the amount if it does not. Returns the fee charged.
+fn charge_integrator_fee(
+    env: &Env,
+    remittance_id: u64,
+    sender: &Address,
+    integrator: &Address,
+    amount: i128,
+    token: &Address,
+) -> Result<i128, ContractError> {
+    let fee_bps = get_integrator_fee_bps(env, integrator).ok_or(ContractError::IntegratorNotRegistered)?;
+    let fee = amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(10000)
+        .ok_or(ContractError::Overflow)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    token::Client::new(env, token).transfer(sender, &env.current_contract_address(), &fee);
+    set_integrator_charge(
+        env,
+        remittance_id,
+        &IntegratorCharge {
+            integrator: integrator.clone(),
+            fee,
+        },
+    );
+    Ok(fee)
+}
+
+/// Credits a settled remittance's integrator fee to the integrator.
+fn accrue_integrator_fee(env: &Env, remittance: &Remittance) -> Result<(), ContractError> {
+    if let Some(charge) = get_integrator_charge(env, remittance.id) {
+        let fees = get_accumulated_integrator_fees(env, &charge.integrator, &remittance.token)
+            .checked_add(charge.fee)
+            .ok_or(ContractError::Overflow)?;
+        set_accumulated_integrator_fees(env, &charge.integrator, &remittance.token, fees);
+    }
+    Ok(())
+}
+
+/// Returns a refunded remittance's integrator fee to its sender.
+fn refund_integrator_fee(env: &Env, remittance: &Remittance) {
+    if let Some(charge) = get_integrator_charge(env, remittance.id) {
+        token::Client::new(env, &remittance.token).transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &charge.fee,
+        );
+    }
+}
+
+/// Carves the agent's commission out of a settled remittance's platform fee.
+///
+/// Returns the commission and the part of it to transfer with the payout.
+/// In accrual mode the commission is credited to the agent's claimable
+/// balance instead and nothing is transferred.
+fn carve_agent_commission(env: &Env, remittance: &Remittance) -> Result<(i128, i128), ContractError> {
+    let commission = calculate_commission(remittance.fee, get_commission_bps(env, &remittance.agent))?;
+    if commission == 0 {
+        return Ok((0, 0));
+    }
+
+    let payout = get_commission_payout(env);
+    if payout == CommissionPayout::Accrue {
+        accrue_commission(env, &remittance.agent, &remittance.token, commission)?;
+    }
+    emit_commission_earned(
+        env,
+        remittance.id,
+        remittance.agent.clone(),
+        remittance.token.clone(),
+        commission,
+        payout,
+    );
+
+    match payout {
+        CommissionPayout::WithPayout => Ok((commission, commission)),
+        CommissionPayout::Accrue => Ok((commission, 0)),
+    }
+}
+
+/// Shared settlement path used by `confirm_payout` and `confirm_payout_with_proof`.
+///
+/// Assumes `remittance` has passed `validate_confirm_payout_request` and any
+/// required proof has been verified.
+fn execute_confirm_payout(env: &Env, remittance: Remittance) -> Result<(), ContractError> {
+    remittance.agent.require_auth();
+    settle_remittance(env, remittance, None)
+}
+
+/// Pays out a remittance whose settlement the agent, or one of its operators, authorized.
+fn settle_remittance(env: &Env, mut remittance: Remittance, operator: Option<Address>) -> Result<(), ContractError> {
+    let remittance_id = remittance.id;
+
+    if get_sanctioned_party(env, &remittance).is_some() {
+        return Err(ContractError::AddressSanctioned);
+    }
+
+    // Require Settler role
+    require_role_settler(env, &remittance.agent)?;
+    
+    // Lock the remittance for payout unless the agent already accepted it
+    if remittance.status == RemittanceStatus::Pending {
+        transition_remittance(env, &mut remittance, RemittanceStatus::Processing)?;
+    }
+
+    // Check rate limit for sender
+    check_settlement_rate_limit(env, &remittance.sender)?;
+
+    // Use the quoted protocol fee, or the current rate
+    let protocol_fee = match remittance.protocol_fee {
+        Some(protocol_fee) => protocol_fee,
+        None => compute_protocol_fee(env, remittance.amount)?,
+    };
+
+    // Calculate payout after platform and protocol fees
+    let payout_amount = compute_payout_amount(remittance.amount, remittance.fee, protocol_fee)?;
+
+    // Split the agent's commission off the platform fee
+    let (commission, commission_paid) = carve_agent_commission(env, &remittance)?;
+
+    // Batch read storage values
+    let current_fees = get_accumulated_fees(env, &remittance.token);
+    let current_time = env.ledger().timestamp();
+    
+    let token_client = token::Client::new(env, &remittance.token);
+    
+    // Transfer payout, plus any commission paid with it, to agent
+    let agent_amount = payout_amount
+        .checked_add(commission_paid)
+        .ok_or(ContractError::Overflow)?;
+    token_client.transfer(
+        &env.current_contract_address(),
+        &remittance.agent,
+        &agent_amount,
+    );
+    
+    // Transfer protocol fee to treasury if needed
+    if protocol_fee > 0 {
+        let treasury = get_treasury(env)?;
+        token_client.transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &protocol_fee,
+        );
+    }
+
+    // Update accumulated fees with the platform's share of the fee
+    let new_fees = current_fees
+        .checked_add(remittance.fee)
+        .ok_or(ContractError::Overflow)?
+        .checked_sub(commission)
+        .ok_or(ContractError::Overflow)?;
+    set_accumulated_fees(env, &remittance.token, new_fees);
+
+    // Update remittance status
+    transition_remittance(env, &mut remittance, RemittanceStatus::Completed)?;
+    remove_acceptance_deadline(env, remittance_id);
+    record_settlement(env, remittance_id, payout_amount, commission);
+    record_agent_settlement(
+        env,
+        &remittance.agent,
+        &remittance.token,
+        remittance.amount,
+        remittance.created_at,
+    )?;
+    release_agent_exposure(env, &remittance.agent, &remittance.token, remittance.amount);
+    accrue_integrator_fee(env, &remittance)?;
+
+    // Mark settlement as executed to prevent duplicates
+    set_settlement_hash(env, remittance_id);
+    
+    // Update last settlement time for rate limiting
+    set_last_settlement_time(env, &remittance.sender, current_time);
+
+    if let Some(operator) = operator {
+        emit_operator_settled(env, remittance_id, remittance.agent.clone(), operator);
+    }
+
+    // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
+    // Used by off-chain systems to track successful settlements and update transaction status
+    emit_remittance_completed(env, remittance_id, remittance.sender.clone(), remittance.agent.clone());
+    
+    // Event: Settlement completed - Fires with final executed settlement values
+    // Used by off-chain systems for reconciliation and audit trails of completed transactions
+    emit_settlement_completed(env, remittance_id, remittance.sender, remittance.agent, remittance.token, payout_amount);
+
+    log_confirm_payout(env, remittance_id, payout_amount);
+
+    Ok(())
+}
+
+/// Returns whether a remittance is pending and past its expiry.
+fn is_expirable(env: &Env, remittance: &Remittance) -> bool {
+    remittance.status == RemittanceStatus::Pending
+        && validate_settlement_not_expired(env, remittance.expiry).is_err()
+}
+
+/// Refunds an expired remittance to its sender and marks it Expired.
+///
+/// Returns `Ok(true)` if the remittance was refunded and `Ok(false)` if it
+/// was frozen instead because a party is sanctioned.
+fn execute_expire_remittance(env: &Env, mut remittance: Remittance) -> Result<bool, ContractError> {
+    // Sanctioned remittances are held rather than refunded automatically
+    if let Some(party) = get_sanctioned_party(env, &remittance) {
+        freeze_remittance(env, &mut remittance, party)?;
+        return Ok(false);
+    }
+
+    let token_client = token::Client::new(env, &remittance.token);
+    token_client.transfer(
+        &env.current_contract_address(),
+        &remittance.sender,
+        &remittance.amount,
+    );
+    refund_integrator_fee(env, &remittance);
+
+    transition_remittance(env, &mut remittance, RemittanceStatus::Expired)?;
+    if remittance.agent != env.current_contract_address() {
+        release_agent_exposure(env, &remittance.agent, &remittance.token, remittance.amount);
+    }
+
+    emit_remittance_expired(env, remittance.id, remittance.sender, remittance.amount);
+
+    Ok(true)
+}
+
+/// Freezes a remittance involving a sanctioned party, releasing any agent acceptance.
+fn freeze_remittance(env: &Env, remittance: &mut Remittance, party: Address) -> Result<(), ContractError> {
+    transition_remittance(env, remittance, RemittanceStatus::Frozen)?;
+    remove_acceptance_deadline(env, remittance.id);
+
+    emit_remittance_frozen(env, remittance.id, party);
+    Ok(())
+}
+
+/// Returns an accepted remittance to Pending if its acceptance has lapsed.
+///
+/// Returns `Ok(true)` if the remittance was reverted and `Ok(false)` if the
+/// acceptance is still active.
+fn revert_lapsed_acceptance(env: &Env, remittance: &mut Remittance) -> Result<bool, ContractError> {
+    let lapsed = match get_acceptance_deadline(env, remittance.id) {
+        Some(deadline) => env.ledger().timestamp() > deadline,
+        None => true,
+    };
+    if !lapsed {
+        return Ok(false);
+    }
+
+    transition_remittance(env, remittance, RemittanceStatus::Pending)?;
+    remove_acceptance_deadline(env, remittance.id);
+    emit_acceptance_lapsed(env, remittance.id, remittance.agent.clone());
+
+    Ok(true)
+}
@@ -1,6 +1,6 @@
 use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec, xdr::ToXdr};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{ContractError, Remittance};
 
 /// Maximum number of items that can be exported/imported in a single batch
 /// to prevent excessive resource consumption
@@ -46,8 +46,8 @@ pub struct InstanceData {
     /// Global remittance counter
     pub remittance_counter: u64,
     
-    /// Accumulated platform fees
-    pub accumulated_fees: i128,
+    /// Accumulated platform fees for every token that was ever whitelisted
+    pub accumulated_fees: Vec<TokenFees>,
     
     /// Contract pause status
     pub paused: bool,
@@ -56,6 +56,17 @@ pub struct InstanceData {
     pub admin_count: u32,
 }
 
+/// Accumulated platform fees held in one token
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenFees {
+    /// Token contract address
+    pub token: Address,
+
+    /// Accumulated platform fees in this token
+    pub fees: i128,
+}
+
 /// Persistent storage data (per-entity data)
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -125,13 +136,22 @@ pub struct MigrationVerification {
 /// # Returns
 /// MigrationSnapshot containing all contract state
 pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
+    // Collect accumulated fees per token
+    let known_tokens = crate::storage::get_known_tokens(env);
+    let mut accumulated_fees = Vec::new(env);
+    for i in 0..known_tokens.len() {
+        let token = known_tokens.get_unchecked(i);
+        let fees = crate::storage::get_accumulated_fees(env, &token);
+        accumulated_fees.push_back(TokenFees { token, fees });
+    }
+
     // Collect instance data
     let instance_data = InstanceData {
         admin: crate::storage::get_admin(env)?,
         usdc_token: crate::storage::get_usdc_token(env)?,
         platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
         remittance_counter: crate::storage::get_remittance_counter(env)?,
-        accumulated_fees: crate::storage::get_accumulated_fees(env)?,
+        accumulated_fees,
         paused: crate::storage::is_paused(env),
         admin_count: crate::storage::get_admin_count(env),
     };
@@ -163,7 +183,13 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     }
     
     // Collect whitelisted tokens
-    let whitelisted_tokens = Vec::new(env);
+    let mut whitelisted_tokens = Vec::new(env);
+    for i in 0..known_tokens.len() {
+        let token = known_tokens.get_unchecked(i);
+        if crate::storage::is_token_whitelisted(env, &token) {
+            whitelisted_tokens.push_back(token);
+        }
+    }
     
     let persistent_data = PersistentData {
         remittances,
@@ -242,7 +268,10 @@ pub fn import_state(
     crate::storage::set_usdc_token(env, &snapshot.instance_data.usdc_token);
     crate::storage::set_platform_fee_bps(env, snapshot.instance_data.platform_fee_bps);
     crate::storage::set_remittance_counter(env, snapshot.instance_data.remittance_counter);
-    crate::storage::set_accumulated_fees(env, snapshot.instance_data.accumulated_fees);
+    for i in 0..snapshot.instance_data.accumulated_fees.len() {
+        let entry = snapshot.instance_data.accumulated_fees.get_unchecked(i);
+        crate::storage::set_accumulated_fees(env, &entry.token, entry.fees);
+    }
     crate::storage::set_paused(env, snapshot.instance_data.paused);
     crate::storage::set_admin_count(env, snapshot.instance_data.admin_count);
     
@@ -252,6 +281,7 @@ pub fn import_state(
     for i in 0..snapshot.persistent_data.remittances.len() {
         let remittance = snapshot.persistent_data.remittances.get_unchecked(i);
         crate::storage::set_remittance(env, remittance.id, &remittance);
+        crate::storage::index_remittance(env, &remittance);
     }
     
     // Import agents
@@ -272,7 +302,12 @@ pub fn import_state(
         crate::storage::set_settlement_hash(env, id);
     }
     
-    // Import whitelisted tokens
+    // Import whitelist status, keeping removed tokens that still hold fees known
+    for i in 0..snapshot.instance_data.accumulated_fees.len() {
+        let token = snapshot.instance_data.accumulated_fees.get_unchecked(i).token;
+        let whitelisted = snapshot.persistent_data.whitelisted_tokens.contains(&token);
+        crate::storage::set_token_whitelisted(env, &token, whitelisted);
+    }
     for i in 0..snapshot.persistent_data.whitelisted_tokens.len() {
         let token = snapshot.persistent_data.whitelisted_tokens.get_unchecked(i);
         crate::storage::set_token_whitelisted(env, &token, true);
@@ -290,8 +325,8 @@ pub fn import_state(
 /// 
 /// # Algorithm
 /// Uses SHA-256 hash of concatenated serialized data:
-/// 1. Instance data (admin, token, fees, counters)
-/// 2. Persistent data (remittances, agents, etc.)
+/// 1. Instance data (admin, token, per-token fees, counters)
+/// 2. Persistent data (every remittance field via XDR, agents, etc.)
 /// 3. Timestamp and ledger sequence
 /// 
 /// # Returns
@@ -310,7 +345,11 @@ fn compute_snapshot_hash(
     data.append(&instance_data.usdc_token.clone().to_xdr(env));
     data.append(&Bytes::from_array(env, &instance_data.platform_fee_bps.to_be_bytes()));
     data.append(&Bytes::from_array(env, &instance_data.remittance_counter.to_be_bytes()));
-    data.append(&Bytes::from_array(env, &instance_data.accumulated_fees.to_be_bytes()));
+    for i in 0..instance_data.accumulated_fees.len() {
+        let entry = instance_data.accumulated_fees.get_unchecked(i);
+        data.append(&entry.token.clone().to_xdr(env));
+        data.append(&Bytes::from_array(env, &entry.fees.to_be_bytes()));
+    }
     data.append(&Bytes::from_array(env, &[if instance_data.paused { 1u8 } else { 0u8 }]));
     data.append(&Bytes::from_array(env, &instance_data.admin_count.to_be_bytes()));
     
@@ -318,23 +357,7 @@ fn compute_snapshot_hash(
     
     // Remittances
     for i in 0..persistent_data.remittances.len() {
-        let r = persistent_data.remittances.get_unchecked(i);
-        data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
-        data.append(&r.sender.clone().to_xdr(env));
-        data.append(&r.agent.clone().to_xdr(env));
-        data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
-        data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
-        
-        let status_byte = match r.status {
-            RemittanceStatus::Pending => 0u8,
-            RemittanceStatus::Completed => 1u8,
-            RemittanceStatus::Cancelled => 2u8,
-        };
-        data.append(&Bytes::from_array(env, &[status_byte]));
-        
-        if let Some(expiry) = r.expiry {
-            data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
-        }
+        data.append(&persistent_data.remittances.get_unchecked(i).to_xdr(env));
     }
     
     // Agents
@@ -492,23 +515,7 @@ fn compute_batch_hash(
     
     // Add all remittances
     for i in 0..remittances.len() {
-        let r = remittances.get_unchecked(i);
-        data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
-        data.append(&r.sender.clone().to_xdr(env));
-        data.append(&r.agent.clone().to_xdr(env));
-        data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
-        data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
-        
-        let status_byte = match r.status {
-            RemittanceStatus::Pending => 0u8,
-            RemittanceStatus::Completed => 1u8,
-            RemittanceStatus::Cancelled => 2u8,
-        };
-        data.append(&Bytes::from_array(env, &[status_byte]));
-        
-        if let Some(expiry) = r.expiry {
-            data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
-        }
+        data.append(&remittances.get_unchecked(i).to_xdr(env));
     }
     
     env.crypto().sha256(&data).into()
@@ -528,7 +535,7 @@ mod tests {
             usdc_token: Address::generate(&env),
             platform_fee_bps: 250,
             remittance_counter: 10,
-            accumulated_fees: 1000,
+            accumulated_fees: Vec::new(&env),
             paused: false,
             admin_count: 1,
         };
@@ -547,6 +554,72 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    fn sample_remittance(env: &Env) -> Remittance {
+        Remittance {
+            id: 1,
+            sender: Address::generate(env),
+            agent: Address::generate(env),
+            amount: 1000,
+            fee: 25,
+            token: Address::generate(env),
+            status: crate::RemittanceStatus::Pending,
+            expiry: None,
+            currency: soroban_sdk::String::from_str(env, "NGN"),
+            country: soroban_sdk::String::from_str(env, "NG"),
+            dest_amount: None,
+            protocol_fee: None,
+            created_at: 100,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_hash_covers_every_remittance_field() {
+        let env = Env::default();
+
+        let instance_data = InstanceData {
+            admin: Address::generate(&env),
+            usdc_token: Address::generate(&env),
+            platform_fee_bps: 250,
+            remittance_counter: 1,
+            accumulated_fees: Vec::new(&env),
+            paused: false,
+            admin_count: 1,
+        };
+        let original = sample_remittance(&env);
+        let snapshot_with = |remittance: Remittance| PersistentData {
+            remittances: Vec::from_array(&env, [remittance]),
+            agents: Vec::new(&env),
+            admin_roles: Vec::new(&env),
+            settlement_hashes: Vec::new(&env),
+            whitelisted_tokens: Vec::new(&env),
+        };
+        let base = compute_snapshot_hash(&env, &instance_data, &snapshot_with(original.clone()), 1000, 100);
+
+        let mut changed = original.clone();
+        changed.token = Address::generate(&env);
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+
+        let mut changed = original.clone();
+        changed.currency = soroban_sdk::String::from_str(&env, "GHS");
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+
+        let mut changed = original.clone();
+        changed.country = soroban_sdk::String::from_str(&env, "GH");
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+
+        let mut changed = original.clone();
+        changed.dest_amount = Some(500);
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+
+        let mut changed = original.clone();
+        changed.protocol_fee = Some(10);
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+
+        let mut changed = original;
+        changed.created_at = 101;
+        assert_ne!(base, compute_snapshot_hash(&env, &instance_data, &snapshot_with(changed), 1000, 100));
+    }
+
     #[test]
     fn test_snapshot_hash_changes_with_data() {
         let env = Env::default();
@@ -556,7 +629,7 @@ mod tests {
             usdc_token: Address::generate(&env),
             platform_fee_bps: 250,
             remittance_counter: 10,
-            accumulated_fees: 1000,
+            accumulated_fees: Vec::new(&env),
             paused: false,
             admin_count: 1,
         };
@@ -566,7 +639,7 @@ mod tests {
             usdc_token: instance_data1.usdc_token.clone(),
             platform_fee_bps: 300, // Different fee
             remittance_counter: 10,
-            accumulated_fees: 1000,
+            accumulated_fees: Vec::new(&env),
             paused: false,
             admin_count: 1,
         };
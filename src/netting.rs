@@ -1,6 +1,6 @@
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{can_settle, ContractError, Remittance};
 
 /// Represents a net transfer between two parties after offsetting opposing flows.
 /// This structure ensures deterministic ordering by always placing the party
@@ -61,8 +61,8 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
     for i in 0..remittances.len() {
         let remittance = remittances.get_unchecked(i);
 
-        // Only process pending remittances
-        if remittance.status != RemittanceStatus::Pending {
+        // Only process remittances that can still be settled
+        if !can_settle(&remittance.status) {
             continue;
         }
 
@@ -176,7 +176,7 @@ pub fn validate_net_settlement(
 
     for i in 0..original_remittances.len() {
         let remittance = original_remittances.get_unchecked(i);
-        if remittance.status == RemittanceStatus::Pending {
+        if can_settle(&remittance.status) {
             total_original_amount = total_original_amount
                 .checked_add(remittance.amount)
                 .ok_or(ContractError::Overflow)?;
@@ -222,7 +222,33 @@ pub fn validate_net_settlement(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use crate::RemittanceStatus;
+    use soroban_sdk::{testutils::Address as _, Env, String};
+
+    fn pending_remittance(
+        env: &Env,
+        id: u64,
+        sender: &Address,
+        agent: &Address,
+        amount: i128,
+        fee: i128,
+    ) -> Remittance {
+        Remittance {
+            id,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            fee,
+            token: Address::generate(env),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+            currency: String::from_str(env, "USD"),
+            country: String::from_str(env, "US"),
+            dest_amount: None,
+            protocol_fee: None,
+            created_at: 0,
+        }
+    }
 
     #[test]
     fn test_simple_netting() {
@@ -233,40 +259,20 @@ mod tests {
         let mut remittances = Vec::new(&env);
 
         // A -> B: 100
-        remittances.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
 
         // B -> A: 90
-        remittances.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_a.clone(),
-            amount: 90,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 2, &addr_b, &addr_a, 90, 1));
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
 
         assert_eq!(net_transfers.len(), 1);
         let transfer = net_transfers.get_unchecked(0);
 
-        // Net should be 10 (100 - 90)
-        let expected_net = if compare_addresses(&addr_a, &addr_b) < 0 {
-            10 // A -> B
-        } else {
-            -10 // B -> A
-        };
+        // Net should be 10 (100 - 90), A -> B
+        let expected_net = if compare_addresses(&addr_a, &addr_b) < 0 { 10 } else { -10 };
 
-        assert_eq!(transfer.net_amount.abs(), 10);
+        assert_eq!(transfer.net_amount, expected_net);
         assert_eq!(transfer.total_fees, 3); // 2 + 1
     }
 
@@ -279,28 +285,12 @@ mod tests {
         let mut remittances = Vec::new(&env);
 
         // A -> B: 100
-        remittances.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
 
         // B -> A: 100
-        remittances.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_a.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 2, &addr_b, &addr_a, 100, 2));
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
 
         // Complete offset should result in no transfers
         assert_eq!(net_transfers.len(), 0);
@@ -316,39 +306,15 @@ mod tests {
         let mut remittances = Vec::new(&env);
 
         // A -> B: 100
-        remittances.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
 
         // B -> C: 50
-        remittances.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_c.clone(),
-            amount: 50,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 2, &addr_b, &addr_c, 50, 1));
 
         // C -> A: 30
-        remittances.push_back(Remittance {
-            id: 3,
-            sender: addr_c.clone(),
-            agent: addr_a.clone(),
-            amount: 30,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 3, &addr_c, &addr_a, 30, 1));
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
 
         // Should have 3 net transfers (one for each pair)
         assert_eq!(net_transfers.len(), 3);
@@ -369,27 +335,11 @@ mod tests {
 
         let mut remittances = Vec::new(&env);
 
-        remittances.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
 
-        remittances.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_a.clone(),
-            amount: 90,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances.push_back(pending_remittance(&env, 2, &addr_b, &addr_a, 90, 1));
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
 
         assert!(validate_net_settlement(&remittances, &net_transfers).is_ok());
     }
@@ -402,52 +352,20 @@ mod tests {
 
         // First ordering
         let mut remittances1 = Vec::new(&env);
-        remittances1.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
-        remittances1.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_a.clone(),
-            amount: 90,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances1.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
+        remittances1.push_back(pending_remittance(&env, 2, &addr_b, &addr_a, 90, 1));
 
         // Second ordering (reversed)
         let mut remittances2 = Vec::new(&env);
-        remittances2.push_back(Remittance {
-            id: 2,
-            sender: addr_b.clone(),
-            agent: addr_a.clone(),
-            amount: 90,
-            fee: 1,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
-        remittances2.push_back(Remittance {
-            id: 1,
-            sender: addr_a.clone(),
-            agent: addr_b.clone(),
-            amount: 100,
-            fee: 2,
-            status: RemittanceStatus::Pending,
-            expiry: None,
-        });
+        remittances2.push_back(pending_remittance(&env, 2, &addr_b, &addr_a, 90, 1));
+        remittances2.push_back(pending_remittance(&env, 1, &addr_a, &addr_b, 100, 2));
 
-        let net1 = compute_net_settlements(&remittances1);
-        let net2 = compute_net_settlements(&remittances2);
+        let net1 = compute_net_settlements(&env, &remittances1);
+        let net2 = compute_net_settlements(&env, &remittances2);
 
         // Results should be identical regardless of input order
         assert_eq!(net1.len(), net2.len());
-        if net1.len() > 0 {
+        if !net1.is_empty() {
             let t1 = net1.get_unchecked(0);
             let t2 = net2.get_unchecked(0);
             assert_eq!(t1.net_amount, t2.net_amount);
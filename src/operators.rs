@@ -0,0 +1,68 @@
+//! Agent operator keys.
+//!
+//! An agent can grant operator addresses, such as its tellers, the right to
+//! confirm payouts on its behalf. A grant only covers settlement, caps the
+//! remittance amount an operator can settle, and can expire. Payouts still go
+//! to the agent address, and the agent can revoke a grant at any time.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+/// Settlement permission an agent granted to an operator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperatorGrant {
+    /// Largest remittance amount the operator can settle (in token units)
+    pub max_amount: i128,
+    /// Timestamp after which the grant no longer applies, if any
+    pub expiry: Option<u64>,
+    /// Timestamp at which the grant was made
+    pub granted_at: u64,
+}
+
+/// Storage keys for operator grants.
+#[contracttype]
+#[derive(Clone)]
+pub enum OperatorKey {
+    /// Grant indexed by agent and operator (persistent storage)
+    Grant(Address, Address),
+}
+
+/// Gets the grant an agent made to an operator, if any
+pub fn get_operator_grant(env: &Env, agent: &Address, operator: &Address) -> Option<OperatorGrant> {
+    env.storage()
+        .persistent()
+        .get(&OperatorKey::Grant(agent.clone(), operator.clone()))
+}
+
+/// Stores the grant an agent made to an operator
+pub fn set_operator_grant(env: &Env, agent: &Address, operator: &Address, grant: &OperatorGrant) {
+    env.storage()
+        .persistent()
+        .set(&OperatorKey::Grant(agent.clone(), operator.clone()), grant);
+}
+
+/// Removes the grant an agent made to an operator
+pub fn remove_operator_grant(env: &Env, agent: &Address, operator: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&OperatorKey::Grant(agent.clone(), operator.clone()));
+}
+
+/// Checks that an operator may settle a remittance of `amount` for an agent.
+pub fn check_operator_grant(
+    env: &Env,
+    agent: &Address,
+    operator: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let grant = get_operator_grant(env, agent, operator).ok_or(ContractError::OperatorNotFound)?;
+    if grant.expiry.is_some_and(|expiry| env.ledger().timestamp() > expiry) {
+        return Err(ContractError::OperatorExpired);
+    }
+    if amount > grant.max_amount {
+        return Err(ContractError::OperatorLimitExceeded);
+    }
+    Ok(())
+}
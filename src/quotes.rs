@@ -0,0 +1,150 @@
+//! Locked quotes with destination-currency amounts.
+//!
+//! Before committing funds, a sender can obtain a quote that fixes the fee
+//! breakdown, the FX rate and therefore the exact amount the recipient will be
+//! paid in local currency. A quote is issued either by the admin or by a
+//! registered oracle signing `compute_quote_hash`, and stays usable until its
+//! deadline. Each signed hash covers an oracle-chosen nonce and issues at most
+//! one quote. `create_remittance_from_quote` consumes a quote exactly once and
+//! records the destination amount and protocol fee on the remittance, binding
+//! the agent to pay that figure.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String};
+
+use crate::{is_oracle_registered, ContractError, ProofData};
+
+/// Fixed-point scale of FX rates (7 decimal places, matching Stellar assets).
+pub const FX_RATE_SCALE: i128 = 10_000_000;
+
+/// Authority vouching for a quote's FX rate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QuoteSigner {
+    /// Contract admin, authorizing the invocation directly
+    Admin(Address),
+    /// Registered oracle signature over `compute_quote_hash`
+    Oracle(ProofData),
+}
+
+/// Locked price for a sender's remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quote {
+    /// Unique identifier for the quote
+    pub id: u64,
+    /// Address the quote was issued to
+    pub sender: Address,
+    /// Source amount the sender will remit (in `token` units)
+    pub amount: i128,
+    /// Whitelisted token the source amount is denominated in
+    pub token: Address,
+    /// Platform fee from the configured fee strategy
+    pub platform_fee: i128,
+    /// Protocol fee sent to the treasury at payout
+    pub protocol_fee: i128,
+    /// Source amount left for the recipient after both fees
+    pub payout_amount: i128,
+    /// Destination units per source unit, scaled by `FX_RATE_SCALE`
+    pub fx_rate: i128,
+    /// Currency the recipient is paid in (e.g., "NGN")
+    pub dest_currency: String,
+    /// Destination country of the corridor (e.g., "NG")
+    pub dest_country: String,
+    /// Amount the recipient receives in `dest_currency`
+    pub dest_amount: i128,
+    /// Timestamp after which the quote can no longer be used
+    pub valid_until: u64,
+    /// Remittance created from the quote, once consumed
+    pub remittance_id: Option<u64>,
+}
+
+/// Storage keys for quotes.
+#[contracttype]
+#[derive(Clone)]
+pub enum QuoteKey {
+    /// Last issued quote ID (instance storage)
+    QuoteCounter,
+    /// Quote indexed by ID (persistent storage)
+    Quote(u64),
+    /// Oracle-signed quote hash that already issued a quote (persistent storage)
+    UsedQuoteHash(BytesN<32>),
+}
+
+/// Gets a quote by ID
+pub fn get_quote(env: &Env, quote_id: u64) -> Result<Quote, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&QuoteKey::Quote(quote_id))
+        .ok_or(ContractError::QuoteNotFound)
+}
+
+/// Stores a quote under its ID
+pub fn set_quote(env: &Env, quote: &Quote) {
+    env.storage()
+        .persistent()
+        .set(&QuoteKey::Quote(quote.id), quote);
+}
+
+/// Allocates the next quote ID
+pub fn next_quote_id(env: &Env) -> Result<u64, ContractError> {
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&QuoteKey::QuoteCounter)
+        .unwrap_or(0);
+    let quote_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&QuoteKey::QuoteCounter, &quote_id);
+    Ok(quote_id)
+}
+
+/// Returns whether an oracle-signed quote hash already issued a quote
+pub fn is_quote_hash_used(env: &Env, quote_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&QuoteKey::UsedQuoteHash(quote_hash.clone()))
+}
+
+/// Records that an oracle-signed quote hash issued a quote
+pub fn set_quote_hash_used(env: &Env, quote_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&QuoteKey::UsedQuoteHash(quote_hash.clone()), &true);
+}
+
+/// Converts a source payout into the destination currency at `fx_rate`.
+pub fn compute_destination_amount(payout_amount: i128, fx_rate: i128) -> Result<i128, ContractError> {
+    payout_amount
+        .checked_mul(fx_rate)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(FX_RATE_SCALE)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Verifies an oracle signature over a quote's canonical hash.
+///
+/// `quote_hash` is the output of `compute_quote_hash` for the quoted fields.
+///
+/// # Returns
+///
+/// * `Ok(())` - The signature was produced by a registered oracle over `quote_hash`
+/// * `Err(ContractError::OracleNotRegistered)` - The signing key is not in the oracle registry
+///
+/// # Panics
+///
+/// `ed25519_verify` traps on a bad signature, so an invalid signature aborts
+/// the invocation rather than returning an error.
+pub fn verify_quote_signature(
+    env: &Env,
+    proof: &ProofData,
+    quote_hash: &BytesN<32>,
+) -> Result<(), ContractError> {
+    if !is_oracle_registered(env, &proof.oracle) {
+        return Err(ContractError::OracleNotRegistered);
+    }
+
+    let message = Bytes::from_array(env, &quote_hash.to_array());
+    env.crypto()
+        .ed25519_verify(&proof.oracle, &message, &proof.signature);
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! Automatic agent selection for routed remittances.
+//!
+//! `create_routed_remittance` picks the agent from the registered agents whose
+//! profile serves the corridor, in profile registration order. Suspended and
+//! sanctioned agents, and agents whose bond or capacity cannot take the
+//! amount, are skipped. The admin chooses the selection policy. Every policy
+//! only reads contract state, so the same ledger state always routes to the
+//! same agent.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{
+    check_agent_bond, check_agent_capacity, get_agent_exposure, get_agent_stats, get_corridor_agents,
+    is_denylisted, AgentStats, ContractError,
+};
+
+/// How `create_routed_remittance` picks among eligible agents.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoutingPolicy {
+    /// Rotate through the corridor's agents, one assignment each
+    RoundRobin,
+    /// Agent with the smallest outstanding exposure
+    LowestExposure,
+    /// Agent with the highest performance score
+    BestScore,
+}
+
+/// Payout currency and destination country of a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Corridor {
+    /// Payout currency (e.g., "NGN")
+    pub currency: String,
+    /// Destination country (e.g., "NG")
+    pub country: String,
+}
+
+/// Result of `create_routed_remittance`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoutedRemittance {
+    /// ID of the created remittance
+    pub remittance_id: u64,
+    /// Agent the remittance was assigned to
+    pub agent: Address,
+}
+
+/// Storage keys for agent routing.
+#[contracttype]
+#[derive(Clone)]
+pub enum RoutingKey {
+    /// Selection policy (instance storage)
+    Policy,
+    /// Suspension flag indexed by agent (persistent storage)
+    Suspended(Address),
+    /// Next round-robin position for a corridor (persistent storage)
+    Cursor(String, String),
+}
+
+/// Gets the routing policy (defaults to round-robin)
+pub fn get_routing_policy(env: &Env) -> RoutingPolicy {
+    env.storage()
+        .instance()
+        .get(&RoutingKey::Policy)
+        .unwrap_or(RoutingPolicy::RoundRobin)
+}
+
+/// Sets the routing policy
+pub fn set_routing_policy(env: &Env, policy: RoutingPolicy) {
+    env.storage().instance().set(&RoutingKey::Policy, &policy);
+}
+
+/// Returns whether an agent is suspended from routing
+pub fn is_agent_suspended(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RoutingKey::Suspended(agent.clone()))
+}
+
+/// Suspends an agent from routing, or lifts the suspension
+pub fn set_agent_suspended(env: &Env, agent: &Address, suspended: bool) {
+    let key = RoutingKey::Suspended(agent.clone());
+    if suspended {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns an agent's performance score in basis points.
+///
+/// The score is the share of the agent's finished remittances it settled
+/// rather than had cancelled. Agents without history score zero.
+pub fn get_agent_score(stats: &AgentStats) -> u64 {
+    let finished = stats.settled_count.saturating_add(stats.cancelled_count);
+    if finished == 0 {
+        return 0;
+    }
+    stats.settled_count.saturating_mul(10000) / finished
+}
+
+fn is_eligible(env: &Env, agent: &Address, token: &Address, amount: i128) -> bool {
+    !is_agent_suspended(env, agent)
+        && !is_denylisted(env, agent)
+        && check_agent_bond(env, agent, token, amount).is_ok()
+        && check_agent_capacity(env, agent, token, amount).is_ok()
+}
+
+/// Picks the agent for a routed remittance according to the routing policy.
+///
+/// Ties are broken in favour of the agent that set up its profile first.
+/// Round-robin advances the corridor's cursor past the selected agent.
+pub fn select_agent(env: &Env, corridor: &Corridor, token: &Address, amount: i128) -> Result<Address, ContractError> {
+    let candidates = get_corridor_agents(env, &corridor.currency, &corridor.country);
+
+    match get_routing_policy(env) {
+        RoutingPolicy::RoundRobin => select_round_robin(env, corridor, &candidates, token, amount),
+        RoutingPolicy::LowestExposure => {
+            let mut selected: Option<(Address, i128)> = None;
+            for agent in candidates.iter() {
+                if !is_eligible(env, &agent, token, amount) {
+                    continue;
+                }
+                let exposure = get_agent_exposure(env, &agent, token);
+                let lower = match &selected {
+                    Some((_, lowest)) => exposure < *lowest,
+                    None => true,
+                };
+                if lower {
+                    selected = Some((agent, exposure));
+                }
+            }
+            selected.map(|(agent, _)| agent).ok_or(ContractError::NoEligibleAgent)
+        }
+        RoutingPolicy::BestScore => {
+            let mut selected: Option<(Address, u64)> = None;
+            for agent in candidates.iter() {
+                if !is_eligible(env, &agent, token, amount) {
+                    continue;
+                }
+                let score = get_agent_score(&get_agent_stats(env, &agent));
+                let better = match &selected {
+                    Some((_, best)) => score > *best,
+                    None => true,
+                };
+                if better {
+                    selected = Some((agent, score));
+                }
+            }
+            selected.map(|(agent, _)| agent).ok_or(ContractError::NoEligibleAgent)
+        }
+    }
+}
+
+fn select_round_robin(
+    env: &Env,
+    corridor: &Corridor,
+    candidates: &Vec<Address>,
+    token: &Address,
+    amount: i128,
+) -> Result<Address, ContractError> {
+    let count = candidates.len();
+    let key = RoutingKey::Cursor(corridor.currency.clone(), corridor.country.clone());
+    let cursor: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+
+    for offset in 0..count {
+        let position = (cursor % count + offset) % count;
+        let agent = candidates.get_unchecked(position);
+        if is_eligible(env, &agent, token, amount) {
+            env.storage().persistent().set(&key, &(position + 1));
+            return Ok(agent);
+        }
+    }
+    Err(ContractError::NoEligibleAgent)
+}
@@ -0,0 +1,74 @@
+//! Sanctions denylist screening.
+//!
+//! Addresses holding the `ComplianceOfficer` role list sanctioned addresses
+//! with a reason code. Listed addresses cannot create, settle, cancel or
+//! receive funds from remittances and escrows, nor receive withdrawn fees.
+//! Remittances that involve a listed sender or agent are frozen rather than
+//! refunded automatically: listing freezes the address's most recent
+//! settleable remittances, expiry paths move any others to `Frozen` instead,
+//! and anyone can freeze them explicitly with `freeze_sanctioned_remittances`.
+//! Only a compliance officer can release a frozen remittance, once none of
+//! its parties is listed any more.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::Remittance;
+
+/// Denylist record of a sanctioned address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenylistEntry {
+    /// Compliance reason code (e.g., the sanctions programme identifier)
+    pub reason_code: u32,
+    /// Timestamp at which the address was listed
+    pub listed_at: u64,
+    /// Compliance officer that listed the address
+    pub officer: Address,
+}
+
+/// Storage keys for the sanctions denylist.
+#[contracttype]
+#[derive(Clone)]
+pub enum SanctionsKey {
+    /// Denylist record indexed by address (persistent storage)
+    Denylisted(Address),
+}
+
+/// Gets the denylist record of an address, if listed
+pub fn get_denylist_entry(env: &Env, address: &Address) -> Option<DenylistEntry> {
+    env.storage()
+        .persistent()
+        .get(&SanctionsKey::Denylisted(address.clone()))
+}
+
+/// Lists an address on the denylist
+pub fn set_denylist_entry(env: &Env, address: &Address, entry: &DenylistEntry) {
+    env.storage()
+        .persistent()
+        .set(&SanctionsKey::Denylisted(address.clone()), entry);
+}
+
+/// Removes an address from the denylist
+pub fn remove_denylist_entry(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&SanctionsKey::Denylisted(address.clone()));
+}
+
+/// Returns whether an address is on the denylist
+pub fn is_denylisted(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&SanctionsKey::Denylisted(address.clone()))
+}
+
+/// Returns the first listed party of a remittance, sender before agent.
+pub fn get_sanctioned_party(env: &Env, remittance: &Remittance) -> Option<Address> {
+    if is_denylisted(env, &remittance.sender) {
+        Some(remittance.sender.clone())
+    } else if is_denylisted(env, &remittance.agent) {
+        Some(remittance.agent.clone())
+    } else {
+        None
+    }
+}
@@ -0,0 +1,192 @@
+//! Recurring remittances (standing orders).
+//!
+//! A standing order sends the same amount to the same agent every `interval`
+//! seconds. The sender authorizes it once, when creating the order, and funds
+//! each installment through a token allowance granted to the contract. Any
+//! keeper can then call `execute_due_orders` to turn due installments into
+//! ordinary remittances. Installments whose allowance or balance is short are
+//! skipped and retried on a later call. Installments that fall due while an
+//! order is paused are skipped when it resumes.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{ContractError, MAX_PAGE_SIZE};
+
+/// Lifecycle state of a standing order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StandingOrderStatus {
+    /// Installments are executed when due
+    Active,
+    /// Installments are held until the sender resumes the order
+    Paused,
+    /// Sender cancelled the order
+    Cancelled,
+    /// All installments were executed or the end date passed
+    Completed,
+}
+
+/// Recurring remittance from a sender to an agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrder {
+    /// Unique identifier for the order
+    pub id: u64,
+    /// Address funding each installment
+    pub sender: Address,
+    /// Agent assigned to each installment
+    pub agent: Address,
+    /// Amount of each installment
+    pub amount: i128,
+    /// Token each installment is denominated in
+    pub token: Address,
+    /// Payout currency of the corridor
+    pub currency: String,
+    /// Destination country of the corridor
+    pub country: String,
+    /// Seconds between installments
+    pub interval: u64,
+    /// Timestamp at which the next installment becomes due
+    pub next_due: u64,
+    /// Number of installments still to execute
+    pub remaining_count: u32,
+    /// Optional timestamp after which no further installments execute
+    pub end_date: Option<u64>,
+    /// Current order status
+    pub status: StandingOrderStatus,
+}
+
+/// One page of a standing order query.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrderPage {
+    /// Orders on this page
+    pub orders: Vec<StandingOrder>,
+    /// 1-based page number that was requested
+    pub page: u32,
+    /// Number of pages at the effective page size
+    pub total_pages: u32,
+    /// Number of orders matching the query
+    pub total_records: u32,
+}
+
+/// Storage keys for standing orders.
+#[contracttype]
+#[derive(Clone)]
+pub enum StandingOrderKey {
+    /// Last issued standing order ID (instance storage)
+    Counter,
+    /// Standing order indexed by ID (persistent storage)
+    Order(u64),
+    /// Number of orders created by a sender (persistent storage)
+    SenderLen(Address),
+    /// Order ID at a position in a sender's list (persistent storage)
+    SenderEntry(Address, u32),
+}
+
+/// Gets a standing order by ID
+pub fn get_standing_order(env: &Env, order_id: u64) -> Result<StandingOrder, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&StandingOrderKey::Order(order_id))
+        .ok_or(ContractError::StandingOrderNotFound)
+}
+
+/// Stores a standing order under its ID
+pub fn set_standing_order(env: &Env, order: &StandingOrder) {
+    env.storage()
+        .persistent()
+        .set(&StandingOrderKey::Order(order.id), order);
+}
+
+/// Allocates the next standing order ID
+pub fn next_standing_order_id(env: &Env) -> Result<u64, ContractError> {
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&StandingOrderKey::Counter)
+        .unwrap_or(0);
+    let order_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&StandingOrderKey::Counter, &order_id);
+    Ok(order_id)
+}
+
+/// Appends an order to its sender's list
+pub fn index_standing_order(env: &Env, sender: &Address, order_id: u64) {
+    let len = get_sender_order_count(env, sender);
+    env.storage()
+        .persistent()
+        .set(&StandingOrderKey::SenderEntry(sender.clone(), len), &order_id);
+    env.storage()
+        .persistent()
+        .set(&StandingOrderKey::SenderLen(sender.clone()), &(len + 1));
+}
+
+fn get_sender_order_count(env: &Env, sender: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StandingOrderKey::SenderLen(sender.clone()))
+        .unwrap_or(0)
+}
+
+/// Gets a page of standing orders created by a sender, oldest first.
+///
+/// `page` is 1-based and `limit` is clamped to `MAX_PAGE_SIZE`.
+pub fn get_standing_orders_by_sender(
+    env: &Env,
+    sender: &Address,
+    page: u32,
+    limit: u32,
+) -> Result<StandingOrderPage, ContractError> {
+    if page == 0 || limit == 0 {
+        return Err(ContractError::InvalidPagination);
+    }
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total_records = get_sender_order_count(env, sender);
+
+    let mut orders = Vec::new(env);
+    let start = (page - 1).saturating_mul(limit);
+    let end = start.saturating_add(limit).min(total_records);
+    for position in start..end {
+        let order_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&StandingOrderKey::SenderEntry(sender.clone(), position))
+            .ok_or(ContractError::KeyNotFound)?;
+        orders.push_back(get_standing_order(env, order_id)?);
+    }
+
+    Ok(StandingOrderPage {
+        orders,
+        page,
+        total_pages: total_records.div_ceil(limit),
+        total_records,
+    })
+}
+
+/// Returns whether an order's end date has passed.
+pub fn is_past_end_date(env: &Env, order: &StandingOrder) -> bool {
+    matches!(order.end_date, Some(end_date) if env.ledger().timestamp() > end_date)
+}
+
+/// Moves `next_due` past the current time, skipping every installment that fell due meanwhile.
+pub fn skip_missed_installments(env: &Env, order: &mut StandingOrder) -> Result<(), ContractError> {
+    let now = env.ledger().timestamp();
+    if now < order.next_due {
+        return Ok(());
+    }
+    let missed = (now - order.next_due) / order.interval + 1;
+    let skipped = missed.checked_mul(order.interval).ok_or(ContractError::Overflow)?;
+    order.next_due = order.next_due.checked_add(skipped).ok_or(ContractError::Overflow)?;
+    Ok(())
+}
+
+/// Returns whether an active order has an installment due now.
+pub fn is_order_due(env: &Env, order: &StandingOrder) -> bool {
+    order.status == StandingOrderStatus::Active
+        && order.remaining_count > 0
+        && env.ledger().timestamp() >= order.next_due
+        && !is_past_end_date(env, order)
+}
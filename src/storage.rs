@@ -7,13 +7,28 @@
 
 use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
-use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
+use crate::{
+    ContractError, DailyLimit, IdempotencyRecord, IntegratorCharge, Remittance, RemittancePage, RemittanceStatus,
+    TransferRecord,
+};
+
+/// Secondary indexes over remittance records, used by the paged history queries.
+#[contracttype]
+#[derive(Clone)]
+enum RemittanceIndex {
+    /// Remittances created by a sender, in creation order
+    Sender(Address),
+    /// Remittances assigned to an agent, in creation order
+    Agent(Address),
+    /// Remittances currently in a status (unordered)
+    Status(RemittanceStatus),
+}
 
 /// Storage keys for the SwiftRemit contract.
 ///
 /// Storage Layout:
 /// - Instance storage: Contract-level configuration and state (Admin, UsdcToken, PlatformFeeBps,
-///   RemittanceCounter, AccumulatedFees)
+///   RemittanceCounter, AccumulatedFees per token)
 /// - Persistent storage: Per-entity data that needs long-term retention (Remittance records,
 ///   AgentRegistered status)
 #[contracttype]
@@ -33,7 +48,7 @@ enum DataKey {
     /// Role assignment indexed by (address, role) (persistent storage)
     RoleAssignment(Address, crate::Role),
 
-    /// USDC token contract address used for all remittance transactions
+    /// Default token contract address set at initialization (USDC)
     UsdcToken,
 
     /// Platform fee in basis points (1 bps = 0.01%)
@@ -60,14 +75,21 @@ enum DataKey {
 
     // === Fee Tracking ===
     // Keys for managing platform fees
-    /// Total accumulated platform fees awaiting withdrawal
-    AccumulatedFees,
+    /// Accumulated platform fees awaiting withdrawal, indexed by token
+    AccumulatedFees(Address),
+
+    /// Integrator fee in basis points, indexed by integrator (persistent storage)
+    IntegratorFeeBps(Address),
 
-    /// Integrator fee in basis points
-    IntegratorFeeBps,
+    /// Address receiving an integrator's fee withdrawals (persistent storage)
+    IntegratorWithdrawalAddress(Address),
 
-    /// Total accumulated integrator fees awaiting withdrawal
-    AccumulatedIntegratorFees,
+    /// Accumulated integrator fees awaiting withdrawal, indexed by integrator and token
+    /// (persistent storage)
+    AccumulatedIntegratorFees(Address, Address),
+
+    /// Integrator fee charged on a remittance, indexed by remittance ID (persistent storage)
+    IntegratorCharge(u64),
 
     /// Contract pause status for emergency halts
     Paused,
@@ -94,13 +116,17 @@ enum DataKey {
     /// Daily limit configuration indexed by currency and country (persistent storage)
     DailyLimit(String, String),
     
-    /// User transfer records indexed by user address (persistent storage)
-    UserTransfers(Address),
+    /// User transfer records indexed by user address, corridor and token (persistent storage)
+    UserTransfers(Address, String, String, Address),
     
     // === Token Whitelist ===
     // Keys for managing whitelisted tokens
     /// Token whitelist status indexed by token address (persistent storage)
     TokenWhitelisted(Address),
+
+    /// Every token that ever had a whitelist entry, in first-seen order (persistent storage)
+    /// Removed tokens stay listed since they can still hold accumulated fees
+    KnownTokens,
     
     /// Settlement completion event emission tracking (persistent storage)
     /// Tracks whether the completion event has been emitted for a settlement
@@ -124,6 +150,33 @@ enum DataKey {
     
     /// Fee strategy configuration (instance storage)
     FeeStrategy,
+
+    // === Idempotency ===
+    // Keys for deduplicating retried remittance requests
+    /// Idempotency record indexed by (sender, client-provided key) (persistent storage)
+    IdempotencyRecord(Address, String),
+
+    /// Configurable TTL for idempotency records in seconds (instance storage)
+    IdempotencyTTL,
+
+    // === Remittance History Indexes ===
+    // Keys for paging through remittances without scanning every ID
+    /// Number of entries in a remittance index (persistent storage)
+    RemittanceIndexLen(RemittanceIndex),
+
+    /// Remittance ID at a position of a remittance index (persistent storage)
+    RemittanceIndexEntry(RemittanceIndex, u32),
+
+    /// Position of a remittance within its current status index (persistent storage)
+    RemittanceStatusPosition(u64),
+
+    // === Agent Acceptance ===
+    // Keys for the lock an agent holds while paying out
+    /// Seconds an agent acceptance stays valid (instance storage)
+    AcceptanceTimeout,
+
+    /// Timestamp at which an agent's acceptance of a remittance lapses (persistent storage)
+    AcceptanceDeadline(u64),
 }
 
 /// Checks if the contract has an admin configured.
@@ -307,33 +360,170 @@ pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
         .unwrap_or(false)
 }
 
-/// Sets the accumulated platform fees.
+/// Sets the accumulated platform fees for a token.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fees` - Total accumulated fees
-pub fn set_accumulated_fees(env: &Env, fees: i128) {
+/// * `token` - Token contract the fees are denominated in
+/// * `fees` - Total accumulated fees in that token
+pub fn set_accumulated_fees(env: &Env, token: &Address, fees: i128) {
     env.storage()
         .instance()
-        .set(&DataKey::AccumulatedFees, &fees);
+        .set(&DataKey::AccumulatedFees(token.clone()), &fees);
 }
 
-/// Retrieves the accumulated platform fees.
+/// Retrieves the accumulated platform fees for a token.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `token` - Token contract the fees are denominated in
 ///
 /// # Returns
 ///
-/// * `Ok(i128)` - Total accumulated fees
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+/// Total accumulated fees in that token (zero if none were collected)
+pub fn get_accumulated_fees(env: &Env, token: &Address) -> i128 {
     env.storage()
         .instance()
-        .get(&DataKey::AccumulatedFees)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::AccumulatedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets an integrator's fee rate, registering the integrator.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `fee_bps` - Fee charged on top of remittances in basis points
+pub fn set_integrator_fee_bps(env: &Env, integrator: &Address, fee_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::IntegratorFeeBps(integrator.clone()), &fee_bps);
+}
+
+/// Retrieves an integrator's fee rate.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+///
+/// # Returns
+///
+/// * `Some(fee_bps)` - Integrator is registered
+/// * `None` - Integrator is not registered
+pub fn get_integrator_fee_bps(env: &Env, integrator: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IntegratorFeeBps(integrator.clone()))
+}
+
+/// Removes an integrator's fee rate, deregistering the integrator.
+///
+/// Accumulated fees and the withdrawal address are kept so the integrator
+/// can still withdraw what it earned.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+pub fn remove_integrator_fee_bps(env: &Env, integrator: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::IntegratorFeeBps(integrator.clone()));
+}
+
+/// Sets the address that receives an integrator's fee withdrawals.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `withdrawal_address` - Recipient of withdrawn fees
+pub fn set_integrator_withdrawal_address(env: &Env, integrator: &Address, withdrawal_address: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::IntegratorWithdrawalAddress(integrator.clone()), withdrawal_address);
+}
+
+/// Retrieves the address that receives an integrator's fee withdrawals.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+///
+/// # Returns
+///
+/// The withdrawal address, if the integrator was ever registered
+pub fn get_integrator_withdrawal_address(env: &Env, integrator: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IntegratorWithdrawalAddress(integrator.clone()))
+}
+
+/// Sets the accumulated fees of an integrator in a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `token` - Token contract the fees are denominated in
+/// * `fees` - Total accumulated fees in that token
+pub fn set_accumulated_integrator_fees(env: &Env, integrator: &Address, token: &Address, fees: i128) {
+    env.storage().persistent().set(
+        &DataKey::AccumulatedIntegratorFees(integrator.clone(), token.clone()),
+        &fees,
+    );
+}
+
+/// Retrieves the accumulated fees of an integrator in a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `integrator` - Integrator address
+/// * `token` - Token contract the fees are denominated in
+///
+/// # Returns
+///
+/// Total accumulated fees in that token (zero if none were earned)
+pub fn get_accumulated_integrator_fees(env: &Env, integrator: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccumulatedIntegratorFees(integrator.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Records the integrator fee charged on a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance the fee was charged on
+/// * `charge` - Integrator and fee amount
+pub fn set_integrator_charge(env: &Env, remittance_id: u64, charge: &IntegratorCharge) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::IntegratorCharge(remittance_id), charge);
+}
+
+/// Retrieves the integrator fee charged on a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+///
+/// # Returns
+///
+/// The charge, if the remittance was created through an integrator
+pub fn get_integrator_charge(env: &Env, remittance_id: u64) -> Option<IntegratorCharge> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IntegratorCharge(remittance_id))
 }
 
 /// Checks if a settlement hash exists for duplicate detection.
@@ -475,17 +665,120 @@ pub fn get_daily_limit(env: &Env, currency: &String, country: &String) -> Option
         .get(&DataKey::DailyLimit(currency.clone(), country.clone()))
 }
 
-pub fn get_user_transfers(env: &Env, user: &Address) -> Vec<TransferRecord> {
+pub fn remove_daily_limit(env: &Env, currency: &String, country: &String) {
     env.storage()
         .persistent()
-        .get(&DataKey::UserTransfers(user.clone()))
-        .unwrap_or(Vec::new(env))
+        .remove(&DataKey::DailyLimit(currency.clone(), country.clone()));
 }
 
-pub fn set_user_transfers(env: &Env, user: &Address, transfers: &Vec<TransferRecord>) {
+pub fn get_user_transfers(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+    token: &Address,
+) -> Vec<TransferRecord> {
     env.storage()
         .persistent()
-        .set(&DataKey::UserTransfers(user.clone()), transfers);
+        .get(&DataKey::UserTransfers(user.clone(), currency.clone(), country.clone(), token.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_user_transfers(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+    token: &Address,
+    transfers: &Vec<TransferRecord>,
+) {
+    env.storage().persistent().set(
+        &DataKey::UserTransfers(user.clone(), currency.clone(), country.clone(), token.clone()),
+        transfers,
+    );
+}
+
+/// Length of the rolling window used for daily send limits (24 hours)
+pub const DAILY_LIMIT_WINDOW: u64 = 86400;
+
+/// Returns the transfers a user made of a token in a corridor within the rolling window.
+fn get_recent_user_transfers(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+    token: &Address,
+) -> Vec<TransferRecord> {
+    let current_time = env.ledger().timestamp();
+    let mut recent = Vec::new(env);
+    for record in get_user_transfers(env, user, currency, country, token).iter() {
+        if current_time.saturating_sub(record.timestamp) < DAILY_LIMIT_WINDOW {
+            recent.push_back(record);
+        }
+    }
+    recent
+}
+
+/// Returns how much a user can still send of a token in a corridor within the rolling window.
+///
+/// * `None` - No daily limit is configured for the corridor
+/// * `Some(amount)` - Remaining allowance (never negative)
+pub fn get_remaining_daily_allowance(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+    token: &Address,
+) -> Result<Option<i128>, ContractError> {
+    let daily_limit = match get_daily_limit(env, currency, country) {
+        Some(daily_limit) => daily_limit,
+        None => return Ok(None),
+    };
+
+    let mut used: i128 = 0;
+    for record in get_recent_user_transfers(env, user, currency, country, token).iter() {
+        used = used.checked_add(record.amount).ok_or(ContractError::Overflow)?;
+    }
+
+    Ok(Some(daily_limit.limit.saturating_sub(used).max(0)))
+}
+
+/// Checks a transfer against the corridor's daily limit and records it.
+///
+/// The limit is in token units, so usage is tracked separately per token.
+///
+/// Records older than the rolling window are pruned on every write so the
+/// stored list stays bounded by the number of transfers in one window.
+pub fn check_and_record_daily_limit(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let daily_limit = match get_daily_limit(env, currency, country) {
+        Some(daily_limit) => daily_limit,
+        None => return Ok(()),
+    };
+
+    let mut transfers = get_recent_user_transfers(env, user, currency, country, token);
+    let mut total = amount;
+    for record in transfers.iter() {
+        total = total.checked_add(record.amount).ok_or(ContractError::Overflow)?;
+    }
+
+    if total > daily_limit.limit {
+        return Err(ContractError::DailySendLimitExceeded);
+    }
+
+    transfers.push_back(TransferRecord {
+        timestamp: env.ledger().timestamp(),
+        amount,
+    });
+    set_user_transfers(env, user, currency, country, token, &transfers);
+
+    Ok(())
 }
 
 // === Admin Role Management ===
@@ -537,6 +830,20 @@ pub fn set_token_whitelisted(env: &Env, token: &Address, whitelisted: bool) {
     env.storage()
         .persistent()
         .set(&DataKey::TokenWhitelisted(token.clone()), &whitelisted);
+
+    let mut known = get_known_tokens(env);
+    if !known.contains(token) {
+        known.push_back(token.clone());
+        env.storage().persistent().set(&DataKey::KnownTokens, &known);
+    }
+}
+
+/// Gets every token that ever had a whitelist entry, including tokens since removed.
+pub fn get_known_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KnownTokens)
+        .unwrap_or(Vec::new(env))
 }
 
 // === Settlement Event Emission Tracking ===
@@ -720,6 +1027,30 @@ pub fn require_role_settler(env: &Env, address: &Address) -> Result<(), Contract
     Ok(())
 }
 
+/// Requires that the caller has Arbitrator role
+pub fn require_role_arbitrator(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::Arbitrator) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Requires that the caller has RateFeeder role
+pub fn require_role_rate_feeder(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::RateFeeder) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Requires that the caller has ComplianceOfficer role
+pub fn require_role_compliance_officer(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::ComplianceOfficer) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
 
 // === Transfer State Registry ===
 
@@ -813,3 +1144,303 @@ pub fn set_treasury(env: &Env, treasury: &Address) {
         .instance()
         .set(&DataKey::Treasury, treasury);
 }
+
+
+// === Idempotency ===
+
+/// Default lifetime of an idempotency record (24 hours)
+pub const DEFAULT_IDEMPOTENCY_TTL: u64 = 86400;
+
+/// Maximum accepted length of an idempotency key in bytes
+pub const MAX_IDEMPOTENCY_KEY_LEN: u32 = 255;
+
+/// Gets the idempotency record a sender stored under a key, if any.
+///
+/// Keys are scoped per sender, so two senders may use the same key.
+/// Expired records are still returned; callers compare `expires_at` against
+/// the ledger timestamp and overwrite stale records lazily.
+pub fn get_idempotency_record(env: &Env, sender: &Address, key: &String) -> Option<IdempotencyRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IdempotencyRecord(sender.clone(), key.clone()))
+}
+
+/// Stores an idempotency record under its sender and key
+pub fn set_idempotency_record(env: &Env, record: &IdempotencyRecord) {
+    env.storage().persistent().set(
+        &DataKey::IdempotencyRecord(record.sender.clone(), record.key.clone()),
+        record,
+    );
+}
+
+/// Gets the idempotency record TTL in seconds (defaults to 24 hours)
+pub fn get_idempotency_ttl(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::IdempotencyTTL)
+        .unwrap_or(DEFAULT_IDEMPOTENCY_TTL)
+}
+
+/// Sets the idempotency record TTL in seconds
+pub fn set_idempotency_ttl(env: &Env, ttl_seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::IdempotencyTTL, &ttl_seconds);
+}
+
+
+// === Agent Acceptance ===
+
+/// Default lifetime of an agent acceptance (1 hour)
+pub const DEFAULT_ACCEPTANCE_TIMEOUT: u64 = 3600;
+
+/// Gets the agent acceptance timeout in seconds (defaults to 1 hour)
+pub fn get_acceptance_timeout(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AcceptanceTimeout)
+        .unwrap_or(DEFAULT_ACCEPTANCE_TIMEOUT)
+}
+
+/// Sets the agent acceptance timeout in seconds
+pub fn set_acceptance_timeout(env: &Env, timeout_seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AcceptanceTimeout, &timeout_seconds);
+}
+
+/// Gets the timestamp at which the acceptance of a remittance lapses, if accepted
+pub fn get_acceptance_deadline(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AcceptanceDeadline(remittance_id))
+}
+
+/// Sets the timestamp at which the acceptance of a remittance lapses
+pub fn set_acceptance_deadline(env: &Env, remittance_id: u64, deadline: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AcceptanceDeadline(remittance_id), &deadline);
+}
+
+/// Removes the acceptance deadline of a remittance
+pub fn remove_acceptance_deadline(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AcceptanceDeadline(remittance_id));
+}
+
+
+// === Remittance History Indexes ===
+
+/// Maximum number of remittances returned by a single history page
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+fn get_index_len(env: &Env, index: &RemittanceIndex) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceIndexLen(index.clone()))
+        .unwrap_or(0)
+}
+
+fn set_index_len(env: &Env, index: &RemittanceIndex, len: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceIndexLen(index.clone()), &len);
+}
+
+fn get_index_entry(env: &Env, index: &RemittanceIndex, position: u32) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceIndexEntry(index.clone(), position))
+        .unwrap_or(0)
+}
+
+fn set_index_entry(env: &Env, index: &RemittanceIndex, position: u32, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceIndexEntry(index.clone(), position), &remittance_id);
+}
+
+/// Appends a remittance ID to an index and returns its position.
+fn push_index_entry(env: &Env, index: &RemittanceIndex, remittance_id: u64) -> u32 {
+    let position = get_index_len(env, index);
+    set_index_entry(env, index, position, remittance_id);
+    set_index_len(env, index, position + 1);
+    position
+}
+
+fn add_to_status_index(env: &Env, remittance_id: u64, status: &RemittanceStatus) {
+    let position = push_index_entry(env, &RemittanceIndex::Status(status.clone()), remittance_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceStatusPosition(remittance_id), &position);
+}
+
+/// Removes a remittance from a status index by moving the last entry into its slot.
+fn remove_from_status_index(env: &Env, remittance_id: u64, status: &RemittanceStatus) {
+    let index = RemittanceIndex::Status(status.clone());
+    let position_key = DataKey::RemittanceStatusPosition(remittance_id);
+    let position: u32 = match env.storage().persistent().get(&position_key) {
+        Some(position) => position,
+        None => return,
+    };
+
+    let last = get_index_len(env, &index) - 1;
+    if position != last {
+        let moved_id = get_index_entry(env, &index, last);
+        set_index_entry(env, &index, position, moved_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RemittanceStatusPosition(moved_id), &position);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RemittanceIndexEntry(index.clone(), last));
+    env.storage().persistent().remove(&position_key);
+    set_index_len(env, &index, last);
+}
+
+/// Gets the number of remittances currently in a status.
+pub fn get_status_index_len(env: &Env, status: &RemittanceStatus) -> u32 {
+    get_index_len(env, &RemittanceIndex::Status(status.clone()))
+}
+
+/// Gets the remittance ID at a position of a status index.
+pub fn get_status_index_entry(env: &Env, status: &RemittanceStatus, position: u32) -> u64 {
+    get_index_entry(env, &RemittanceIndex::Status(status.clone()), position)
+}
+
+/// Adds a newly created remittance to the sender, agent and status indexes.
+///
+/// Open-claim remittances are held by the contract until claimed, so they only
+/// join an agent index once `index_remittance_agent` records the claiming agent.
+pub fn index_remittance(env: &Env, remittance: &Remittance) {
+    push_index_entry(env, &RemittanceIndex::Sender(remittance.sender.clone()), remittance.id);
+    if remittance.agent != env.current_contract_address() {
+        push_index_entry(env, &RemittanceIndex::Agent(remittance.agent.clone()), remittance.id);
+    }
+    add_to_status_index(env, remittance.id, &remittance.status);
+}
+
+/// Adds a remittance to an agent's index once the agent is assigned after creation.
+pub fn index_remittance_agent(env: &Env, agent: &Address, remittance_id: u64) {
+    push_index_entry(env, &RemittanceIndex::Agent(agent.clone()), remittance_id);
+}
+
+/// Gets up to `limit` remittance IDs created by or assigned to an address,
+/// newest first, reading the sender index before the agent index.
+pub fn get_recent_party_remittance_ids(env: &Env, address: &Address, limit: u32) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for index in [
+        RemittanceIndex::Sender(address.clone()),
+        RemittanceIndex::Agent(address.clone()),
+    ] {
+        let mut position = get_index_len(env, &index);
+        while position > 0 && ids.len() < limit {
+            position -= 1;
+            ids.push_back(get_index_entry(env, &index, position));
+        }
+    }
+    ids
+}
+
+/// Moves a remittance between status indexes after its status changed.
+pub fn reindex_remittance_status(
+    env: &Env,
+    remittance_id: u64,
+    from: &RemittanceStatus,
+    to: &RemittanceStatus,
+) {
+    if from == to {
+        return;
+    }
+    remove_from_status_index(env, remittance_id, from);
+    add_to_status_index(env, remittance_id, to);
+}
+
+/// Reads one page of an index.
+///
+/// `page` is 1-based and `limit` is clamped to `MAX_PAGE_SIZE`. Pages past the
+/// end are empty.
+///
+/// # Returns
+///
+/// * `Ok((ids, page_size, total_records))` - IDs on the page, effective page size and index length
+/// * `Err(ContractError::InvalidPagination)` - `page` or `limit` is zero
+fn get_index_page(
+    env: &Env,
+    index: &RemittanceIndex,
+    page: u32,
+    limit: u32,
+) -> Result<(Vec<u64>, u32, u32), ContractError> {
+    if page == 0 || limit == 0 {
+        return Err(ContractError::InvalidPagination);
+    }
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total = get_index_len(env, index);
+
+    let mut ids = Vec::new(env);
+    let start = (page - 1).saturating_mul(limit);
+    let end = start.saturating_add(limit).min(total);
+    for position in start..end {
+        ids.push_back(get_index_entry(env, index, position));
+    }
+
+    Ok((ids, limit, total))
+}
+
+/// Reads one page of remittances from an index, with paging metadata.
+fn get_remittance_page(
+    env: &Env,
+    index: &RemittanceIndex,
+    page: u32,
+    limit: u32,
+) -> Result<RemittancePage, ContractError> {
+    let (ids, page_size, total_records) = get_index_page(env, index, page, limit)?;
+
+    let mut remittances = Vec::new(env);
+    for id in ids.iter() {
+        remittances.push_back(get_remittance(env, id)?);
+    }
+
+    Ok(RemittancePage {
+        remittances,
+        page,
+        total_pages: total_records.div_ceil(page_size),
+        total_records,
+    })
+}
+
+/// Gets a page of remittances created by a sender, oldest first.
+pub fn get_remittances_by_sender(
+    env: &Env,
+    sender: &Address,
+    page: u32,
+    limit: u32,
+) -> Result<RemittancePage, ContractError> {
+    get_remittance_page(env, &RemittanceIndex::Sender(sender.clone()), page, limit)
+}
+
+/// Gets a page of remittances assigned to an agent, oldest first.
+pub fn get_remittances_by_agent(
+    env: &Env,
+    agent: &Address,
+    page: u32,
+    limit: u32,
+) -> Result<RemittancePage, ContractError> {
+    get_remittance_page(env, &RemittanceIndex::Agent(agent.clone()), page, limit)
+}
+
+/// Gets a page of remittances currently in a status.
+///
+/// Entries are moved out of a status index by swapping in the last entry, so
+/// the order is not stable across status changes.
+pub fn get_remittances_by_status(
+    env: &Env,
+    status: &RemittanceStatus,
+    page: u32,
+    limit: u32,
+) -> Result<RemittancePage, ContractError> {
+    get_remittance_page(env, &RemittanceIndex::Status(status.clone()), page, limit)
+}
@@ -37,13 +37,10 @@ extern crate alloc;
 //
 // ============================================================================
 
-use crate::{SwiftRemitContract, SwiftRemitContractClient};
-use soroban_sdk::token::Client as TokenClient;
-use soroban_sdk::token::StellarAssetClient;
-use soroban_sdk::testutils::Ledger;
+use crate::{FeeStrategy, Role, SwiftRemitContract, SwiftRemitContractClient};
 use soroban_sdk::{
     symbol_short, testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger},
-    token, Address, Env, IntoVal,
+    token, Address, Env, FromVal, IntoVal, String, Symbol,
 };
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
@@ -55,8 +52,12 @@ fn get_token_balance(token: &token::StellarAssetClient, address: &Address) -> i1
     token::Client::new(&token.env, &token.address).balance(address)
 }
 
-fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
-    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+fn create_swiftremit_contract<'a>(env: &Env, token: &Address) -> SwiftRemitContractClient<'a> {
+    let contract_id = env.register_contract(None, SwiftRemitContract {});
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(env, token, true);
+    });
+    SwiftRemitContractClient::new(env, &contract_id)
 }
 
 fn default_currency(env: &Env) -> String {
@@ -76,7 +77,7 @@ fn test_initialize() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
 
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
@@ -93,7 +94,7 @@ fn test_initialize_twice() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
 
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
@@ -109,7 +110,7 @@ fn test_initialize_invalid_fee() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
 
     contract.initialize(&admin, &token.address, &10001, &0, &0, &admin);
 }
@@ -124,10 +125,10 @@ fn test_register_agent() {
     let token = create_token_contract(&env, &token_admin);
     let agent = Address::generate(&env);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
-        contract.register_agent(&agent);
+    contract.register_agent(&agent);
 
     assert_eq!(
         env.auths(),
@@ -136,7 +137,7 @@ fn test_register_agent() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     contract.address.clone(),
-                    symbol_short!("reg_agent"),
+                    Symbol::new(&env, "register_agent"),
                     (&agent,).into_val(&env)
                 )),
                 sub_invocations: alloc::vec![]
@@ -157,10 +158,12 @@ fn test_remove_agent() {
     let token = create_token_contract(&env, &token_admin);
     let agent = Address::generate(&env);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     contract.register_agent(&agent);
+
+    contract.assign_role(&admin, &agent, &Role::Settler);
     assert!(contract.is_agent_registered(&agent));
 
     contract.remove_agent(&agent);
@@ -176,7 +179,7 @@ fn test_update_fee() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     contract.update_fee(&500);
@@ -193,7 +196,7 @@ fn test_update_fee_invalid() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     contract.update_fee(&10001);
@@ -213,11 +216,12 @@ fn test_create_remittance() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     assert_eq!(remittance_id, 1);
 
@@ -243,11 +247,12 @@ fn test_create_remittance_invalid_amount() {
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    contract.create_remittance(&sender, &agent, &0, &None);
+    contract.create_remittance(&sender, &agent, &0, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 }
 
 #[test]
@@ -265,10 +270,10 @@ fn test_create_remittance_unregistered_agent() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
-    contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 }
 
 #[test]
@@ -285,11 +290,12 @@ fn test_confirm_payout() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     contract.confirm_payout(&remittance_id);
 
@@ -297,12 +303,12 @@ fn test_confirm_payout() {
     assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     assert_eq!(get_token_balance(&token, &agent), 975);
-    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
     assert_eq!(get_token_balance(&token, &contract.address), 25);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_confirm_payout_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -316,11 +322,12 @@ fn test_confirm_payout_twice() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     contract.confirm_payout(&remittance_id);
     contract.confirm_payout(&remittance_id);
@@ -340,11 +347,12 @@ fn test_cancel_remittance() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     contract.cancel_remittance(&remittance_id);
 
@@ -356,7 +364,7 @@ fn test_cancel_remittance() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_cancel_remittance_already_completed() {
     let env = Env::default();
     env.mock_all_auths();
@@ -370,11 +378,12 @@ fn test_cancel_remittance_already_completed() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
     contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     contract.confirm_payout(&remittance_id);
 
     contract.cancel_remittance(&remittance_id);
@@ -399,13 +408,14 @@ fn test_cancel_remittance_full_refund() {
     let initial_balance = 10000i128;
     token.mint(&sender, &initial_balance);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250); // 2.5% fee
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin); // 2.5% fee
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create remittance with 1000 tokens
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     let token_client = token::Client::new(&env, &token.address);
     // Verify sender balance decreased by full amount
@@ -441,11 +451,12 @@ fn test_cancel_remittance_sender_authorization() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Cancel and verify sender authorization was required
     contract.cancel_remittance(&remittance_id);
@@ -460,7 +471,7 @@ fn test_cancel_remittance_sender_authorization() {
                     Symbol::new(&env, "cancel_remittance"),
                     (remittance_id,).into_val(&env)
                 )),
-                sub_invocations: std::vec::Vec::new()
+                sub_invocations: alloc::vec![]
             }
         )]
     );
@@ -480,12 +491,13 @@ fn test_cancel_remittance_event_emission() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Cancel the remittance
     contract.cancel_remittance(&remittance_id);
@@ -521,15 +533,15 @@ fn test_cancel_remittance_not_found() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     // Try to cancel non-existent remittance
-    contract.cancel_remittance(&999, &0, &0, &admin);
+    contract.cancel_remittance(&999);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_cancel_remittance_already_cancelled() {
     let env = Env::default();
     env.mock_all_auths();
@@ -543,11 +555,12 @@ fn test_cancel_remittance_already_cancelled() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Cancel once
     contract.cancel_remittance(&remittance_id);
@@ -569,14 +582,15 @@ fn test_cancel_remittance_multiple_remittances() {
 
     token.mint(&sender, &20000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create multiple remittances
-    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &None);
-    let remittance_id2 = contract.create_remittance(&sender, &agent, &2000, &None);
-    let remittance_id3 = contract.create_remittance(&sender, &agent, &3000, &None);
+    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender, &agent, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id3 = contract.create_remittance(&sender, &agent, &3000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     let token_client = token::Client::new(&env, &token.address);
     // Sender should have 14000 left (20000 - 1000 - 2000 - 3000)
@@ -615,16 +629,17 @@ fn test_cancel_remittance_no_fee_accumulation() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create and cancel remittance
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     contract.cancel_remittance(&remittance_id);
 
     // Verify no fees were accumulated (fees only accumulate on successful payout)
-    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 0);
 }
 
 #[test]
@@ -641,12 +656,13 @@ fn test_cancel_remittance_preserves_remittance_data() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent, &0, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Get original remittance data
     let original = contract.get_remittance(&remittance_id);
@@ -683,18 +699,18 @@ fn test_withdraw_fees() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-    contract.authorize_remittance(&admin, &remittance_id);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     contract.confirm_payout(&remittance_id);
 
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&fee_recipient, &token.address);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
-    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 0);
     assert_eq!(get_token_balance(&token, &contract.address), 0);
 }
 
@@ -709,10 +725,10 @@ fn test_withdraw_fees_no_fees() {
     let token = create_token_contract(&env, &token_admin);
     let fee_recipient = Address::generate(&env);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&fee_recipient, &token.address);
 }
 
 #[test]
@@ -721,26 +737,27 @@ fn test_fee_calculation() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let token_admin = Address::generate(&env, &0, &admin);
+    let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
 
     token.mint(&sender, &100000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &500, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &500, &0, &0, &admin);
+    contract.update_fee_strategy(&admin, &FeeStrategy::Percentage(500));
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.fee, 500);
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
     assert_eq!(get_token_balance(&token, &agent), 9500);
-    assert_eq!(contract.get_accumulated_fees(), 500);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 500);
 }
 
 #[test]
@@ -758,23 +775,22 @@ fn test_multiple_remittances() {
     token.mint(&sender1, &10000);
     token.mint(&sender2, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id1 = contract.create_remittance(&sender1, &agent, &1000, &None);
-    let remittance_id2 = contract.create_remittance(&sender2, &agent, &2000, &None);
+    let remittance_id1 = contract.create_remittance(&sender1, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender2, &agent, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     assert_eq!(remittance_id1, 1);
     assert_eq!(remittance_id2, 2);
 
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
 
-    assert_eq!(contract.get_accumulated_fees(), 75);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 75);
     assert_eq!(get_token_balance(&token, &agent), 2925);
 }
 
@@ -792,18 +808,19 @@ fn test_events_emitted() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let initial_events = env.events().all().len();
 
     contract.register_agent(&agent);
-    assert!(env.events().all().len(, &0, &admin) > initial_events, "Agent registration should emit event");
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    assert!(env.events().all().len() > initial_events, "Agent registration should emit event");
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert!(env.events().all().len() > initial_events + 1, "Remittance creation should emit event");
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
     assert!(env.events().all().len() > initial_events + 2, "Payout confirmation should emit event");
 }
@@ -821,17 +838,17 @@ fn test_authorization_enforcement() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
+    let contract = create_swiftremit_contract(&env, &token.address);
 
     env.mock_all_auths();
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    env.mock_all_auths(, &0, &admin);
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    env.mock_all_auths();
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     env.mock_all_auths();
-    contract.authorize_remittance(&admin, &remittance_id);
 
     env.mock_all_auths();
     contract.confirm_payout(&remittance_id);
@@ -843,7 +860,7 @@ fn test_authorization_enforcement() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     contract.address.clone(),
-                    symbol_short!("conf_pay"),
+                    Symbol::new(&env, "confirm_payout"),
                     (remittance_id,).into_val(&env)
                 )),
                 sub_invocations: alloc::vec![]
@@ -867,19 +884,19 @@ fn test_withdraw_fees_valid_address() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-    contract.authorize_remittance(&admin, &remittance_id);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     contract.confirm_payout(&remittance_id);
 
     // This should succeed with a valid address
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&fee_recipient, &token.address);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
-    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 0);
 }
 
 #[test]
@@ -896,14 +913,14 @@ fn test_confirm_payout_valid_address() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // This should succeed with a valid agent address
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -925,22 +942,22 @@ fn test_address_validation_in_settlement_flow() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create remittance with valid addresses
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Confirm payout - should validate agent address
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Verify the settlement completed successfully
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
     assert_eq!(get_token_balance(&token, &agent), 975);
-    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
 }
 
 #[test]
@@ -959,25 +976,25 @@ fn test_multiple_settlements_with_address_validation() {
     token.mint(&sender1, &10000);
     token.mint(&sender2, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent1);
-    contract.register_agent(&agent2, &0, &admin);
+    contract.assign_role(&admin, &agent1, &Role::Settler);
+    contract.register_agent(&agent2);
+    contract.assign_role(&admin, &agent2, &Role::Settler);
 
     // Create and confirm multiple remittances
-    let remittance_id1 = contract.create_remittance(&sender1, &agent1, &1000, &None);
-    let remittance_id2 = contract.create_remittance(&sender2, &agent2, &2000, &None);
+    let remittance_id1 = contract.create_remittance(&sender1, &agent1, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender2, &agent2, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Both should succeed with valid addresses
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
 
     assert_eq!(get_token_balance(&token, &agent1), 975);
     assert_eq!(get_token_balance(&token, &agent2), 1950);
-    assert_eq!(contract.get_accumulated_fees(), 75);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 75);
 }
 
 #[test]
@@ -994,19 +1011,19 @@ fn test_settlement_with_future_expiry() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Set expiry to 1 hour in the future
-    env.ledger(, &0, &admin).set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time + 3600;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None);
 
     // Should succeed since expiry is in the future
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -1029,19 +1046,19 @@ fn test_settlement_with_past_expiry() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Set expiry to 1 hour in the past
-    env.ledger(, &0, &admin).set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time.saturating_sub(3600);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None);
 
     // Should fail with SettlementExpired error
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 }
 
@@ -1059,15 +1076,15 @@ fn test_settlement_without_expiry() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create remittance without expiry
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Should succeed since there's no expiry
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -1090,21 +1107,21 @@ fn test_duplicate_settlement_prevention() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // First settlement should succeed
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Verify first settlement completed
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
     assert_eq!(get_token_balance(&token, &agent), 975);
-    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
 
     // Manually reset status to Pending to bypass status check
     // This simulates an attempt to re-execute the same settlement
@@ -1117,7 +1134,6 @@ fn test_duplicate_settlement_prevention() {
     });
 
     // Second settlement attempt should fail with DuplicateSettlement error
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 }
 
@@ -1134,17 +1150,16 @@ fn test_different_settlements_allowed() {
 
     token.mint(&sender, &20000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create two different remittances
-    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &None);
-    let remittance_id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Both settlements should succeed as they are different remittances
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
@@ -1156,7 +1171,7 @@ fn test_different_settlements_allowed() {
     assert_eq!(remittance1.status, crate::types::RemittanceStatus::Completed);
     assert_eq!(remittance2.status, crate::types::RemittanceStatus::Completed);
     assert_eq!(get_token_balance(&token, &agent), 1950);
-    assert_eq!(contract.get_accumulated_fees(), 50);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 50);
 }
 
 #[test]
@@ -1172,19 +1187,19 @@ fn test_settlement_hash_storage_efficiency() {
 
     token.mint(&sender, &50000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     // Create and settle multiple remittances
     for _ in 0..5 {
-        let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-        contract.authorize_remittance(&admin, &remittance_id);
+        let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
         contract.confirm_payout(&remittance_id);
     }
 
     // Verify all settlements completed
-    assert_eq!(contract.get_accumulated_fees(), 125);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 125);
     assert_eq!(get_token_balance(&token, &agent), 4875);
     
     // Storage should only contain settlement hashes (boolean flags), not full remittance data duplicates
@@ -1205,26 +1220,36 @@ fn test_settlement_completed_event() {
 
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
-    env.ledger(, &0, &admin).set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time + 3600;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry_time));
-
-    contract.authorize_remittance(&admin, &remittance_id);
-
-    // First settlement should succeed
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None);
     contract.confirm_payout(&remittance_id);
 
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
+    let settlement_event = env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1.len() == 2
+                && Symbol::from_val(&env, &event.1.get(0).unwrap()) == symbol_short!("settle")
+                && Symbol::from_val(&env, &event.1.get(1).unwrap()) == symbol_short!("complete")
+        })
+        .expect("settlement event should be emitted");
 
-    // Even with valid expiry, duplicate should be prevented
-    // (This would require manual status manipulation to test, covered by test_duplicate_settlement_prevention)
+    // Verify event data contains correct fields
+    let event_data: soroban_sdk::Vec<soroban_sdk::Val> = FromVal::from_val(&env, &settlement_event.2);
+    assert_eq!(u64::from_val(&env, &event_data.get(3).unwrap()), remittance_id);
+    assert_eq!(Address::from_val(&env, &event_data.get(4).unwrap()), sender);
+    assert_eq!(Address::from_val(&env, &event_data.get(5).unwrap()), agent);
+    assert_eq!(Address::from_val(&env, &event_data.get(6).unwrap()), token.address);
+    assert_eq!(i128::from_val(&env, &event_data.get(7).unwrap()), 975); // payout_amount = 1000 - 25 (fee)
 }
 
 #[test]
@@ -1236,23 +1261,16 @@ fn test_pause_unpause() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     assert!(!contract.is_paused());
 
-    // Verify event topic
-    assert_eq!(
-        settlement_event.topics,
-        (symbol_short!("settled"),).into_val(&env)
-    );
+    contract.pause();
+    assert!(contract.is_paused());
 
-    // Verify event data contains correct fields
-    let event_data: (Address, Address, Address, i128) = settlement_event.data.clone().try_into_val(&env).unwrap();
-    assert_eq!(event_data.0, sender);
-    assert_eq!(event_data.1, agent);
-    assert_eq!(event_data.2, token.address);
-    assert_eq!(event_data.3, 975); // payout_amount = 1000 - 25 (fee)
+    contract.unpause();
+    assert!(!contract.is_paused());
 }
 
 #[test]
@@ -1269,23 +1287,23 @@ fn test_duplicate_prevention_with_expiry() {
     env.mock_all_auths();
     token.mint(&sender, &10000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent, &0, &admin);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time + 3600;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None);
 
-    contract.authorize_remittance(&admin, &remittance_id);
 
     // First settlement should succeed
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Settled);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     // Even with valid expiry, duplicate should be prevented
     // (This would require manual status manipulation to test, covered by test_duplicate_settlement_prevention)
@@ -1299,11 +1317,11 @@ fn test_set_and_get_asset_verification() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let token_admin = Address::generate(&env, &0, &admin);
+    let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "USDC");
     let issuer = Address::generate(&env);
@@ -1326,7 +1344,7 @@ fn test_set_and_get_asset_verification() {
     assert_eq!(verification.status, crate::VerificationStatus::Verified);
     assert_eq!(verification.reputation_score, 95);
     assert_eq!(verification.trustline_count, 15000);
-    assert_eq!(verification.has_toml, true);
+    assert!(verification.has_toml);
 }
 
 #[test]
@@ -1338,14 +1356,14 @@ fn test_has_asset_verification() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "USDC");
     let issuer = Address::generate(&env);
 
     // Should not exist initially
-    assert_eq!(contract.has_asset_verification(&asset_code, &issuer), false);
+    assert!(!contract.has_asset_verification(&asset_code, &issuer));
 
     // Set verification
     contract.set_asset_verification(
@@ -1358,7 +1376,7 @@ fn test_has_asset_verification() {
     );
 
     // Should exist now
-    assert_eq!(contract.has_asset_verification(&asset_code, &issuer), true);
+    assert!(contract.has_asset_verification(&asset_code, &issuer));
 }
 
 #[test]
@@ -1371,8 +1389,8 @@ fn test_set_asset_verification_invalid_score() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "USDC");
     let issuer = Address::generate(&env);
@@ -1398,8 +1416,8 @@ fn test_get_asset_verification_not_found() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "NOTFOUND");
     let issuer = Address::generate(&env);
@@ -1417,8 +1435,8 @@ fn test_validate_asset_safety_verified() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "USDC");
     let issuer = Address::generate(&env);
@@ -1446,8 +1464,8 @@ fn test_validate_asset_safety_unverified() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "UNKNOWN");
     let issuer = Address::generate(&env);
@@ -1476,8 +1494,8 @@ fn test_validate_asset_safety_suspicious() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "SCAM");
     let issuer = Address::generate(&env);
@@ -1505,8 +1523,8 @@ fn test_update_asset_verification() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
 
     let asset_code = String::from_str(&env, "USDC");
     let issuer = Address::generate(&env);
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{add_agent, setup, Setup};
+use crate::PayoutMethod;
+use soroban_sdk::{testutils::Ledger, vec, Address, Bytes, BytesN, Env, String, Vec};
+
+fn strings(env: &Env, items: &[&str]) -> Vec<String> {
+    let mut out = Vec::new(env);
+    for item in items {
+        out.push_back(String::from_str(env, item));
+    }
+    out
+}
+
+fn set_profile(env: &Env, s: &Setup, agent: &Address, countries: &[&str], currencies: &[&str]) {
+    s.client.set_agent_profile(
+        &s.admin,
+        agent,
+        &strings(env, countries),
+        &strings(env, currencies),
+        &vec![env, PayoutMethod::Cash],
+        &None,
+    );
+}
+
+fn send(env: &Env, s: &Setup, currency: &str, country: &str) -> u64 {
+    s.client.create_remittance(
+        &s.sender,
+        &s.agent,
+        &1000,
+        &s.token.address,
+        &String::from_str(env, currency),
+        &String::from_str(env, country),
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_profile_is_stored() {
+    let env = Env::default();
+    let s = setup(&env);
+    let name_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    s.client.set_agent_profile(
+        &s.admin,
+        &s.agent,
+        &strings(&env, &["NG", "GH"]),
+        &strings(&env, &["NGN", "GHS"]),
+        &vec![&env, PayoutMethod::Cash, PayoutMethod::MobileMoney],
+        &Some(name_hash.clone()),
+    );
+
+    let profile = s.client.get_agent_profile(&s.agent).unwrap();
+    assert_eq!(profile.countries, strings(&env, &["NG", "GH"]));
+    assert_eq!(profile.payout_methods.len(), 2);
+    assert_eq!(s.client.get_agent_name_hash(&s.agent), Some(name_hash));
+}
+
+#[test]
+fn test_agent_without_profile_serves_any_corridor() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, "KES", "KE");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+fn test_unserved_country_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    set_profile(&env, &s, &s.agent, &["NG"], &["NGN"]);
+
+    send(&env, &s, "NGN", "NG");
+    send(&env, &s, "NGN", "GH");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+fn test_unserved_currency_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    set_profile(&env, &s, &s.agent, &["NG"], &["NGN"]);
+
+    send(&env, &s, "USD", "NG");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+fn test_open_claim_requires_corridor() {
+    let env = Env::default();
+    let s = setup(&env);
+    set_profile(&env, &s, &s.agent, &["GH"], &["GHS"]);
+
+    let code = Bytes::from_slice(&env, b"483-921-XK");
+    let claim_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    let id = s.client.create_open_remittance(
+        &s.sender,
+        &1000,
+        &s.token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &claim_hash,
+        &None,
+    );
+
+    s.client.commit_claim(&s.agent, &id, &crate::compute_claim_commitment(&env, &code, &s.agent));
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    s.client.claim_remittance(&s.agent, &id, &code);
+}
+
+#[test]
+fn test_agents_by_corridor_paged() {
+    let env = Env::default();
+    let s = setup(&env);
+    let second = add_agent(&env, &s);
+    let third = add_agent(&env, &s);
+    let other_currency = add_agent(&env, &s);
+    set_profile(&env, &s, &s.agent, &["NG"], &["NGN"]);
+    set_profile(&env, &s, &second, &["NG", "GH"], &["NGN", "GHS"]);
+    set_profile(&env, &s, &third, &["NG"], &["NGN"]);
+    set_profile(&env, &s, &other_currency, &["NG"], &["USD"]);
+
+    let ngn = String::from_str(&env, "NGN");
+    let ng = String::from_str(&env, "NG");
+    let first_page = s.client.get_agents_by_corridor(&ngn, &ng, &1, &2);
+    assert_eq!(first_page.agents, vec![&env, s.agent.clone(), second.clone()]);
+    assert_eq!(first_page.total_records, 3);
+    assert_eq!(first_page.total_pages, 2);
+
+    let second_page = s.client.get_agents_by_corridor(&ngn, &ng, &2, &2);
+    assert_eq!(second_page.agents, vec![&env, third.clone()]);
+}
+
+#[test]
+fn test_corridor_index_follows_profile_and_registration() {
+    let env = Env::default();
+    let s = setup(&env);
+    let second = add_agent(&env, &s);
+    set_profile(&env, &s, &s.agent, &["NG"], &["NGN"]);
+    set_profile(&env, &s, &second, &["NG"], &["NGN"]);
+
+    set_profile(&env, &s, &s.agent, &["GH"], &["GHS"]);
+    s.client.remove_agent(&second);
+
+    let page = s.client.get_agents_by_corridor(&String::from_str(&env, "NGN"), &String::from_str(&env, "NG"), &1, &10);
+    assert_eq!(page.total_records, 0);
+
+    let page = s.client.get_agents_by_corridor(&String::from_str(&env, "GHS"), &String::from_str(&env, "GH"), &1, &10);
+    assert_eq!(page.agents, vec![&env, s.agent.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #79)")]
+fn test_profile_without_countries_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    set_profile(&env, &s, &s.agent, &[], &["NGN"]);
+}
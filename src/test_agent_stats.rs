@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{add_agent, add_token, send_to, send_token, setup, START};
+use crate::{AgentStats, BatchSettlementEntry};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+
+#[test]
+fn test_unknown_agent_has_zero_stats() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_agent_stats(&s.agent), AgentStats::default());
+}
+
+#[test]
+fn test_confirm_payout_records_volume_and_timing() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let first = send_to(&env, &s, &s.agent, 1000);
+    let second = send_to(&env, &s, &s.agent, 3000);
+    env.ledger().with_mut(|li| li.timestamp = START + 100);
+    s.client.confirm_payout(&first);
+    env.ledger().with_mut(|li| li.timestamp = START + 300);
+    s.client.confirm_payout(&second);
+
+    assert_eq!(s.client.get_agent_settled_volume(&s.agent, &s.token.address), 4000);
+    let stats = s.client.get_agent_stats(&s.agent);
+    assert_eq!(stats.settled_count, 2);
+    assert_eq!(stats.avg_settle_time, 200);
+    assert_eq!(stats.max_settle_time, 300);
+    assert_eq!(stats.last_active, START + 300);
+}
+
+#[test]
+fn test_cancellation_counted_against_agent() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.cancel_remittance(&send_to(&env, &s, &s.agent, 1000));
+
+    let stats = s.client.get_agent_stats(&s.agent);
+    assert_eq!(stats.cancelled_count, 1);
+    assert_eq!(stats.settled_count, 0);
+    assert_eq!(stats.last_active, 0);
+}
+
+#[test]
+fn test_batch_settlement_records_each_remittance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let first = send_to(&env, &s, &s.agent, 1000);
+    let second = send_to(&env, &s, &s.agent, 2000);
+    env.ledger().with_mut(|li| li.timestamp = START + 50);
+
+    s.client.batch_settle_with_netting(
+        &vec![
+            &env,
+            BatchSettlementEntry { remittance_id: first },
+            BatchSettlementEntry { remittance_id: second },
+        ],
+        &s.token.address,
+    );
+
+    assert_eq!(s.client.get_agent_settled_volume(&s.agent, &s.token.address), 3000);
+    let stats = s.client.get_agent_stats(&s.agent);
+    assert_eq!(stats.settled_count, 2);
+    assert_eq!(stats.avg_settle_time, 50);
+}
+
+#[test]
+fn test_leaderboard_ranks_by_volume() {
+    let env = Env::default();
+    let s = setup(&env);
+    let second_agent = add_agent(&env, &s);
+    let third_agent = add_agent(&env, &s);
+
+    s.client.confirm_payout(&send_to(&env, &s, &s.agent, 1000));
+    s.client.confirm_payout(&send_to(&env, &s, &second_agent, 5000));
+    s.client.confirm_payout(&send_to(&env, &s, &third_agent, 3000));
+
+    let first_page = s.client.get_agent_leaderboard(&s.token.address, &1, &2);
+    assert_eq!(first_page.total_records, 3);
+    assert_eq!(first_page.total_pages, 2);
+    assert_eq!(first_page.rankings.len(), 2);
+    assert_eq!(first_page.rankings.get(0).unwrap().agent, second_agent);
+    assert_eq!(first_page.rankings.get(1).unwrap().agent, third_agent);
+
+    let second_page = s.client.get_agent_leaderboard(&s.token.address, &2, &2);
+    assert_eq!(second_page.rankings.len(), 1);
+    assert_eq!(second_page.rankings.get(0).unwrap().agent, s.agent);
+    assert_eq!(second_page.rankings.get(0).unwrap().settled_volume, 1000);
+}
+
+#[test]
+fn test_later_settlement_moves_agent_up() {
+    let env = Env::default();
+    let s = setup(&env);
+    let second_agent = add_agent(&env, &s);
+    let third_agent = add_agent(&env, &s);
+
+    s.client.confirm_payout(&send_to(&env, &s, &s.agent, 1000));
+    s.client.confirm_payout(&send_to(&env, &s, &second_agent, 3000));
+    s.client.confirm_payout(&send_to(&env, &s, &third_agent, 2000));
+    s.client.confirm_payout(&send_to(&env, &s, &s.agent, 2500));
+
+    let rankings = s.client.get_agent_leaderboard(&s.token.address, &1, &10).rankings;
+    assert_eq!(rankings.len(), 3);
+    assert_eq!(rankings.get(0).unwrap().agent, s.agent);
+    assert_eq!(rankings.get(0).unwrap().settled_volume, 3500);
+    assert_eq!(rankings.get(1).unwrap().agent, second_agent);
+    assert_eq!(rankings.get(2).unwrap().agent, third_agent);
+}
+
+#[test]
+fn test_volume_is_ranked_per_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+    let second_agent = add_agent(&env, &s);
+
+    s.client.confirm_payout(&send_to(&env, &s, &s.agent, 1000));
+    s.client.confirm_payout(&send_token(&env, &s, &second_agent, &eurc.address, 5000));
+
+    let usdc_board = s.client.get_agent_leaderboard(&s.token.address, &1, &10);
+    assert_eq!(usdc_board.total_records, 1);
+    assert_eq!(usdc_board.rankings.get(0).unwrap().agent, s.agent);
+
+    let eurc_board = s.client.get_agent_leaderboard(&eurc.address, &1, &10);
+    assert_eq!(eurc_board.total_records, 1);
+    assert_eq!(eurc_board.rankings.get(0).unwrap().agent, second_agent);
+    assert_eq!(s.client.get_agent_settled_volume(&second_agent, &s.token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")]
+fn test_leaderboard_rejects_zero_page() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.get_agent_leaderboard(&s.token.address, &0, &10);
+}
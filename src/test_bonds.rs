@@ -0,0 +1,232 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{self, add_token, send, send_token, Setup, START};
+use crate::{DisputeOutcome, SlashDestination};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+// Just over the default seven-day dispute window
+const DELAY: u64 = 691200;
+
+/// Fixture where remittances above 500 need an active bond of at least 2000
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    let s = test_fixtures::setup(env);
+    token::StellarAssetClient::new(env, &s.token.address).mint(&s.agent, &10000);
+    s.client.set_bond_config(&s.admin, &500, &2000, &DELAY, &Some(Address::generate(env)));
+    s
+}
+
+fn disputed_remittance(env: &Env, s: &Setup) -> u64 {
+    let id = send(env, s, 1000);
+    s.client.confirm_payout(&id);
+    s.client.open_dispute(&id, &BytesN::from_array(env, &[9u8; 32]));
+    id
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+fn test_unbonded_agent_cannot_take_large_remittance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 500);
+    send(&env, &s, 501);
+}
+
+#[test]
+fn test_bonded_agent_takes_large_remittance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    send(&env, &s, 1000);
+
+    assert_eq!(s.client.get_agent_bond(&s.agent, &s.token.address).bonded, 2000);
+    assert_eq!(s.token.balance(&s.agent), 8000);
+}
+
+#[test]
+fn test_bond_config_does_not_clobber_rate_limit_config() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Both configs live in instance storage and must not share a key
+    assert_eq!(s.client.get_rate_limit_config(), (100, 60, true));
+
+    let remittance_id = send(&env, &s, 500);
+    assert_eq!(s.client.get_remittance(&remittance_id).amount, 500);
+    assert_eq!(s.client.get_bond_config().unwrap().threshold, 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #76)")]
+fn test_withdraw_before_delay_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    assert_eq!(s.client.request_unbond(&s.agent, &s.token.address, &500), START + DELAY);
+
+    env.ledger().with_mut(|li| li.timestamp = START + DELAY - 1);
+    s.client.withdraw_unbonded(&s.agent, &s.token.address);
+}
+
+#[test]
+fn test_withdraw_after_delay() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    s.client.request_unbond(&s.agent, &s.token.address, &500);
+    env.ledger().with_mut(|li| li.timestamp = START + DELAY);
+
+    assert_eq!(s.client.withdraw_unbonded(&s.agent, &s.token.address), 500);
+
+    let bond = s.client.get_agent_bond(&s.agent, &s.token.address);
+    assert_eq!(bond.bonded, 1500);
+    assert_eq!(bond.unbonding, 0);
+    assert_eq!(s.token.balance(&s.agent), 8500);
+}
+
+#[test]
+fn test_remove_agent_unbonds_whole_bond() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    s.client.remove_agent(&s.agent);
+
+    let bond = s.client.get_agent_bond(&s.agent, &s.token.address);
+    assert_eq!(bond.bonded, 0);
+    assert_eq!(bond.unbonding, 2000);
+    assert_eq!(bond.withdrawable_at, START + DELAY);
+}
+
+#[test]
+fn test_slash_compensates_sender() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+    let sender_balance = s.token.balance(&s.sender);
+
+    s.client.slash_agent_bond(&s.arbitrator, &id, &600, &SlashDestination::Sender);
+
+    assert_eq!(s.token.balance(&s.sender), sender_balance + 600);
+    let bond = s.client.get_agent_bond(&s.agent, &s.token.address);
+    assert_eq!(bond.bonded, 1400);
+    assert_eq!(bond.slashed, 600);
+    assert_eq!(s.client.get_slashed_amount(&id), 600);
+}
+
+#[test]
+fn test_slash_reaches_unbonding_collateral_and_insurance_pool() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+    s.client.request_unbond(&s.agent, &s.token.address, &1800);
+
+    s.client.slash_agent_bond(&s.admin, &id, &500, &SlashDestination::InsurancePool);
+
+    let pool = s.client.get_bond_config().unwrap().insurance_pool.unwrap();
+    assert_eq!(s.token.balance(&pool), 500);
+    let bond = s.client.get_agent_bond(&s.agent, &s.token.address);
+    assert_eq!(bond.bonded, 0);
+    assert_eq!(bond.unbonding, 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_slash_capped_at_remittance_amount() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+
+    s.client.slash_agent_bond(&s.arbitrator, &id, &800, &SlashDestination::Sender);
+    s.client.slash_agent_bond(&s.arbitrator, &id, &201, &SlashDestination::Sender);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_rejected_dispute_cannot_be_slashed() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::Rejected);
+
+    s.client.slash_agent_bond(&s.arbitrator, &id, &100, &SlashDestination::Sender);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_only_arbitrator_or_admin_slashes() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+
+    s.client.slash_agent_bond(&s.sender, &id, &100, &SlashDestination::Sender);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #76)")]
+fn test_withdraw_blocked_while_dispute_open() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    disputed_remittance(&env, &s);
+    s.client.request_unbond(&s.agent, &s.token.address, &2000);
+    env.ledger().with_mut(|li| li.timestamp = START + DELAY);
+
+    s.client.withdraw_unbonded(&s.agent, &s.token.address);
+}
+
+#[test]
+fn test_withdraw_allowed_once_dispute_resolved() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = disputed_remittance(&env, &s);
+    assert_eq!(s.client.get_open_dispute_count(&s.agent), 1);
+    s.client.request_unbond(&s.agent, &s.token.address, &2000);
+
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::Rejected);
+    env.ledger().with_mut(|li| li.timestamp = START + DELAY);
+
+    assert_eq!(s.client.get_open_dispute_count(&s.agent), 0);
+    assert_eq!(s.client.withdraw_unbonded(&s.agent, &s.token.address), 2000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #90)")]
+fn test_unbonding_delay_must_exceed_dispute_window() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_bond_config(&s.admin, &500, &2000, &s.client.get_dispute_window(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #90)")]
+fn test_dispute_window_must_stay_below_unbonding_delay() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.update_dispute_window(&s.admin, &DELAY);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+fn test_bond_only_covers_its_own_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+
+    send_token(&env, &s, &s.agent, &eurc.address, 1000);
+}
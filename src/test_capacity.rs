@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{add_token, send, send_expiring, send_token, setup, START};
+use soroban_sdk::{testutils::Ledger, Env};
+
+#[test]
+fn test_agent_without_capacity_is_unlimited() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 50000);
+
+    let capacity = s.client.get_agent_capacity(&s.agent, &s.token.address);
+    assert_eq!(capacity.capacity, None);
+    assert_eq!(capacity.used, 50000);
+    assert_eq!(capacity.available, None);
+}
+
+#[test]
+fn test_exposure_follows_remittance_lifecycle() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_agent_capacity(&s.admin, &s.agent, &s.token.address, &Some(5000));
+
+    let settled = send(&env, &s, 1000);
+    let cancelled = send(&env, &s, 2000);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).used, 3000);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).available, Some(2000));
+
+    s.client.confirm_payout(&settled);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).used, 2000);
+
+    s.client.cancel_remittance(&cancelled);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).used, 0);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).available, Some(5000));
+}
+
+#[test]
+fn test_capacity_is_per_token() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let other = add_token(&env, &s).address;
+    s.client.set_agent_capacity(&s.admin, &s.agent, &s.token.address, &Some(1000));
+
+    // Exposure in another token neither uses nor is limited by this capacity
+    send(&env, &s, 1000);
+    send_token(&env, &s, &s.agent, &other, 5000);
+
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).available, Some(0));
+    let capacity = s.client.get_agent_capacity(&s.agent, &other);
+    assert_eq!(capacity.capacity, None);
+    assert_eq!(capacity.used, 5000);
+}
+
+#[test]
+fn test_expiry_releases_exposure() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send_expiring(&env, &s, 1000, START + 100);
+    env.ledger().with_mut(|li| li.timestamp = START + 101);
+    s.client.sweep_expired(&0, &10);
+
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).used, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #78)")]
+fn test_assignment_over_capacity_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_agent_capacity(&s.admin, &s.agent, &s.token.address, &Some(1500));
+
+    send(&env, &s, 1000);
+    send(&env, &s, 501);
+}
+
+#[test]
+fn test_agent_declares_own_capacity() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_agent_capacity(&s.agent, &s.agent, &s.token.address, &Some(1000));
+    send(&env, &s, 1000);
+    assert_eq!(s.client.get_agent_capacity(&s.agent, &s.token.address).available, Some(0));
+
+    s.client.set_agent_capacity(&s.agent, &s.agent, &s.token.address, &None);
+    send(&env, &s, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_only_admin_or_agent_sets_capacity() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_agent_capacity(&s.sender, &s.agent, &s.token.address, &Some(1000000));
+}
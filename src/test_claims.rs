@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{BatchSettlementEntry, RemittanceStatus, Role, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Bytes, BytesN, Env, String,
+};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.token, s.admin, s.sender, s.agent)
+}
+
+fn claim_code(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"483-921-XK")
+}
+
+fn create_open(env: &Env, client: &SwiftRemitContractClient, token: &Address, sender: &Address) -> u64 {
+    let claim_hash: BytesN<32> = env.crypto().sha256(&claim_code(env)).into();
+    client.create_open_remittance(
+        sender,
+        &1000,
+        token,
+        &String::from_str(env, "NGN"),
+        &String::from_str(env, "NG"),
+        &claim_hash,
+        &None,
+    )
+}
+
+/// Commits `agent` to `code` and advances to the next ledger so it can reveal.
+fn commit(env: &Env, client: &SwiftRemitContractClient, agent: &Address, id: u64, code: &Bytes) {
+    client.commit_claim(agent, &id, &crate::compute_claim_commitment(env, code, agent));
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+}
+
+#[test]
+fn test_any_agent_claims_with_code() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    assert_eq!(client.get_remittance(&id).agent, client.address);
+
+    commit(&env, &client, &agent, id, &claim_code(&env));
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+
+    let remittance = client.get_remittance(&id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(remittance.agent, agent);
+    assert_eq!(token.balance(&agent), 975);
+    assert_eq!(client.get_accumulated_fees(&token.address), 25);
+    assert_eq!(client.get_remittances_by_agent(&agent, &1, &10).total_records, 1);
+    assert_eq!(client.get_remittances_by_agent(&client.address, &1, &10).total_records, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+fn test_wrong_code_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    client.claim_remittance(&agent, &id, &Bytes::from_slice(&env, b"000-000-00"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_code_is_single_use() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    commit(&env, &client, &agent, id, &claim_code(&env));
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+fn test_assigned_remittance_cannot_be_claimed() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = client.create_remittance(
+        &sender,
+        &agent,
+        &1000,
+        &token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &None,
+        &None,
+    );
+
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_claiming_agent_needs_settler_role() {
+    let env = Env::default();
+    let (client, token, _admin, sender, _agent) = setup(&env);
+    let other_agent = Address::generate(&env);
+    client.register_agent(&other_agent);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    commit(&env, &client, &other_agent, id, &claim_code(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #89)")]
+fn test_claim_without_commitment_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #89)")]
+fn test_claim_in_commitment_ledger_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    let code = claim_code(&env);
+    client.commit_claim(&agent, &id, &crate::compute_claim_commitment(&env, &code, &agent));
+    client.claim_remittance(&agent, &id, &code);
+}
+
+#[test]
+fn test_revealed_code_cannot_be_front_run() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let rival = Address::generate(&env);
+    client.register_agent(&rival);
+    client.assign_role(&admin, &rival, &Role::Settler);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    // The rival only learns the code once the agent reveals it
+    commit(&env, &client, &agent, id, &claim_code(&env));
+    let rival_commitment = crate::compute_claim_commitment(&env, &claim_code(&env), &rival);
+    client.commit_claim(&rival, &id, &rival_commitment);
+    assert!(client.try_claim_remittance(&rival, &id, &claim_code(&env)).is_err());
+
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+    assert_eq!(client.get_remittance(&id).agent, agent);
+    assert_eq!(token.balance(&agent), 975);
+    assert_eq!(token.balance(&rival), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+fn test_commitment_bound_to_agent() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let other = Address::generate(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    // A commitment computed for another address does not match the agent's
+    client.commit_claim(&agent, &id, &crate::compute_claim_commitment(&env, &claim_code(&env), &other));
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.claim_remittance(&agent, &id, &claim_code(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #88)")]
+fn test_batch_rejects_open_claim() {
+    let env = Env::default();
+    let (client, token, _admin, sender, _agent) = setup(&env);
+    let id = create_open(&env, &client, &token.address, &sender);
+
+    client.batch_settle_with_netting(&vec![&env, BatchSettlementEntry { remittance_id: id }], &token.address);
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{send, setup};
+use crate::{BatchSettlementEntry, CommissionPayout};
+use soroban_sdk::{vec, Env};
+
+#[test]
+fn test_no_commission_by_default() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.confirm_payout(&send(&env, &s, 1000));
+
+    assert_eq!(s.client.get_commission_bps(&s.agent), 0);
+    assert_eq!(s.token.balance(&s.agent), 975);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 25);
+}
+
+#[test]
+fn test_commission_paid_with_payout() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_commission_config(&s.admin, &4000, &CommissionPayout::WithPayout);
+
+    s.client.confirm_payout(&send(&env, &s, 1000));
+
+    // 40% of the 25 fee is 10
+    assert_eq!(s.token.balance(&s.agent), 985);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 15);
+    assert_eq!(s.token.balance(&s.client.address), 15);
+    assert_eq!(s.client.get_commission_balance(&s.agent, &s.token.address), 0);
+}
+
+#[test]
+fn test_commission_accrued_and_claimed() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_commission_config(&s.admin, &4000, &CommissionPayout::Accrue);
+
+    s.client.confirm_payout(&send(&env, &s, 1000));
+    s.client.confirm_payout(&send(&env, &s, 1000));
+
+    assert_eq!(s.token.balance(&s.agent), 1950);
+    assert_eq!(s.client.get_commission_balance(&s.agent, &s.token.address), 20);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 30);
+    assert_eq!(s.token.balance(&s.client.address), 50);
+
+    assert_eq!(s.client.claim_commission(&s.agent, &s.token.address), 20);
+    assert_eq!(s.token.balance(&s.agent), 1970);
+    assert_eq!(s.client.get_commission_balance(&s.agent, &s.token.address), 0);
+    assert_eq!(s.token.balance(&s.client.address), 30);
+}
+
+#[test]
+fn test_agent_rate_overrides_default() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_commission_config(&s.admin, &4000, &CommissionPayout::WithPayout);
+    s.client.set_agent_commission(&s.admin, &s.agent, &Some(10000));
+    assert_eq!(s.client.get_commission_bps(&s.agent), 10000);
+
+    s.client.confirm_payout(&send(&env, &s, 1000));
+    assert_eq!(s.token.balance(&s.agent), 1000);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 0);
+
+    s.client.set_agent_commission(&s.admin, &s.agent, &None);
+    assert_eq!(s.client.get_commission_bps(&s.agent), 4000);
+}
+
+#[test]
+fn test_batch_settlement_pays_commission() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_commission_config(&s.admin, &4000, &CommissionPayout::WithPayout);
+
+    let first = send(&env, &s, 1000);
+    let second = send(&env, &s, 2000);
+    s.client.batch_settle_with_netting(
+        &vec![
+            &env,
+            BatchSettlementEntry { remittance_id: first },
+            BatchSettlementEntry { remittance_id: second },
+        ],
+        &s.token.address,
+    );
+
+    // Fees of 25 and 50 carry commissions of 10 and 20
+    assert_eq!(s.token.balance(&s.agent), 2925 + 30);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 45);
+    assert_eq!(s.token.balance(&s.client.address), 45);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_claim_without_balance_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.claim_commission(&s.agent, &s.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_commission_above_full_fee_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_commission_config(&s.admin, &10001, &CommissionPayout::WithPayout);
+}
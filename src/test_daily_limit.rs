@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::SwiftRemitContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.token.address, s.admin, s.sender, s.agent)
+}
+
+fn ngn(env: &Env) -> (String, String) {
+    (String::from_str(env, "NGN"), String::from_str(env, "NG"))
+}
+
+#[test]
+fn test_sends_within_limit_succeed() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let (currency, country) = ngn(&env);
+
+    client.set_daily_limit(&admin, &currency, &country, &5000);
+    client.create_remittance(&sender, &agent, &3000, &token, &currency, &country, &None, &None);
+    client.create_remittance(&sender, &agent, &2000, &token, &currency, &country, &None, &None);
+
+    assert_eq!(client.get_remaining_daily_allowance(&sender, &currency, &country, &token), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_send_over_limit_rejected() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let (currency, country) = ngn(&env);
+
+    client.set_daily_limit(&admin, &currency, &country, &5000);
+    client.create_remittance(&sender, &agent, &3000, &token, &currency, &country, &None, &None);
+    client.create_remittance(&sender, &agent, &2001, &token, &currency, &country, &None, &None);
+}
+
+#[test]
+fn test_allowance_resets_after_window() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let (currency, country) = ngn(&env);
+
+    client.set_daily_limit(&admin, &currency, &country, &5000);
+    client.create_remittance(&sender, &agent, &5000, &token, &currency, &country, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp += 86401);
+
+    assert_eq!(client.get_remaining_daily_allowance(&sender, &currency, &country, &token), Some(5000));
+    client.create_remittance(&sender, &agent, &5000, &token, &currency, &country, &None, &None);
+}
+
+#[test]
+fn test_limits_are_per_corridor_token_and_sender() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let (currency, country) = ngn(&env);
+    let other_sender = Address::generate(&env);
+    let ghs = String::from_str(&env, "GHS");
+    let gh = String::from_str(&env, "GH");
+
+    client.set_daily_limit(&admin, &currency, &country, &1000);
+    client.create_remittance(&sender, &agent, &1000, &token, &currency, &country, &None, &None);
+
+    // Uncapped corridor is unaffected
+    assert_eq!(client.get_remaining_daily_allowance(&sender, &ghs, &gh, &token), None);
+    client.create_remittance(&sender, &agent, &5000, &token, &ghs, &gh, &None, &None);
+
+    // Other senders have their own allowance
+    assert_eq!(client.get_remaining_daily_allowance(&other_sender, &currency, &country, &token), Some(1000));
+
+    // Transfers in another token are counted separately
+    let other_token = Address::generate(&env);
+    assert_eq!(client.get_remaining_daily_allowance(&sender, &currency, &country, &other_token), Some(1000));
+}
+
+#[test]
+fn test_remove_daily_limit() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let (currency, country) = ngn(&env);
+
+    client.set_daily_limit(&admin, &currency, &country, &1000);
+    assert_eq!(client.get_daily_limit(&currency, &country).unwrap().limit, 1000);
+
+    client.remove_daily_limit(&admin, &currency, &country);
+
+    assert!(client.get_daily_limit(&currency, &country).is_none());
+    client.create_remittance(&sender, &agent, &5000, &token, &currency, &country, &None, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")]
+fn test_empty_corridor_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    client.create_remittance(&sender, &agent, &1000, &token, &String::from_str(&env, ""), &String::from_str(&env, "NG"), &None, &None);
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{setup, Setup};
+use crate::{CommissionPayout, DisputeOutcome, DisputeStatus};
+use soroban_sdk::{testutils::Ledger, token, BytesN, Env, String};
+
+fn completed_remittance(env: &Env, s: &Setup) -> u64 {
+    let id = s.client.create_remittance(
+        &s.sender,
+        &s.agent,
+        &1000,
+        &s.token.address,
+        &String::from_str(env, "USD"),
+        &String::from_str(env, "US"),
+        &None,
+        &None,
+    );
+    s.client.confirm_payout(&id);
+    id
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9u8; 32])
+}
+
+#[test]
+fn test_fee_refund_ruling() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = completed_remittance(&env, &s);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    assert_eq!(s.client.get_dispute(&id).status, DisputeStatus::Open);
+
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::FeeRefunded);
+
+    let dispute = s.client.get_dispute(&id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved(DisputeOutcome::FeeRefunded));
+    assert_eq!(dispute.arbitrator, Some(s.arbitrator.clone()));
+    assert_eq!(dispute.refunded_amount, 25);
+    assert_eq!(s.token.balance(&s.sender), 99025);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 0);
+}
+
+#[test]
+fn test_agent_charge_ruling() {
+    let env = Env::default();
+    let s = setup(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&s.agent, &2000);
+    s.client.post_bond(&s.agent, &s.token.address, &2000);
+    let id = completed_remittance(&env, &s);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::AgentCharged);
+
+    // Charged out of the bond; the agent keeps the payout it already handed out
+    assert_eq!(s.token.balance(&s.agent), 975);
+    assert_eq!(s.client.get_agent_bond(&s.agent, &s.token.address).bonded, 1025);
+    assert_eq!(s.client.get_agent_bond(&s.agent, &s.token.address).slashed, 975);
+    assert_eq!(s.token.balance(&s.sender), 99975);
+    assert_eq!(s.client.get_dispute(&id).refunded_amount, 975);
+    assert_eq!(s.token.balance(&s.client.address), 1025 + 25);
+}
+
+#[test]
+fn test_fee_refund_excludes_agent_commission() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_commission_config(&s.admin, &4000, &CommissionPayout::WithPayout);
+    let id = completed_remittance(&env, &s);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 15);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::FeeRefunded);
+
+    assert_eq!(s.client.get_dispute(&id).refunded_amount, 15);
+    assert_eq!(s.token.balance(&s.sender), 99015);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 0);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+fn test_agent_charge_requires_bond() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = completed_remittance(&env, &s);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::AgentCharged);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+fn test_dispute_after_window_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.update_dispute_window(&s.admin, &3600);
+    let id = completed_remittance(&env, &s);
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    s.client.open_dispute(&id, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+fn test_pending_remittance_cannot_be_disputed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = s.client.create_remittance(
+        &s.sender,
+        &s.agent,
+        &1000,
+        &s.token.address,
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "US"),
+        &None,
+        &None,
+    );
+
+    s.client.open_dispute(&id, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_only_arbitrator_can_rule() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = completed_remittance(&env, &s);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    s.client.resolve_dispute(&s.admin, &id, &DisputeOutcome::FeeRefunded);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+fn test_dispute_cannot_be_resolved_twice() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = completed_remittance(&env, &s);
+
+    s.client.open_dispute(&id, &evidence(&env));
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::Rejected);
+    s.client.resolve_dispute(&s.arbitrator, &id, &DisputeOutcome::FeeRefunded);
+}
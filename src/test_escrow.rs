@@ -1,16 +1,21 @@
 #![cfg(test)]
-use crate::{SwiftRemitContract, SwiftRemitContractClient, Escrow, EscrowStatus};
+use crate::{SwiftRemitContract, SwiftRemitContractClient, EscrowStatus};
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
-    token, Address, Env, IntoVal, Symbol,
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Env, FromVal, Symbol,
 };
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
     token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
 }
 
-fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
-    SwiftRemitContractClient::new(env, &env.register(SwiftRemitContract, ()))
+fn create_swiftremit_contract<'a>(env: &Env, token: &Address) -> SwiftRemitContractClient<'a> {
+    let contract_id = env.register_contract(None, SwiftRemitContract {});
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(env, token, true);
+    });
+    SwiftRemitContractClient::new(env, &contract_id)
 }
 
 #[test]
@@ -25,14 +30,14 @@ fn test_create_escrow() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
 
     assert_eq!(transfer_id, 1);
-    assert_eq!(token.balance(&sender), 500);
-    assert_eq!(token.balance(&contract.address), 500);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&sender), 500);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&contract.address), 500);
 
     let escrow = contract.get_escrow(&transfer_id);
     assert_eq!(escrow.sender, sender);
@@ -53,16 +58,16 @@ fn test_release_escrow() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
     contract.release_escrow(&transfer_id);
 
     let escrow = contract.get_escrow(&transfer_id);
     assert_eq!(escrow.status, EscrowStatus::Released);
-    assert_eq!(token.balance(&recipient), 500);
-    assert_eq!(token.balance(&contract.address), 0);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&recipient), 500);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&contract.address), 0);
 }
 
 #[test]
@@ -77,20 +82,20 @@ fn test_refund_escrow() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
     contract.refund_escrow(&transfer_id);
 
     let escrow = contract.get_escrow(&transfer_id);
     assert_eq!(escrow.status, EscrowStatus::Refunded);
-    assert_eq!(token.balance(&sender), 1000);
-    assert_eq!(token.balance(&contract.address), 0);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&sender), 1000);
+    assert_eq!(token::Client::new(&env, &token.address).balance(&contract.address), 0);
 }
 
 #[test]
-#[should_panic(expected = "InvalidEscrowStatus")]
+#[should_panic(expected = "Error(Contract, #40)")]
 fn test_double_release_prevented() {
     let env = Env::default();
     env.mock_all_auths();
@@ -102,16 +107,16 @@ fn test_double_release_prevented() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
     contract.release_escrow(&transfer_id);
     contract.release_escrow(&transfer_id); // Should panic
 }
 
 #[test]
-#[should_panic(expected = "InvalidEscrowStatus")]
+#[should_panic(expected = "Error(Contract, #40)")]
 fn test_double_refund_prevented() {
     let env = Env::default();
     env.mock_all_auths();
@@ -123,16 +128,16 @@ fn test_double_refund_prevented() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
     contract.refund_escrow(&transfer_id);
     contract.refund_escrow(&transfer_id); // Should panic
 }
 
 #[test]
-#[should_panic(expected = "InvalidAmount")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_create_escrow_zero_amount() {
     let env = Env::default();
     env.mock_all_auths();
@@ -142,10 +147,10 @@ fn test_create_escrow_zero_amount() {
     let recipient = Address::generate(&env);
 
     let token = create_token_contract(&env, &admin);
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    contract.create_escrow(&sender, &recipient, &0);
+    contract.create_escrow(&sender, &recipient, &0, &token.address);
 }
 
 #[test]
@@ -160,15 +165,15 @@ fn test_escrow_events_emitted() {
     let token = create_token_contract(&env, &admin);
     token.mint(&sender, &1000);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    let contract = create_swiftremit_contract(&env, &token.address);
+    contract.initialize(&admin, &token.address, &250, &3600, &0, &admin);
 
-    let transfer_id = contract.create_escrow(&sender, &recipient, &500);
+    let transfer_id = contract.create_escrow(&sender, &recipient, &500, &token.address);
     
     let events = env.events().all();
     let create_event = events.iter().find(|e| {
-        e.topics.get(0).unwrap() == &Symbol::new(&env, "escrow").into_val(&env)
-            && e.topics.get(1).unwrap() == &Symbol::new(&env, "created").into_val(&env)
+        Symbol::from_val(&env, &e.1.get(0).unwrap()) == symbol_short!("escrow")
+            && Symbol::from_val(&env, &e.1.get(1).unwrap()) == symbol_short!("created")
     });
     assert!(create_event.is_some());
 
@@ -176,8 +181,8 @@ fn test_escrow_events_emitted() {
     
     let events = env.events().all();
     let release_event = events.iter().find(|e| {
-        e.topics.get(0).unwrap() == &Symbol::new(&env, "escrow").into_val(&env)
-            && e.topics.get(1).unwrap() == &Symbol::new(&env, "released").into_val(&env)
+        Symbol::from_val(&env, &e.1.get(0).unwrap()) == symbol_short!("escrow")
+            && Symbol::from_val(&env, &e.1.get(1).unwrap()) == symbol_short!("released")
     });
     assert!(release_event.is_some());
 }
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{RemittanceStatus, SwiftRemitContractClient, TransferState};
+use soroban_sdk::{testutils::Ledger, token, vec, Address, Env, String};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.token, s.sender, s.agent)
+}
+
+fn create(
+    env: &Env,
+    client: &SwiftRemitContractClient,
+    token: &Address,
+    sender: &Address,
+    agent: &Address,
+    expiry: Option<u64>,
+) -> u64 {
+    client.create_remittance(
+        sender,
+        agent,
+        &1000,
+        token,
+        &String::from_str(env, "USD"),
+        &String::from_str(env, "US"),
+        &expiry,
+        &None,
+    )
+}
+
+#[test]
+fn test_expire_refunds_sender() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let expiry = env.ledger().timestamp() + 100;
+    let id = create(&env, &client, &token.address, &sender, &agent, Some(expiry));
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    let expired = client.expire_remittances(&vec![&env, id]);
+
+    assert_eq!(expired, vec![&env, id]);
+    assert_eq!(token.balance(&sender), 100000);
+    assert_eq!(client.get_remittance(&id).status, RemittanceStatus::Expired);
+    assert_eq!(client.get_transfer_state(&id), Some(TransferState::Refunded));
+}
+
+#[test]
+fn test_expire_skips_ineligible_remittances() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let expiry = env.ledger().timestamp() + 100;
+    let no_expiry = create(&env, &client, &token.address, &sender, &agent, None);
+    let not_yet = create(&env, &client, &token.address, &sender, &agent, Some(expiry + 1000));
+    let cancelled = create(&env, &client, &token.address, &sender, &agent, Some(expiry));
+    client.cancel_remittance(&cancelled);
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    let expired = client.expire_remittances(&vec![&env, no_expiry, not_yet, cancelled]);
+
+    assert!(expired.is_empty());
+    assert_eq!(token.balance(&sender), 98000);
+    assert_eq!(client.get_remittance(&cancelled).status, RemittanceStatus::Cancelled);
+}
+
+#[test]
+fn test_sweep_expires_across_pages() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let expiry = env.ledger().timestamp() + 100;
+    let keep = create(&env, &client, &token.address, &sender, &agent, None);
+    for _ in 0..3 {
+        create(&env, &client, &token.address, &sender, &agent, Some(expiry));
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+
+    let first = client.sweep_expired(&0, &2);
+    assert_eq!(first.expired_ids.len(), 1);
+    assert!(!first.done);
+
+    let second = client.sweep_expired(&first.next_cursor, &10);
+    assert_eq!(second.expired_ids.len(), 2);
+    assert!(second.done);
+
+    assert_eq!(token.balance(&sender), 99000);
+    let pending = client.get_remittances_by_status(&RemittanceStatus::Pending, &1, &10);
+    assert_eq!(pending.total_records, 1);
+    assert_eq!(pending.remittances.get_unchecked(0).id, keep);
+    assert_eq!(client.get_remittances_by_status(&RemittanceStatus::Expired, &1, &10).total_records, 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_expired_remittance_cannot_be_cancelled() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let expiry = env.ledger().timestamp() + 100;
+    let id = create(&env, &client, &token.address, &sender, &agent, Some(expiry));
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    client.expire_remittances(&vec![&env, id]);
+
+    client.cancel_remittance(&id);
+}
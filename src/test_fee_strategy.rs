@@ -1,19 +1,24 @@
 #![cfg(test)]
 
-use crate::{SwiftRemitContract, SwiftRemitContractClient, FeeStrategy};
-use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
-    token, Address, Env, IntoVal, Symbol,
-};
+use crate::{SwiftRemitContract, SwiftRemitContractClient, FeeLimits, FeeSchedule, FeeStrategy, FeeTier};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
-    let contract_address = env.register_stellar_asset_contract(admin.clone());
+    let contract_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
     (
         token::Client::new(env, &contract_address),
         token::StellarAssetClient::new(env, &contract_address),
     )
 }
 
+fn default_currency(env: &Env) -> String {
+    String::from_str(env, "USD")
+}
+
+fn default_country(env: &Env) -> String {
+    String::from_str(env, "US")
+}
+
 #[test]
 fn test_percentage_strategy() {
     let env = Env::default();
@@ -30,9 +35,9 @@ fn test_percentage_strategy() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    // Whitelist token first
-    client.whitelist_token(&admin, &token.address);
-
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
 
     // Set percentage strategy: 5%
@@ -40,7 +45,7 @@ fn test_percentage_strategy() {
 
     client.register_agent(&agent);
 
-    let remittance_id = client.create_remittance(&sender, &agent, &10000, &None);
+    let remittance_id = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     let remittance = client.get_remittance(&remittance_id);
 
     // Fee should be 5% of 10000 = 500
@@ -63,7 +68,9 @@ fn test_flat_strategy() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    client.whitelist_token(&admin, &token.address);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
 
     // Set flat fee: 100 units
@@ -72,11 +79,11 @@ fn test_flat_strategy() {
     client.register_agent(&agent);
 
     // Small amount
-    let id1 = client.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id1).fee, 100);
 
     // Large amount - same fee
-    let id2 = client.create_remittance(&sender, &agent, &50000, &None);
+    let id2 = client.create_remittance(&sender, &agent, &50000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id2).fee, 100);
 }
 
@@ -96,7 +103,9 @@ fn test_dynamic_strategy() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    client.whitelist_token(&admin, &token.address);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
 
     // Set dynamic strategy: 4% base
@@ -105,18 +114,278 @@ fn test_dynamic_strategy() {
     client.register_agent(&agent);
 
     // <1000: 4%
-    let id1 = client.create_remittance(&sender, &agent, &500, &None);
+    let id1 = client.create_remittance(&sender, &agent, &500, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id1).fee, 20);
 
     // 1000-10000: 2%
-    let id2 = client.create_remittance(&sender, &agent, &5000, &None);
+    let id2 = client.create_remittance(&sender, &agent, &5000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id2).fee, 100);
 
     // >10000: 1%
-    let id3 = client.create_remittance(&sender, &agent, &20000, &None);
+    let id3 = client.create_remittance(&sender, &agent, &20000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id3).fee, 200);
 }
 
+#[test]
+fn test_tiered_strategy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&sender, &200000);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+
+    // 3% up to 1000, then 2% plus 5 up to 10000, then 1% plus 20
+    let tiers = vec![
+        &env,
+        FeeTier { upper_bound: 1000, bps: 300, flat_component: 0 },
+        FeeTier { upper_bound: 10000, bps: 200, flat_component: 5 },
+        FeeTier { upper_bound: 50000, bps: 100, flat_component: 20 },
+    ];
+    client.update_fee_strategy(&admin, &FeeStrategy::Tiered(tiers.clone()));
+    assert_eq!(client.get_fee_strategy(), FeeStrategy::Tiered(tiers));
+
+    client.register_agent(&agent);
+
+    // Upper bounds are inclusive
+    let id1 = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id1).fee, 30);
+
+    let id2 = client.create_remittance(&sender, &agent, &1001, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id2).fee, 25);
+
+    let id3 = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id3).fee, 205);
+
+    let id4 = client.create_remittance(&sender, &agent, &10001, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id4).fee, 120);
+
+    // Above the last bound the last tier applies
+    let id5 = client.create_remittance(&sender, &agent, &60000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id5).fee, 620);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #86)")]
+fn test_tiered_strategy_rejects_unordered_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, _) = create_token_contract(&env, &admin);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+
+    client.update_fee_strategy(
+        &admin,
+        &FeeStrategy::Tiered(vec![
+            &env,
+            FeeTier { upper_bound: 10000, bps: 200, flat_component: 0 },
+            FeeTier { upper_bound: 1000, bps: 300, flat_component: 0 },
+        ]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_tiered_strategy_rejects_excessive_bps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, _) = create_token_contract(&env, &admin);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+
+    client.update_fee_strategy(
+        &admin,
+        &FeeStrategy::Tiered(vec![&env, FeeTier { upper_bound: 1000, bps: 10001, flat_component: 0 }]),
+    );
+}
+
+#[test]
+fn test_fee_limits_clamp_global_strategy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&sender, &200000);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+    client.register_agent(&agent);
+
+    // 2.5%, but never below 10 or above 500
+    let limits = FeeLimits { min_fee: Some(10), max_fee: Some(500) };
+    client.set_fee_limits(&admin, &limits);
+    assert_eq!(client.get_fee_limits(), limits);
+
+    // 2.5% of 100 rounds to 2, raised to the minimum
+    let id1 = client.create_remittance(&sender, &agent, &100, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id1).fee, 10);
+
+    let id2 = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id2).fee, 250);
+
+    // 2.5% of 100000 is 2500, capped at the maximum
+    let id3 = client.create_remittance(&sender, &agent, &100000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert_eq!(client.get_remittance(&id3).fee, 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #91)")]
+fn test_minimum_fee_above_amount_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&sender, &10000);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+    client.register_agent(&agent);
+    client.set_fee_limits(&admin, &FeeLimits { min_fee: Some(200), max_fee: None });
+
+    // The minimum fee alone exceeds the amount
+    client.create_remittance(&sender, &agent, &100, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+}
+
+#[test]
+fn test_corridor_and_token_schedules_fall_back_to_global() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&sender, &200000);
+    let (other_token, other_token_admin) = create_token_contract(&env, &admin);
+    other_token_admin.mint(&sender, &200000);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+    client.whitelist_token(&admin, &other_token.address);
+    client.register_agent(&agent);
+
+    let ngn = String::from_str(&env, "NGN");
+    let ng = String::from_str(&env, "NG");
+    let mxn = String::from_str(&env, "MXN");
+    let mx = String::from_str(&env, "MX");
+
+    // Nigeria: 1% with a minimum of 50
+    let nigeria = FeeSchedule {
+        strategy: FeeStrategy::Percentage(100),
+        limits: FeeLimits { min_fee: Some(50), max_fee: None },
+    };
+    client.set_corridor_fee_schedule(&admin, &ngn, &ng, &nigeria);
+    assert_eq!(client.get_corridor_fee_schedule(&ngn, &ng), Some(nigeria.clone()));
+
+    // The other token: flat 40
+    let flat = FeeSchedule {
+        strategy: FeeStrategy::Flat(40),
+        limits: FeeLimits::default(),
+    };
+    client.set_token_fee_schedule(&admin, &other_token.address, &flat);
+
+    // Corridor schedule wins over token and global
+    let id1 = client.create_remittance(&sender, &agent, &10000, &token.address, &ngn, &ng, &None, &None);
+    assert_eq!(client.get_remittance(&id1).fee, 100);
+    let id2 = client.create_remittance(&sender, &agent, &1000, &other_token.address, &ngn, &ng, &None, &None);
+    assert_eq!(client.get_remittance(&id2).fee, 50);
+
+    // Mexico has no corridor schedule: token schedule, then global
+    let id3 = client.create_remittance(&sender, &agent, &10000, &other_token.address, &mxn, &mx, &None, &None);
+    assert_eq!(client.get_remittance(&id3).fee, 40);
+    let id4 = client.create_remittance(&sender, &agent, &10000, &token.address, &mxn, &mx, &None, &None);
+    assert_eq!(client.get_remittance(&id4).fee, 250);
+    assert_eq!(
+        client.get_effective_fee_schedule(&token.address, &mxn, &mx),
+        FeeSchedule { strategy: FeeStrategy::Percentage(250), limits: FeeLimits::default() }
+    );
+
+    // Removing the corridor schedule falls back again
+    client.remove_corridor_fee_schedule(&admin, &ngn, &ng);
+    assert_eq!(client.get_effective_fee_schedule(&other_token.address, &ngn, &ng), flat);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #87)")]
+fn test_fee_limits_reject_max_below_min() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let (token, _) = create_token_contract(&env, &admin);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    let client = SwiftRemitContractClient::new(&env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
+
+    client.set_fee_limits(&admin, &FeeLimits { min_fee: Some(100), max_fee: Some(50) });
+}
+
 #[test]
 fn test_strategy_switch_without_redeployment() {
     let env = Env::default();
@@ -133,23 +402,25 @@ fn test_strategy_switch_without_redeployment() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    client.whitelist_token(&admin, &token.address);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
     client.register_agent(&agent);
 
     // Start with percentage
     client.update_fee_strategy(&admin, &FeeStrategy::Percentage(250));
-    let id1 = client.create_remittance(&sender, &agent, &10000, &None);
+    let id1 = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id1).fee, 250); // 2.5%
 
     // Switch to flat
     client.update_fee_strategy(&admin, &FeeStrategy::Flat(150));
-    let id2 = client.create_remittance(&sender, &agent, &10000, &None);
+    let id2 = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id2).fee, 150);
 
     // Switch to dynamic
     client.update_fee_strategy(&admin, &FeeStrategy::Dynamic(400));
-    let id3 = client.create_remittance(&sender, &agent, &15000, &None);
+    let id3 = client.create_remittance(&sender, &agent, &15000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id3).fee, 150); // 1% of 15000
 }
 
@@ -166,7 +437,9 @@ fn test_get_fee_strategy() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    client.whitelist_token(&admin, &token.address);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
 
     // Default should be Percentage(250)
@@ -194,14 +467,16 @@ fn test_backwards_compatibility() {
     let contract_id = env.register_contract(None, SwiftRemitContract);
     let client = SwiftRemitContractClient::new(&env, &contract_id);
 
-    client.whitelist_token(&admin, &token.address);
-    
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+
     // Initialize with old fee_bps parameter (250 = 2.5%)
     client.initialize(&admin, &token.address, &250, &0, &0, &treasury);
     client.register_agent(&agent);
 
     // Should default to Percentage strategy with 2.5%
-    let id = client.create_remittance(&sender, &agent, &10000, &None);
+    let id = client.create_remittance(&sender, &agent, &10000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
     assert_eq!(client.get_remittance(&id).fee, 250);
 
     // Old update_fee should still work (updates percentage strategy)
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+//! Contract setup shared by the test modules.
+
+use crate::{Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+/// Ledger timestamp the fixture starts at
+pub const START: u64 = 1_000_000;
+
+/// Amount of each token minted to the sender
+pub const SENDER_BALANCE: i128 = 100000;
+
+pub struct Setup<'a> {
+    pub client: SwiftRemitContractClient<'a>,
+    pub token: token::Client<'a>,
+    pub admin: Address,
+    pub officer: Address,
+    pub arbitrator: Address,
+    pub sender: Address,
+    pub agent: Address,
+}
+
+/// Initializes the contract with a 2.5% fee in a whitelisted token held by
+/// the sender, one registered settler agent, a compliance officer and an
+/// arbitrator, at timestamp `START`.
+pub fn setup<'a>(env: &Env) -> Setup<'a> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = START);
+
+    let admin = Address::generate(env);
+    let officer = Address::generate(env);
+    let arbitrator = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    token::StellarAssetClient::new(env, &token_address).mint(&sender, &SENDER_BALANCE);
+
+    // The contract must already accept its initial token when initialized
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(env, &token_address, true);
+    });
+
+    let client = SwiftRemitContractClient::new(env, &contract_id);
+    client.initialize(&admin, &token_address, &250, &0, &0, &admin);
+    client.register_agent(&agent);
+    client.assign_role(&admin, &agent, &Role::Settler);
+    client.assign_role(&admin, &officer, &Role::ComplianceOfficer);
+    client.assign_role(&admin, &arbitrator, &Role::Arbitrator);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        admin,
+        officer,
+        arbitrator,
+        sender,
+        agent,
+    }
+}
+
+/// Registers another settler agent
+pub fn add_agent(env: &Env, s: &Setup) -> Address {
+    let agent = Address::generate(env);
+    s.client.register_agent(&agent);
+    s.client.assign_role(&s.admin, &agent, &Role::Settler);
+    agent
+}
+
+/// Whitelists another token and funds the sender with it
+pub fn add_token<'a>(env: &Env, s: &Setup) -> token::Client<'a> {
+    let token_address = env.register_stellar_asset_contract_v2(s.admin.clone()).address();
+    token::StellarAssetClient::new(env, &token_address).mint(&s.sender, &SENDER_BALANCE);
+    s.client.whitelist_token(&s.admin, &token_address);
+    token::Client::new(env, &token_address)
+}
+
+fn remit(env: &Env, s: &Setup, agent: &Address, token: &Address, amount: i128, expiry: Option<u64>) -> u64 {
+    s.client.create_remittance(
+        &s.sender,
+        agent,
+        &amount,
+        token,
+        &String::from_str(env, "NGN"),
+        &String::from_str(env, "NG"),
+        &expiry,
+        &None,
+    )
+}
+
+/// Sends `amount` of `token` from the sender to `agent` in the NGN/NG corridor
+pub fn send_token(env: &Env, s: &Setup, agent: &Address, token: &Address, amount: i128) -> u64 {
+    remit(env, s, agent, token, amount, None)
+}
+
+/// Sends `amount` of the fixture token from the sender to `agent` in the NGN/NG corridor
+pub fn send_to(env: &Env, s: &Setup, agent: &Address, amount: i128) -> u64 {
+    remit(env, s, agent, &s.token.address, amount, None)
+}
+
+/// Sends `amount` of the fixture token from the sender to the fixture agent in the NGN/NG corridor
+pub fn send(env: &Env, s: &Setup, amount: i128) -> u64 {
+    remit(env, s, &s.agent, &s.token.address, amount, None)
+}
+
+/// Like `send`, but the remittance expires at `expiry`
+pub fn send_expiring(env: &Env, s: &Setup, amount: i128, expiry: u64) -> u64 {
+    remit(env, s, &s.agent, &s.token.address, amount, Some(expiry))
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{self, START};
+use crate::{Role, SwiftRemitContractClient, DEFAULT_MAX_RATE_AGE, FX_RATE_SCALE};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.admin)
+}
+
+fn feeder(env: &Env, client: &SwiftRemitContractClient, admin: &Address) -> Address {
+    let feeder = Address::generate(env);
+    client.assign_role(admin, &feeder, &Role::RateFeeder);
+    feeder
+}
+
+fn post(env: &Env, client: &SwiftRemitContractClient, feeder: &Address, naira: i128) {
+    client.post_fx_rate(
+        feeder,
+        &String::from_str(env, "USD"),
+        &String::from_str(env, "NGN"),
+        &(naira * FX_RATE_SCALE),
+        &env.ledger().timestamp(),
+    );
+}
+
+fn usd_ngn(env: &Env, client: &SwiftRemitContractClient) -> crate::FxRate {
+    client.get_fx_rate(&String::from_str(env, "USD"), &String::from_str(env, "NGN"))
+}
+
+#[test]
+fn test_rate_is_median_of_feeders() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    post(&env, &client, &feeder(&env, &client, &admin), 1500);
+    post(&env, &client, &feeder(&env, &client, &admin), 1520);
+    post(&env, &client, &feeder(&env, &client, &admin), 9000);
+
+    let rate = usd_ngn(&env, &client);
+    assert_eq!(rate.rate, 1520 * FX_RATE_SCALE);
+    assert_eq!(rate.sources, 3);
+    assert_eq!(rate.oldest_timestamp, START);
+}
+
+#[test]
+fn test_feeder_repost_replaces_previous_submission() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let feeder = feeder(&env, &client, &admin);
+
+    post(&env, &client, &feeder, 1500);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    post(&env, &client, &feeder, 1510);
+
+    let rate = usd_ngn(&env, &client);
+    assert_eq!(rate.rate, 1510 * FX_RATE_SCALE);
+    assert_eq!(rate.sources, 1);
+}
+
+#[test]
+fn test_stale_submissions_excluded_from_median() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let slow = feeder(&env, &client, &admin);
+    let fast = feeder(&env, &client, &admin);
+
+    post(&env, &client, &slow, 1400);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_MAX_RATE_AGE + 1);
+    post(&env, &client, &fast, 1500);
+
+    let rate = usd_ngn(&env, &client);
+    assert_eq!(rate.rate, 1500 * FX_RATE_SCALE);
+    assert_eq!(rate.sources, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #67)")]
+fn test_all_stale_rates_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    post(&env, &client, &feeder(&env, &client, &admin), 1500);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_MAX_RATE_AGE + 1);
+
+    usd_ngn(&env, &client);
+}
+
+#[test]
+fn test_max_rate_age_is_configurable() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    post(&env, &client, &feeder(&env, &client, &admin), 1500);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_MAX_RATE_AGE + 1);
+    client.update_max_rate_age(&admin, &(2 * DEFAULT_MAX_RATE_AGE));
+
+    assert_eq!(client.get_max_rate_age(), 2 * DEFAULT_MAX_RATE_AGE);
+    assert_eq!(usd_ngn(&env, &client).rate, 1500 * FX_RATE_SCALE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #92)")]
+fn test_median_needs_minimum_sources() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.update_min_rate_sources(&admin, &3);
+
+    post(&env, &client, &feeder(&env, &client, &admin), 1500);
+    post(&env, &client, &feeder(&env, &client, &admin), 1510);
+    post(&env, &client, &feeder(&env, &client, &admin), 1520);
+    assert_eq!(usd_ngn(&env, &client).sources, 3);
+
+    // One source aging out leaves too few for a rate
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    let late = feeder(&env, &client, &admin);
+    post(&env, &client, &late, 1500);
+    client.update_max_rate_age(&admin, &30);
+    usd_ngn(&env, &client);
+}
+
+#[test]
+fn test_revoked_feeder_excluded_from_median() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let honest = feeder(&env, &client, &admin);
+    let revoked = feeder(&env, &client, &admin);
+
+    post(&env, &client, &honest, 1500);
+    post(&env, &client, &revoked, 9000);
+    client.remove_role(&admin, &revoked, &Role::RateFeeder);
+
+    let rate = usd_ngn(&env, &client);
+    assert_eq!(rate.rate, 1500 * FX_RATE_SCALE);
+    assert_eq!(rate.sources, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_non_feeder_cannot_post() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    post(&env, &client, &Address::generate(&env), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #68)")]
+fn test_future_timestamp_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.post_fx_rate(
+        &feeder(&env, &client, &admin),
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "NGN"),
+        &(1500 * FX_RATE_SCALE),
+        &(START + 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #66)")]
+fn test_unknown_pair_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.get_fx_rate(&String::from_str(&env, "USD"), &String::from_str(&env, "PHP"));
+}
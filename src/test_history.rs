@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{RemittanceStatus, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    token::StellarAssetClient::new(env, &s.token.address).mint(&s.sender, &900000);
+    (s.client, s.token.address, s.admin, s.sender, s.agent)
+}
+
+fn create(env: &Env, client: &SwiftRemitContractClient, token: &Address, sender: &Address, agent: &Address) -> u64 {
+    client.create_remittance(
+        sender,
+        agent,
+        &1000,
+        token,
+        &String::from_str(env, "USD"),
+        &String::from_str(env, "US"),
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_sender_history_pages() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    for _ in 0..5 {
+        create(&env, &client, &token, &sender, &agent);
+    }
+
+    let first = client.get_remittances_by_sender(&sender, &1, &2);
+    assert_eq!(first.page, 1);
+    assert_eq!(first.total_pages, 3);
+    assert_eq!(first.total_records, 5);
+    assert_eq!(first.remittances.len(), 2);
+    assert_eq!(first.remittances.get_unchecked(0).id, 1);
+    assert_eq!(first.remittances.get_unchecked(1).id, 2);
+
+    let last = client.get_remittances_by_sender(&sender, &3, &2);
+    assert_eq!(last.remittances.len(), 1);
+    assert_eq!(last.remittances.get_unchecked(0).id, 5);
+
+    let past_end = client.get_remittances_by_sender(&sender, &4, &2);
+    assert_eq!(past_end.remittances.len(), 0);
+    assert_eq!(past_end.total_records, 5);
+}
+
+#[test]
+fn test_agent_history_is_per_agent() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let other_agent = Address::generate(&env);
+    client.register_agent(&other_agent);
+
+    create(&env, &client, &token, &sender, &agent);
+    let other_id = create(&env, &client, &token, &sender, &other_agent);
+
+    let page = client.get_remittances_by_agent(&other_agent, &1, &10);
+    assert_eq!(page.total_records, 1);
+    assert_eq!(page.remittances.get_unchecked(0).id, other_id);
+    assert_eq!(client.get_remittances_by_sender(&sender, &1, &10).total_records, 2);
+}
+
+#[test]
+fn test_status_index_follows_lifecycle() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let completed_id = create(&env, &client, &token, &sender, &agent);
+    let cancelled_id = create(&env, &client, &token, &sender, &agent);
+    let pending_id = create(&env, &client, &token, &sender, &agent);
+
+    client.confirm_payout(&completed_id);
+    client.cancel_remittance(&cancelled_id);
+
+    let pending = client.get_remittances_by_status(&RemittanceStatus::Pending, &1, &10);
+    assert_eq!(pending.total_records, 1);
+    assert_eq!(pending.remittances.get_unchecked(0).id, pending_id);
+
+    let completed = client.get_remittances_by_status(&RemittanceStatus::Completed, &1, &10);
+    assert_eq!(completed.total_records, 1);
+    assert_eq!(completed.remittances.get_unchecked(0).id, completed_id);
+
+    let cancelled = client.get_remittances_by_status(&RemittanceStatus::Cancelled, &1, &10);
+    assert_eq!(cancelled.total_records, 1);
+    assert_eq!(cancelled.remittances.get_unchecked(0).id, cancelled_id);
+}
+
+#[test]
+fn test_page_size_is_capped() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    for _ in 0..(crate::storage::MAX_PAGE_SIZE + 1) {
+        create(&env, &client, &token, &sender, &agent);
+    }
+
+    let page = client.get_remittances_by_sender(&sender, &1, &u32::MAX);
+    assert_eq!(page.remittances.len(), crate::storage::MAX_PAGE_SIZE);
+    assert_eq!(page.total_pages, 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")]
+fn test_page_zero_rejected() {
+    let env = Env::default();
+    let (client, _token, _admin, sender, _agent) = setup(&env);
+
+    client.get_remittances_by_sender(&sender, &0, &10);
+}
+
+#[test]
+fn test_imported_remittances_are_indexed() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    create(&env, &client, &token, &sender, &agent);
+    create(&env, &client, &token, &sender, &agent);
+
+    let snapshot = env.as_contract(&client.address, || crate::export_state(&env).unwrap());
+
+    let target_id = env.register_contract(None, SwiftRemitContract);
+    env.as_contract(&target_id, || crate::import_state(&env, snapshot).unwrap());
+    let target = SwiftRemitContractClient::new(&env, &target_id);
+
+    assert_eq!(target.get_remittances_by_sender(&sender, &1, &10).total_records, 2);
+    assert_eq!(target.get_remittances_by_agent(&agent, &1, &10).total_records, 2);
+    let pending = target.get_remittances_by_status(&RemittanceStatus::Pending, &1, &10);
+    assert_eq!(pending.total_records, 2);
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{RemittanceStatus, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Events, Ledger},
+    token, Address, Bytes, BytesN, Env, IntoVal, String, Val, Vec,
+};
+
+const TIMELOCK: u64 = 10_000;
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    (s.client, s.token, s.admin, s.sender, s.agent)
+}
+
+fn preimage(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"anchor-swap-secret")
+}
+
+fn create_htlc(env: &Env, client: &SwiftRemitContractClient, token: &Address, sender: &Address, agent: &Address) -> u64 {
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage(env)).into();
+    client.create_htlc_remittance(
+        sender,
+        agent,
+        &1000,
+        token,
+        &String::from_str(env, "NGN"),
+        &String::from_str(env, "NG"),
+        &hashlock,
+        &TIMELOCK,
+    )
+}
+
+#[test]
+fn test_settle_with_preimage_reveals_it() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    client.settle_htlc(&id, &preimage(&env));
+
+    assert_eq!(client.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let data: Vec<Val> = data.into_val(&env);
+    let revealed: Bytes = data.get(5).unwrap().into_val(&env);
+    assert_eq!(revealed, preimage(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #55)")]
+fn test_wrong_preimage_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    client.settle_htlc(&id, &Bytes::from_slice(&env, b"guess"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_settle_after_timelock_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMELOCK + 1);
+    client.settle_htlc(&id, &preimage(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #56)")]
+fn test_confirm_payout_requires_preimage() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    client.confirm_payout(&id);
+}
+
+#[test]
+fn test_refund_after_timelock() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMELOCK + 1);
+    client.refund_htlc(&id);
+
+    assert_eq!(client.get_remittance(&id).status, RemittanceStatus::Expired);
+    assert_eq!(token.balance(&sender), 100000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #58)")]
+fn test_refund_before_timelock_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMELOCK);
+    client.refund_htlc(&id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #58)")]
+fn test_sender_cannot_cancel_before_timelock() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let id = create_htlc(&env, &client, &token.address, &sender, &agent);
+
+    client.cancel_remittance(&id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #57)")]
+fn test_past_timelock_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage(&env)).into();
+
+    client.create_htlc_remittance(
+        &sender,
+        &agent,
+        &1000,
+        &token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &hashlock,
+        &1_000,
+    );
+}
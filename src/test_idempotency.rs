@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::SwiftRemitContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.token, s.admin, s.sender, s.agent)
+}
+
+fn default_currency(env: &Env) -> String {
+    String::from_str(env, "USD")
+}
+
+fn default_country(env: &Env) -> String {
+    String::from_str(env, "US")
+}
+
+#[test]
+fn test_retry_returns_original_remittance() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let key = String::from_str(&env, "order-42");
+
+    let id1 = client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+    let id2 = client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+
+    assert_eq!(id1, id2);
+    assert_eq!(token.balance(&sender), 99000);
+    assert_eq!(token.balance(&client.address), 1000);
+
+    let record = client.get_idempotency_record(&sender, &key).unwrap();
+    assert_eq!(record.sender, sender);
+    assert_eq!(record.remittance_id, id1);
+    assert_eq!(record.expires_at, env.ledger().timestamp() + 86400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+fn test_retry_with_different_payload_conflicts() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let key = String::from_str(&env, "order-42");
+
+    client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+    client.create_remittance_idempotent(&sender, &agent, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+}
+
+#[test]
+fn test_expired_key_creates_new_remittance() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let key = String::from_str(&env, "order-42");
+
+    client.update_idempotency_ttl(&admin, &60);
+    let id1 = client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+
+    env.ledger().with_mut(|li| li.timestamp += 61);
+    let id2 = client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+
+    assert_ne!(id1, id2);
+    assert_eq!(token.balance(&sender), 98000);
+    assert_eq!(client.get_idempotency_record(&sender, &key).unwrap().remittance_id, id2);
+}
+
+#[test]
+fn test_plain_create_stores_no_record() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+
+    assert!(client.get_idempotency_record(&sender, &String::from_str(&env, "order-42")).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_empty_key_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &String::from_str(&env, ""));
+}
+
+#[test]
+fn test_keys_are_scoped_per_sender() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let other_sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token.address).mint(&other_sender, &100000);
+    let key = String::from_str(&env, "order-42");
+
+    let id1 = client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+    let id2 = client.create_remittance_idempotent(&other_sender, &agent, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &key);
+
+    assert_ne!(id1, id2);
+    assert_eq!(client.get_remittance(&id2).sender, other_sender);
+    assert_eq!(client.get_idempotency_record(&sender, &key).unwrap().remittance_id, id1);
+    assert_eq!(client.get_idempotency_record(&other_sender, &key).unwrap().remittance_id, id2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_key_with_invalid_characters_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    client.create_remittance_idempotent(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &String::from_str(&env, "order 42/retry"));
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{self, Setup};
+use crate::{Integrator, IntegratorCharge};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, FromVal, String, Symbol, TryFromVal, Val, Vec,
+};
+
+/// Fixture with a registered integrator (partner) charging 1%, paid out to its payee
+fn setup<'a>(env: &Env) -> (Setup<'a>, Address, Address) {
+    let s = test_fixtures::setup(env);
+    let partner = Address::generate(env);
+    let payee = Address::generate(env);
+    s.client.register_integrator(&s.admin, &partner, &100, &payee);
+    (s, partner, payee)
+}
+
+fn send(env: &Env, s: &Setup, amount: i128, integrator: Option<Address>) -> u64 {
+    s.client.create_remittance(
+        &s.sender,
+        &s.agent,
+        &amount,
+        &s.token.address,
+        &String::from_str(env, "NGN"),
+        &String::from_str(env, "NG"),
+        &None,
+        &integrator,
+    )
+}
+
+/// Data of the `remit created` events published so far.
+fn created_events(env: &Env) -> Vec<Vec<Val>> {
+    let mut found = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        if Symbol::from_val(env, &topics.get(0).unwrap()) == symbol_short!("remit")
+            && Symbol::from_val(env, &topics.get(1).unwrap()) == symbol_short!("created")
+        {
+            found.push_back(Vec::<Val>::try_from_val(env, &data).unwrap());
+        }
+    }
+    found
+}
+
+#[test]
+fn test_integrator_fee_charged_on_top() {
+    let env = Env::default();
+    let (s, partner, _) = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000, Some(partner.clone()));
+
+    assert_eq!(s.token.balance(&s.sender), 100000 - 1010);
+    assert_eq!(s.client.get_remittance(&remittance_id).amount, 1000);
+    assert_eq!(
+        s.client.get_integrator_charge(&remittance_id),
+        Some(IntegratorCharge {
+            integrator: partner.clone(),
+            fee: 10,
+        })
+    );
+    // Held until the remittance settles
+    assert_eq!(s.client.get_integrator_fees(&partner, &s.token.address), 0);
+}
+
+#[test]
+fn test_integrator_fee_accrues_on_settlement_and_is_withdrawn() {
+    let env = Env::default();
+    let (s, partner, payee) = setup(&env);
+
+    s.client.confirm_payout(&send(&env, &s, 1000, Some(partner.clone())));
+    s.client.confirm_payout(&send(&env, &s, 2000, Some(partner.clone())));
+
+    assert_eq!(s.token.balance(&s.agent), 975 + 1950);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 75);
+    assert_eq!(s.client.get_integrator_fees(&partner, &s.token.address), 30);
+
+    assert_eq!(s.client.withdraw_integrator_fees(&partner, &s.token.address), 30);
+    assert_eq!(s.token.balance(&payee), 30);
+    assert_eq!(s.client.get_integrator_fees(&partner, &s.token.address), 0);
+    assert_eq!(s.token.balance(&s.client.address), 75);
+}
+
+#[test]
+fn test_integrator_fee_refunded_on_cancel() {
+    let env = Env::default();
+    let (s, partner, _) = setup(&env);
+
+    s.client.cancel_remittance(&send(&env, &s, 1000, Some(partner.clone())));
+
+    assert_eq!(s.token.balance(&s.sender), 100000);
+    assert_eq!(s.client.get_integrator_fees(&partner, &s.token.address), 0);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_remittance_without_integrator_pays_no_integrator_fee() {
+    let env = Env::default();
+    let (s, _, _) = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000, None);
+
+    assert_eq!(s.token.balance(&s.sender), 100000 - 1000);
+    assert_eq!(s.client.get_integrator_charge(&remittance_id), None);
+}
+
+#[test]
+fn test_removed_integrator_keeps_earned_fees() {
+    let env = Env::default();
+    let (s, partner, payee) = setup(&env);
+    assert_eq!(
+        s.client.get_integrator(&partner),
+        Some(Integrator {
+            fee_bps: 100,
+            withdrawal_address: payee.clone(),
+        })
+    );
+
+    let remittance_id = send(&env, &s, 1000, Some(partner.clone()));
+    s.client.remove_integrator(&s.admin, &partner);
+    assert_eq!(s.client.get_integrator(&partner), None);
+
+    s.client.confirm_payout(&remittance_id);
+    assert_eq!(s.client.withdraw_integrator_fees(&partner, &s.token.address), 10);
+    assert_eq!(s.token.balance(&payee), 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #85)")]
+fn test_unregistered_integrator_rejected() {
+    let env = Env::default();
+    let (s, _, _) = setup(&env);
+
+    send(&env, &s, 1000, Some(Address::generate(&env)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_withdraw_without_fees_rejected() {
+    let env = Env::default();
+    let (s, partner, _) = setup(&env);
+
+    s.client.withdraw_integrator_fees(&partner, &s.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_integrator_fee_above_limit_rejected() {
+    let env = Env::default();
+    let (s, partner, payee) = setup(&env);
+
+    s.client.register_integrator(&s.admin, &partner, &10001, &payee);
+}
+
+#[test]
+fn test_created_event_emitted_once_with_integrator_fee() {
+    let env = Env::default();
+    let (s, partner, _) = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000, Some(partner.clone()));
+
+    let events = created_events(&env);
+    assert_eq!(events.len(), 1);
+    let data = events.get_unchecked(0);
+    assert_eq!(u64::from_val(&env, &data.get_unchecked(3)), remittance_id);
+    assert_eq!(i128::from_val(&env, &data.get_unchecked(7)), 25);
+    assert_eq!(i128::from_val(&env, &data.get_unchecked(8)), 10);
+}
+
+#[test]
+fn test_created_event_emitted_by_every_creation_path() {
+    let env = Env::default();
+    let (s, _, _) = setup(&env);
+
+    s.client.create_remittance_idempotent(
+        &s.sender,
+        &s.agent,
+        &1000,
+        &s.token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &None,
+        &String::from_str(&env, "order-1"),
+    );
+    assert_eq!(created_events(&env).len(), 1);
+
+    s.client.create_open_remittance(
+        &s.sender,
+        &1000,
+        &s.token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &soroban_sdk::BytesN::from_array(&env, &[1u8; 32]),
+        &None,
+    );
+    assert_eq!(created_events(&env).len(), 2);
+}
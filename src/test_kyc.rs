@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{self, add_token, send, send_token, Setup, START};
+use crate::{KycTier, DAILY_LIMIT_WINDOW};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn setup<'a>(env: &Env) -> Setup<'a> {
+    let s = test_fixtures::setup(env);
+
+    // Unverified senders: 500 per transfer, 1000 per day, 2000 per month
+    s.client.set_kyc_limits(&s.officer, &KycTier::None, &s.token.address, &500, &1000, &2000);
+    s.client.set_kyc_limits(&s.officer, &KycTier::Full, &s.token.address, &5000, &10000, &50000);
+    s
+}
+
+fn verify(env: &Env, s: &Setup, tier: KycTier, expiry: u64) {
+    s.client.set_kyc_record(&s.officer, &s.sender, &tier, &expiry, &String::from_str(env, "NG"));
+}
+
+#[test]
+fn test_headroom_tracks_usage() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 400);
+    let headroom = s.client.get_kyc_headroom(&s.sender, &s.token.address).unwrap();
+
+    assert_eq!(headroom.tier, KycTier::None);
+    assert_eq!(headroom.per_transaction, 500);
+    assert_eq!(headroom.daily_remaining, 600);
+    assert_eq!(headroom.monthly_remaining, 1600);
+}
+
+#[test]
+fn test_unconfigured_tier_is_unlimited() {
+    let env = Env::default();
+    let s = setup(&env);
+    verify(&env, &s, KycTier::Business, START + 1000);
+
+    assert_eq!(s.client.get_kyc_headroom(&s.sender, &s.token.address), None);
+    send(&env, &s, 50000);
+}
+
+#[test]
+fn test_verified_tier_raises_caps() {
+    let env = Env::default();
+    let s = setup(&env);
+    verify(&env, &s, KycTier::Full, START + 1000);
+
+    send(&env, &s, 5000);
+
+    let record = s.client.get_kyc_record(&s.sender).unwrap();
+    assert_eq!(record.tier, KycTier::Full);
+    assert_eq!(record.officer, s.officer);
+    assert_eq!(s.client.get_kyc_headroom(&s.sender, &s.token.address).unwrap().daily_remaining, 5000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #69)")]
+fn test_expired_record_falls_back_to_none_tier() {
+    let env = Env::default();
+    let s = setup(&env);
+    verify(&env, &s, KycTier::Full, START + 1000);
+
+    env.ledger().with_mut(|li| li.timestamp = START + 1001);
+    assert_eq!(s.client.get_kyc_tier(&s.sender), KycTier::None);
+
+    send(&env, &s, 5000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #69)")]
+fn test_per_transaction_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 501);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+fn test_daily_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 500);
+    send(&env, &s, 500);
+    send(&env, &s, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn test_monthly_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 500);
+    send(&env, &s, 500);
+    env.ledger().with_mut(|li| li.timestamp += DAILY_LIMIT_WINDOW);
+    send(&env, &s, 500);
+    send(&env, &s, 500);
+    env.ledger().with_mut(|li| li.timestamp += DAILY_LIMIT_WINDOW);
+    send(&env, &s, 1);
+}
+
+#[test]
+fn test_caps_expire_with_their_buckets() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    send(&env, &s, 500);
+    send(&env, &s, 500);
+    env.ledger().with_mut(|li| li.timestamp += DAILY_LIMIT_WINDOW);
+    send(&env, &s, 500);
+    assert_eq!(s.client.get_kyc_headroom(&s.sender, &s.token.address).unwrap().monthly_remaining, 500);
+
+    // The first day's buckets leave the monthly window after 30 days
+    env.ledger().with_mut(|li| li.timestamp += 29 * DAILY_LIMIT_WINDOW);
+    let headroom = s.client.get_kyc_headroom(&s.sender, &s.token.address).unwrap();
+    assert_eq!(headroom.daily_remaining, 1000);
+    assert_eq!(headroom.monthly_remaining, 1500);
+}
+
+#[test]
+fn test_caps_are_per_token() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let other = add_token(&env, &s).address;
+
+    // Usage in one token does not count against caps in another
+    send(&env, &s, 500);
+    send(&env, &s, 500);
+    assert_eq!(s.client.get_kyc_headroom(&s.sender, &other), None);
+    send_token(&env, &s, &s.agent, &other, 5000);
+
+    s.client.set_kyc_limits(&s.officer, &KycTier::None, &other, &50, &100, &200);
+    send_token(&env, &s, &s.agent, &other, 50);
+    let headroom = s.client.get_kyc_headroom(&s.sender, &other).unwrap();
+    assert_eq!(headroom.per_transaction, 50);
+    assert_eq!(headroom.daily_remaining, 50);
+    assert_eq!(s.client.get_kyc_headroom(&s.sender, &s.token.address).unwrap().daily_remaining, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #69)")]
+fn test_escrow_enforces_caps() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.create_escrow(&s.sender, &Address::generate(&env), &501, &s.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_only_compliance_officer_sets_records() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_kyc_record(&s.sender, &s.sender, &KycTier::Business, &(START + 1000), &String::from_str(&env, "NG"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+fn test_inconsistent_limits_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_kyc_limits(&s.officer, &KycTier::Basic, &s.token.address, &2000, &1000, &5000);
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{add_token, setup, Setup};
+use crate::{BatchSettlementEntry, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+fn create(env: &Env, s: &Setup, token: &Address, amount: i128) -> u64 {
+    s.client.create_remittance(
+        &s.sender,
+        &s.agent,
+        &amount,
+        token,
+        &String::from_str(env, "EUR"),
+        &String::from_str(env, "FR"),
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_fees_accumulate_per_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+
+    let usdc_id = create(&env, &s, &s.token.address, 1000);
+    let eurc_id = create(&env, &s, &eurc.address, 2000);
+    assert_eq!(s.client.get_remittance(&eurc_id).token, eurc.address);
+
+    s.client.confirm_payout(&usdc_id);
+    s.client.confirm_payout(&eurc_id);
+
+    assert_eq!(s.token.balance(&s.agent), 975);
+    assert_eq!(eurc.balance(&s.agent), 1950);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 25);
+    assert_eq!(s.client.get_accumulated_fees(&eurc.address), 50);
+}
+
+#[test]
+fn test_withdraw_fees_per_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+    let treasury = Address::generate(&env);
+
+    s.client.confirm_payout(&create(&env, &s, &s.token.address, 1000));
+    s.client.confirm_payout(&create(&env, &s, &eurc.address, 2000));
+
+    s.client.withdraw_fees(&treasury, &eurc.address);
+
+    assert_eq!(eurc.balance(&treasury), 50);
+    assert_eq!(s.token.balance(&treasury), 0);
+    assert_eq!(s.client.get_accumulated_fees(&eurc.address), 0);
+    assert_eq!(s.client.get_accumulated_fees(&s.token.address), 25);
+}
+
+#[test]
+fn test_cancel_refunds_in_remittance_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+
+    let id = create(&env, &s, &eurc.address, 2000);
+    assert_eq!(eurc.balance(&s.sender), 98000);
+
+    s.client.cancel_remittance(&id);
+
+    assert_eq!(eurc.balance(&s.sender), 100000);
+    assert_eq!(s.token.balance(&s.sender), 100000);
+}
+
+#[test]
+fn test_escrow_in_whitelisted_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+    let recipient = Address::generate(&env);
+
+    let transfer_id = s.client.create_escrow(&s.sender, &recipient, &500, &eurc.address);
+    s.client.release_escrow(&transfer_id);
+
+    assert_eq!(eurc.balance(&recipient), 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_non_whitelisted_token_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+
+    s.client.remove_whitelisted_token(&s.admin, &eurc.address);
+    create(&env, &s, &eurc.address, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #61)")]
+fn test_batch_rejects_mixed_tokens() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+
+    let usdc_id = create(&env, &s, &s.token.address, 1000);
+    let eurc_id = create(&env, &s, &eurc.address, 1000);
+
+    s.client.batch_settle_with_netting(
+        &vec![
+            &env,
+            BatchSettlementEntry { remittance_id: usdc_id },
+            BatchSettlementEntry { remittance_id: eurc_id },
+        ],
+        &s.token.address,
+    );
+}
+
+#[test]
+fn test_migration_carries_fees_for_every_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let eurc = add_token(&env, &s);
+
+    s.client.confirm_payout(&create(&env, &s, &s.token.address, 1000));
+    s.client.confirm_payout(&create(&env, &s, &eurc.address, 2000));
+    s.client.remove_whitelisted_token(&s.admin, &eurc.address);
+
+    let snapshot = env.as_contract(&s.client.address, || crate::export_state(&env).unwrap());
+
+    let target_id = env.register_contract(None, SwiftRemitContract);
+    env.as_contract(&target_id, || crate::import_state(&env, snapshot).unwrap());
+    let target = SwiftRemitContractClient::new(&env, &target_id);
+
+    assert_eq!(target.get_accumulated_fees(&s.token.address), 25);
+    assert_eq!(target.get_accumulated_fees(&eurc.address), 50);
+    assert!(target.is_token_whitelisted(&s.token.address));
+    assert!(!target.is_token_whitelisted(&eurc.address));
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{send, setup, START};
+use crate::RemittanceStatus;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, FromVal, Symbol, Val, Vec,
+};
+
+#[test]
+fn test_operator_settles_to_agent() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+    s.client.add_operator(&s.agent, &teller, &5000, &None);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+
+    assert_eq!(s.client.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(s.token.balance(&s.agent), 975);
+    assert_eq!(s.token.balance(&teller), 0);
+}
+
+#[test]
+fn test_operator_recorded_in_event() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+    s.client.add_operator(&s.agent, &teller, &5000, &None);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            Symbol::from_val(&env, &event.1.get(0).unwrap()) == symbol_short!("operator")
+                && Symbol::from_val(&env, &event.1.get(1).unwrap()) == symbol_short!("settled")
+        })
+        .unwrap();
+    let data: Vec<Val> = FromVal::from_val(&env, &event.2);
+    assert_eq!(u64::from_val(&env, &data.get(3).unwrap()), remittance_id);
+    assert_eq!(Address::from_val(&env, &data.get(4).unwrap()), s.agent);
+    assert_eq!(Address::from_val(&env, &data.get(5).unwrap()), teller);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #82)")]
+fn test_ungranted_operator_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #82)")]
+fn test_revoked_operator_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+    s.client.add_operator(&s.agent, &teller, &5000, &None);
+    s.client.revoke_operator(&s.agent, &teller);
+
+    assert_eq!(s.client.get_operator_grant(&s.agent, &teller), None);
+    let remittance_id = send(&env, &s, 1000);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #83)")]
+fn test_expired_grant_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+    s.client.add_operator(&s.agent, &teller, &5000, &Some(START + 100));
+
+    let remittance_id = send(&env, &s, 1000);
+    env.ledger().with_mut(|li| li.timestamp = START + 101);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #84)")]
+fn test_amount_above_grant_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+    s.client.add_operator(&s.agent, &teller, &999, &None);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.confirm_payout_as_operator(&teller, &remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #83)")]
+fn test_grant_with_past_expiry_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let teller = Address::generate(&env);
+
+    s.client.add_operator(&s.agent, &teller, &5000, &Some(START));
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{BatchSettlementEntry, ProofData, RemittanceStatus, SwiftRemitContractClient};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{token, vec, Address, BytesN, Env, String};
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let s = test_fixtures::setup(env);
+    (s.client, s.token, s.admin, s.sender, s.agent)
+}
+
+fn sign_settlement(
+    env: &Env,
+    client: &SwiftRemitContractClient,
+    key: &SigningKey,
+    remittance_id: u64,
+) -> ProofData {
+    let settlement_id = client.compute_settlement_hash(&remittance_id);
+    let signature = key.sign(&settlement_id.to_array()).to_bytes();
+    ProofData {
+        oracle: BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        signature: BytesN::from_array(env, &signature),
+    }
+}
+
+fn default_currency(env: &Env) -> String {
+    String::from_str(env, "USD")
+}
+
+fn default_country(env: &Env) -> String {
+    String::from_str(env, "US")
+}
+
+#[test]
+fn test_settlement_with_valid_proof() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let oracle = SigningKey::from_bytes(&[7u8; 32]);
+
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let proof = sign_settlement(&env, &client, &oracle, remittance_id);
+    client.register_oracle(&admin, &proof.oracle);
+    client.require_remittance_proof(&remittance_id);
+
+    client.confirm_payout_with_proof(&remittance_id, &proof);
+
+    assert_eq!(client.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_confirm_payout_missing_required_proof() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    client.set_agent_proof_required(&admin, &agent, &true);
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+
+    assert!(client.is_proof_required(&remittance_id));
+    client.confirm_payout(&remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_batch_settlement_missing_required_proof() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    client.require_remittance_proof(&remittance_id);
+
+    client.batch_settle_with_netting(&vec![&env, BatchSettlementEntry { remittance_id }], &token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #44)")]
+fn test_proof_from_unregistered_oracle_rejected() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+    let oracle = SigningKey::from_bytes(&[7u8; 32]);
+
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let proof = sign_settlement(&env, &client, &oracle, remittance_id);
+
+    client.confirm_payout_with_proof(&remittance_id, &proof);
+}
+
+#[test]
+#[should_panic]
+fn test_proof_for_other_remittance_rejected() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let oracle = SigningKey::from_bytes(&[7u8; 32]);
+
+    let first_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let second_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let proof = sign_settlement(&env, &client, &oracle, first_id);
+    client.register_oracle(&admin, &proof.oracle);
+
+    client.confirm_payout_with_proof(&second_id, &proof);
+}
+
+#[test]
+fn test_settlement_without_proof_requirement() {
+    let env = Env::default();
+    let (client, token, _admin, sender, agent) = setup(&env);
+
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    assert!(!client.is_proof_required(&remittance_id));
+
+    client.confirm_payout(&remittance_id);
+
+    assert_eq!(client.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+}
@@ -0,0 +1,312 @@
+#![cfg(test)]
+
+use crate::{
+    FeeLimits, FeeSchedule, FeeStrategy, ProofData, QuoteSigner, Role, SwiftRemitContract, SwiftRemitContractClient,
+    FX_RATE_SCALE,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+/// 1 source unit buys 1500 NGN
+const NGN_RATE: i128 = 1500 * FX_RATE_SCALE;
+const VALIDITY: u64 = 600;
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    token::StellarAssetClient::new(env, &token_address).mint(&sender, &100000);
+
+    let contract_id = env.register_contract(None, SwiftRemitContract);
+    env.as_contract(&contract_id, || {
+        crate::storage::set_token_whitelisted(env, &token_address, true);
+    });
+
+    let client = SwiftRemitContractClient::new(env, &contract_id);
+    // 2.5% platform fee, 1% protocol fee
+    client.initialize(&admin, &token_address, &250, &0, &100, &treasury);
+    client.register_agent(&agent);
+    client.assign_role(&admin, &agent, &Role::Settler);
+
+    // Feeders publish USD/NGN at NGN_RATE and the token is priced in USD
+    client.set_token_currency(&admin, &token_address, &String::from_str(env, "USD"));
+    let feeder = Address::generate(env);
+    client.assign_role(&admin, &feeder, &Role::RateFeeder);
+    client.post_fx_rate(&feeder, &String::from_str(env, "USD"), &ngn(env), &NGN_RATE, &env.ledger().timestamp());
+
+    (client, token::Client::new(env, &token_address), admin, sender, agent)
+}
+
+fn quote_at_rate(env: &Env, client: &SwiftRemitContractClient, token: &Address, admin: &Address, sender: &Address, fx_rate: i128) -> u64 {
+    client.create_quote(
+        &QuoteSigner::Admin(admin.clone()),
+        sender,
+        &10000,
+        token,
+        &ngn(env),
+        &ng(env),
+        &fx_rate,
+        &(env.ledger().timestamp() + VALIDITY),
+        &0,
+    )
+}
+
+fn ngn(env: &Env) -> String {
+    String::from_str(env, "NGN")
+}
+
+fn ng(env: &Env) -> String {
+    String::from_str(env, "NG")
+}
+
+fn admin_quote(env: &Env, client: &SwiftRemitContractClient, token: &Address, admin: &Address, sender: &Address) -> u64 {
+    quote_at_rate(env, client, token, admin, sender, NGN_RATE)
+}
+
+#[test]
+fn test_quote_locks_fee_breakdown_and_destination_amount() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    let quote = client.get_quote(&quote_id);
+
+    assert_eq!(quote.amount, 10000);
+    assert_eq!(quote.platform_fee, 250);
+    assert_eq!(quote.protocol_fee, 100);
+    assert_eq!(quote.payout_amount, 9650);
+    assert_eq!(quote.dest_currency, ngn(&env));
+    assert_eq!(quote.dest_amount, 14_475_000);
+    assert_eq!(quote.remittance_id, None);
+}
+
+#[test]
+fn test_remittance_from_quote_records_destination_amount() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    let remittance_id =
+        client.create_remittance_from_quote(&quote_id, &agent, &None);
+
+    let remittance = client.get_remittance(&remittance_id);
+    assert_eq!(remittance.sender, sender);
+    assert_eq!(remittance.amount, 10000);
+    assert_eq!(remittance.fee, 250);
+    assert_eq!(remittance.currency, ngn(&env));
+    assert_eq!(remittance.dest_amount, Some(14_475_000));
+    assert_eq!(client.get_quote(&quote_id).remittance_id, Some(remittance_id));
+    assert_eq!(token.balance(&sender), 90000);
+}
+
+#[test]
+fn test_quoted_fee_survives_fee_strategy_change() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    client.update_fee_strategy(&admin, &FeeStrategy::Flat(500));
+
+    let remittance_id =
+        client.create_remittance_from_quote(&quote_id, &agent, &None);
+    client.confirm_payout(&remittance_id);
+
+    assert_eq!(client.get_remittance(&remittance_id).fee, 250);
+    assert_eq!(token.balance(&agent), 9650);
+}
+
+#[test]
+fn test_quote_ids_independent_of_standing_orders() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let order_id = client.create_standing_order(
+        &sender,
+        &agent,
+        &1000,
+        &token.address,
+        &ngn(&env),
+        &String::from_str(&env, "NG"),
+        &86400,
+        &(env.ledger().timestamp() + 86400),
+        &3,
+        &None,
+    );
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+
+    assert_eq!(order_id, 1);
+    assert_eq!(quote_id, 1);
+}
+
+fn oracle_quote(env: &Env, client: &SwiftRemitContractClient, token: &Address, admin: &Address, sender: &Address) -> u64 {
+    let oracle = SigningKey::from_bytes(&[9u8; 32]);
+    let oracle_key = BytesN::from_array(env, &oracle.verifying_key().to_bytes());
+    if !client.is_oracle_registered(&oracle_key) {
+        client.register_oracle(admin, &oracle_key);
+    }
+
+    let valid_until = env.ledger().timestamp() + VALIDITY;
+    let quote_hash =
+        client.compute_quote_hash(sender, &10000, token, &ngn(env), &ng(env), &NGN_RATE, &valid_until, &7);
+    let signature = oracle.sign(&quote_hash.to_array()).to_bytes();
+    let proof = ProofData {
+        oracle: oracle_key,
+        signature: BytesN::from_array(env, &signature),
+    };
+
+    client.create_quote(
+        &QuoteSigner::Oracle(proof),
+        sender,
+        &10000,
+        token,
+        &ngn(env),
+        &ng(env),
+        &NGN_RATE,
+        &valid_until,
+        &7,
+    )
+}
+
+#[test]
+fn test_oracle_signed_quote() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+
+    let quote_id = oracle_quote(&env, &client, &token.address, &admin, &sender);
+
+    assert_eq!(client.get_quote(&quote_id).dest_amount, 14_475_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #64)")]
+fn test_oracle_signature_cannot_be_replayed() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+
+    oracle_quote(&env, &client, &token.address, &admin, &sender);
+    oracle_quote(&env, &client, &token.address, &admin, &sender);
+}
+
+#[test]
+fn test_quoted_protocol_fee_survives_rate_change() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    let remittance_id = client.create_remittance_from_quote(&quote_id, &agent, &None);
+    assert_eq!(client.get_remittance(&remittance_id).protocol_fee, Some(100));
+    env.as_contract(&client.address, || {
+        crate::storage::set_protocol_fee_bps(&env, 200).unwrap();
+    });
+
+    client.confirm_payout(&remittance_id);
+
+    assert_eq!(token.balance(&agent), 9650);
+}
+
+#[test]
+fn test_quote_applies_corridor_fee_schedule() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+    let schedule = FeeSchedule {
+        strategy: FeeStrategy::Flat(400),
+        limits: FeeLimits { min_fee: None, max_fee: None },
+    };
+    client.set_corridor_fee_schedule(&admin, &ngn(&env), &ng(&env), &schedule);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    let remittance_id = client.create_remittance_from_quote(&quote_id, &agent, &None);
+
+    assert_eq!(client.get_quote(&quote_id).platform_fee, 400);
+    assert_eq!(client.get_remittance(&remittance_id).country, ng(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_non_admin_cannot_issue_quote() {
+    let env = Env::default();
+    let (client, token, _admin, sender, _agent) = setup(&env);
+
+    admin_quote(&env, &client, &token.address, &sender, &sender);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #64)")]
+fn test_quote_cannot_be_used_twice() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    client.create_remittance_from_quote(&quote_id, &agent, &None);
+    client.create_remittance_from_quote(&quote_id, &agent, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #63)")]
+fn test_expired_quote_rejected() {
+    let env = Env::default();
+    let (client, token, admin, sender, agent) = setup(&env);
+
+    let quote_id = admin_quote(&env, &client, &token.address, &admin, &sender);
+    env.ledger().with_mut(|li| li.timestamp += VALIDITY + 1);
+
+    client.create_remittance_from_quote(&quote_id, &agent, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #65)")]
+fn test_non_positive_fx_rate_rejected() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+
+    client.create_quote(
+        &QuoteSigner::Admin(admin),
+        &sender,
+        &10000,
+        &token.address,
+        &ngn(&env),
+        &ng(&env),
+        &0,
+        &(env.ledger().timestamp() + VALIDITY),
+        &0,
+    );
+}
+
+#[test]
+fn test_rate_within_tolerance_of_median_accepted() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+
+    // Default tolerance is 1%
+    let quote_id = quote_at_rate(&env, &client, &token.address, &admin, &sender, NGN_RATE + NGN_RATE / 100);
+    assert_eq!(client.get_quote(&quote_id).fx_rate, 1515 * FX_RATE_SCALE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #93)")]
+fn test_rate_outside_tolerance_of_median_rejected() {
+    let env = Env::default();
+    let (client, token, admin, sender, _agent) = setup(&env);
+    client.update_quote_rate_tolerance(&admin, &50);
+
+    quote_at_rate(&env, &client, &token.address, &admin, &sender, NGN_RATE - NGN_RATE / 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #94)")]
+fn test_token_without_currency_cannot_be_quoted() {
+    let env = Env::default();
+    let (client, _token, admin, sender, _agent) = setup(&env);
+    let other = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    client.whitelist_token(&admin, &other);
+
+    admin_quote(&env, &client, &other, &admin, &sender);
+}
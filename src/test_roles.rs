@@ -1,7 +1,15 @@
 #![cfg(test)]
 
 use crate::{SwiftRemitContract, SwiftRemitContractClient, Role};
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn default_currency(env: &Env) -> String {
+    String::from_str(env, "USD")
+}
+
+fn default_country(env: &Env) -> String {
+    String::from_str(env, "US")
+}
 
 #[test]
 fn test_role_assignment_by_admin() {
@@ -72,7 +80,7 @@ fn test_confirm_payout_requires_settler_role() {
     client.register_agent(&agent);
 
     // Create remittance
-    let remittance_id = client.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Agent tries to confirm payout without Settler role - should panic
     client.confirm_payout(&remittance_id);
@@ -102,7 +110,7 @@ fn test_settler_can_finalize_transfers() {
     assert!(client.has_role(&agent, &Role::Settler));
 
     // Create remittance
-    let remittance_id = client.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = client.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Agent with Settler role can confirm payout
     client.confirm_payout(&remittance_id);
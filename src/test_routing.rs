@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{self, add_agent, add_token, send_to, Setup};
+use crate::{Corridor, PayoutMethod, RoutingPolicy};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+/// Fixture with three more agents serving the NGN/NG corridor
+fn setup<'a>(env: &Env) -> (Setup<'a>, [Address; 3]) {
+    let s = test_fixtures::setup(env);
+
+    let agents = [add_agent(env, &s), add_agent(env, &s), add_agent(env, &s)];
+    for agent in agents.iter() {
+        s.client.set_agent_profile(
+            &s.admin,
+            agent,
+            &vec![env, String::from_str(env, "NG")],
+            &vec![env, String::from_str(env, "NGN")],
+            &vec![env, PayoutMethod::Cash],
+            &None,
+        );
+    }
+
+    (s, agents)
+}
+
+fn corridor(env: &Env, currency: &str, country: &str) -> Corridor {
+    Corridor {
+        currency: String::from_str(env, currency),
+        country: String::from_str(env, country),
+    }
+}
+
+fn route(env: &Env, s: &Setup, amount: i128) -> Address {
+    s.client
+        .create_routed_remittance(&s.sender, &corridor(env, "NGN", "NG"), &amount, &s.token.address, &None)
+        .agent
+}
+
+#[test]
+fn test_round_robin_rotates_agents() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+
+    assert_eq!(route(&env, &s, 1000), agents[0]);
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+    assert_eq!(route(&env, &s, 1000), agents[2]);
+    assert_eq!(route(&env, &s, 1000), agents[0]);
+}
+
+#[test]
+fn test_routed_remittance_assigned_to_selected_agent() {
+    let env = Env::default();
+    let (s, _) = setup(&env);
+
+    let routed = s.client.create_routed_remittance(&s.sender, &corridor(&env, "NGN", "NG"), &1000, &s.token.address, &None);
+
+    let remittance = s.client.get_remittance(&routed.remittance_id);
+    assert_eq!(remittance.agent, routed.agent);
+    assert_eq!(remittance.token, s.token.address);
+    assert_eq!(s.token.balance(&s.client.address), 1000);
+}
+
+#[test]
+fn test_suspended_agent_skipped() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+
+    s.client.suspend_agent(&s.admin, &agents[0]);
+    assert!(s.client.is_agent_suspended(&agents[0]));
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+    assert_eq!(route(&env, &s, 1000), agents[2]);
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+
+    s.client.reinstate_agent(&s.admin, &agents[0]);
+    assert_eq!(route(&env, &s, 1000), agents[2]);
+    assert_eq!(route(&env, &s, 1000), agents[0]);
+}
+
+#[test]
+fn test_lowest_exposure_policy() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+    s.client.set_routing_policy(&s.admin, &RoutingPolicy::LowestExposure);
+
+    assert_eq!(route(&env, &s, 3000), agents[0]);
+    assert_eq!(route(&env, &s, 2000), agents[1]);
+    assert_eq!(route(&env, &s, 1000), agents[2]);
+    assert_eq!(route(&env, &s, 1000), agents[2]);
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+}
+
+#[test]
+fn test_best_score_policy() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+
+    // First agent settles one and loses one (50%), the second settles one (100%)
+    s.client.confirm_payout(&send_to(&env, &s, &agents[0], 1000));
+    s.client.cancel_remittance(&send_to(&env, &s, &agents[0], 1000));
+    s.client.confirm_payout(&send_to(&env, &s, &agents[1], 1000));
+
+    s.client.set_routing_policy(&s.admin, &RoutingPolicy::BestScore);
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+}
+
+#[test]
+fn test_agent_at_capacity_skipped() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+    s.client.set_routing_policy(&s.admin, &RoutingPolicy::LowestExposure);
+    s.client.set_agent_capacity(&s.admin, &agents[0], &s.token.address, &Some(500));
+
+    assert_eq!(route(&env, &s, 1000), agents[1]);
+}
+
+#[test]
+fn test_routed_remittance_in_another_token() {
+    let env = Env::default();
+    let (s, agents) = setup(&env);
+    let eurc = add_token(&env, &s);
+    s.client.set_routing_policy(&s.admin, &RoutingPolicy::LowestExposure);
+    s.client.set_agent_capacity(&s.admin, &agents[0], &s.token.address, &Some(500));
+
+    let routed = s.client.create_routed_remittance(&s.sender, &corridor(&env, "NGN", "NG"), &1000, &eurc.address, &None);
+
+    assert_eq!(routed.agent, agents[0]);
+    assert_eq!(s.client.get_remittance(&routed.remittance_id).token, eurc.address);
+    assert_eq!(eurc.balance(&s.client.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_routed_remittance_rejects_unlisted_token() {
+    let env = Env::default();
+    let (s, _) = setup(&env);
+
+    s.client.create_routed_remittance(&s.sender, &corridor(&env, "NGN", "NG"), &1000, &Address::generate(&env), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+fn test_unserved_corridor_has_no_eligible_agent() {
+    let env = Env::default();
+    let (s, _) = setup(&env);
+
+    s.client.create_routed_remittance(&s.sender, &corridor(&env, "GHS", "GH"), &1000, &s.token.address, &None);
+}
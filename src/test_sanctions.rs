@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use crate::test_fixtures::{send, send_expiring, setup, START};
+use crate::{RemittanceStatus, TransferState};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+const OFAC: u32 = 1;
+
+#[test]
+fn test_denylist_records_reason_and_timestamp() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+
+    let entry = s.client.get_denylist_entry(&s.sender).unwrap();
+    assert_eq!(entry.reason_code, OFAC);
+    assert_eq!(entry.listed_at, START);
+    assert_eq!(entry.officer, s.officer);
+    assert!(s.client.is_denylisted(&s.sender));
+
+    s.client.remove_from_denylist(&s.officer, &s.sender);
+    assert!(!s.client.is_denylisted(&s.sender));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+fn test_listed_sender_cannot_create_remittance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+    send(&env, &s, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_listed_agent_cannot_confirm_payout() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.add_to_denylist(&s.officer, &s.agent, &OFAC);
+
+    s.client.confirm_payout(&remittance_id);
+}
+
+#[test]
+fn test_listing_freezes_pending_remittances() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    let other_sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&other_sender, &1000);
+    let clean_id = s.client.create_remittance(
+        &other_sender,
+        &s.agent,
+        &1000,
+        &s.token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &None,
+        &None,
+    );
+
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+
+    assert_eq!(s.client.get_remittance(&remittance_id).status, RemittanceStatus::Frozen);
+    assert_eq!(s.client.get_transfer_state(&remittance_id), Some(TransferState::Frozen));
+    assert_eq!(s.client.get_remittance(&clean_id).status, RemittanceStatus::Pending);
+    assert_eq!(s.token.balance(&s.client.address), 2000);
+}
+
+#[test]
+fn test_freeze_sanctioned_remittances_covers_overflow() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let s = setup(&env);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&s.sender, &1000);
+
+    let oldest_id = send(&env, &s, 1000);
+    for _ in 0..100 {
+        send(&env, &s, 1000);
+    }
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+    assert_eq!(s.client.get_remittance(&oldest_id).status, RemittanceStatus::Pending);
+
+    let frozen = s.client.freeze_sanctioned_remittances(&vec![&env, oldest_id, oldest_id + 1]);
+
+    assert_eq!(frozen, vec![&env, oldest_id]);
+    assert_eq!(s.client.get_remittance(&oldest_id).status, RemittanceStatus::Frozen);
+}
+
+#[test]
+fn test_expiry_freezes_instead_of_refunding() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send_expiring(&env, &s, 1000, START + 100);
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+    env.ledger().with_mut(|li| li.timestamp = START + 101);
+
+    let result = s.client.sweep_expired(&0, &10);
+
+    assert_eq!(result.expired_ids.len(), 0);
+    assert_eq!(s.client.get_remittance(&remittance_id).status, RemittanceStatus::Frozen);
+    assert_eq!(s.token.balance(&s.sender), 99000);
+}
+
+#[test]
+fn test_release_after_delisting() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.add_to_denylist(&s.officer, &s.agent, &OFAC);
+
+    s.client.remove_from_denylist(&s.officer, &s.agent);
+    s.client.release_frozen_remittance(&s.officer, &remittance_id);
+    s.client.confirm_payout(&remittance_id);
+
+    assert_eq!(s.client.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(s.token.balance(&s.agent), 975);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+fn test_release_while_listed_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.add_to_denylist(&s.officer, &s.sender, &OFAC);
+
+    s.client.release_frozen_remittance(&s.officer, &remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_frozen_remittance_cannot_be_cancelled() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let remittance_id = send(&env, &s, 1000);
+    s.client.add_to_denylist(&s.officer, &s.agent, &OFAC);
+
+    s.client.cancel_remittance(&remittance_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+fn test_release_escrow_to_listed_recipient_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let transfer_id = s.client.create_escrow(&s.sender, &recipient, &500, &s.token.address);
+    s.client.add_to_denylist(&s.officer, &recipient, &OFAC);
+
+    s.client.release_escrow(&transfer_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+fn test_withdraw_fees_to_listed_address_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+
+    s.client.confirm_payout(&send(&env, &s, 1000));
+    s.client.add_to_denylist(&s.officer, &treasury, &OFAC);
+
+    s.client.withdraw_fees(&treasury, &s.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_only_compliance_officer_lists() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.add_to_denylist(&s.sender, &s.agent, &OFAC);
+}
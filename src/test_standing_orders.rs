@@ -0,0 +1,189 @@
+#![cfg(test)]
+
+use crate::test_fixtures;
+use crate::{StandingOrderStatus, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Ledger, token, vec, Address, Env, String};
+
+const DAY: u64 = 86400;
+
+fn setup<'a>(env: &Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let s = test_fixtures::setup(env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    (s.client, s.token, s.sender, s.agent)
+}
+
+fn create_order(
+    env: &Env,
+    client: &SwiftRemitContractClient,
+    token: &Address,
+    sender: &Address,
+    agent: &Address,
+    count: u32,
+    end_date: Option<u64>,
+) -> u64 {
+    client.create_standing_order(
+        sender,
+        agent,
+        &1000,
+        token,
+        &String::from_str(env, "NGN"),
+        &String::from_str(env, "NG"),
+        &(30 * DAY),
+        &2_000,
+        &count,
+        &end_date,
+    )
+}
+
+#[test]
+fn test_due_orders_execute_each_interval() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    token.approve(&sender, &client.address, &10000, &1000);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 2, None);
+
+    // Not due yet
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let created = client.execute_due_orders(&vec![&env, order_id]);
+    assert_eq!(created.len(), 1);
+    let remittance = client.get_remittance(&created.get(0).unwrap());
+    assert_eq!(remittance.agent, agent);
+    assert_eq!(remittance.fee, 25);
+    assert_eq!(token.balance(&sender), 99000);
+
+    // Same installment is not executed twice
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + 30 * DAY);
+    assert_eq!(client.execute_due_orders(&vec![&env, order_id]).len(), 1);
+
+    let order = client.get_standing_order(&order_id);
+    assert_eq!(order.remaining_count, 0);
+    assert_eq!(order.status, StandingOrderStatus::Completed);
+    assert_eq!(token.balance(&sender), 98000);
+}
+
+#[test]
+fn test_installment_without_allowance_is_skipped() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 3, None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+    assert_eq!(client.get_standing_order(&order_id).remaining_count, 3);
+
+    token.approve(&sender, &client.address, &1000, &1000);
+    assert_eq!(client.execute_due_orders(&vec![&env, order_id]).len(), 1);
+}
+
+#[test]
+fn test_resume_skips_missed_installments() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    token.approve(&sender, &client.address, &10000, &1000);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 3, None);
+
+    client.pause_standing_order(&order_id);
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + 65 * DAY);
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+
+    // Installments due at 2_000, +30 and +60 days fell due while paused
+    client.resume_standing_order(&order_id);
+    assert_eq!(client.get_standing_order(&order_id).next_due, 2_000 + 90 * DAY);
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + 90 * DAY);
+    assert_eq!(client.execute_due_orders(&vec![&env, order_id]).len(), 1);
+    assert_eq!(client.get_standing_order(&order_id).remaining_count, 2);
+}
+
+#[test]
+fn test_repeated_order_id_executes_once() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    token.approve(&sender, &client.address, &10000, &1000);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 3, None);
+
+    // Three installments are overdue, but one call executes only one of them
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + 65 * DAY);
+    let created = client.execute_due_orders(&vec![&env, order_id, order_id, order_id]);
+
+    assert_eq!(created.len(), 1);
+    assert_eq!(client.get_standing_order(&order_id).remaining_count, 2);
+    assert_eq!(token.balance(&sender), 99000);
+}
+
+#[test]
+fn test_cancelled_order_stops() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    token.approve(&sender, &client.address, &10000, &1000);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 3, None);
+
+    client.cancel_standing_order(&order_id);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+    assert_eq!(client.get_standing_order(&order_id).status, StandingOrderStatus::Cancelled);
+}
+
+#[test]
+fn test_order_completes_after_end_date() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    token.approve(&sender, &client.address, &10000, &1000);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 12, Some(2_000 + DAY));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.execute_due_orders(&vec![&env, order_id]).len(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + 30 * DAY);
+    assert!(client.execute_due_orders(&vec![&env, order_id]).is_empty());
+    assert_eq!(client.get_standing_order(&order_id).status, StandingOrderStatus::Completed);
+}
+
+#[test]
+fn test_list_orders_by_sender() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let first = create_order(&env, &client, &token.address, &sender, &agent, 1, None);
+    let second = create_order(&env, &client, &token.address, &sender, &agent, 1, None);
+
+    let page = client.get_standing_orders_by_sender(&sender, &1, &10);
+    assert_eq!(page.total_records, 2);
+    assert_eq!(page.orders.get(0).unwrap().id, first);
+    assert_eq!(page.orders.get(1).unwrap().id, second);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+fn test_zero_interval_rejected() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+
+    client.create_standing_order(
+        &sender,
+        &agent,
+        &1000,
+        &token.address,
+        &String::from_str(&env, "NGN"),
+        &String::from_str(&env, "NG"),
+        &0,
+        &2_000,
+        &3,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_resume_active_order_rejected() {
+    let env = Env::default();
+    let (client, token, sender, agent) = setup(&env);
+    let order_id = create_order(&env, &client, &token.address, &sender, &agent, 3, None);
+
+    client.resume_standing_order(&order_id);
+}
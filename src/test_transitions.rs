@@ -1,9 +1,9 @@
 #![cfg(test)]
 
-use crate::{SwiftRemitContract, SwiftRemitContractClient, RemittanceStatus};
+use crate::{RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient, TransferState};
 use soroban_sdk::{
-    testutils::{Address as _, Events},
-    token, Address, Env, symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
 };
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
@@ -15,7 +15,7 @@ fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
     SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
 }
 
-fn setup_contract(env: &Env) -> (SwiftRemitContractClient, token::StellarAssetClient, Address, Address, Address) {
+fn setup_contract(env: &Env) -> (SwiftRemitContractClient<'_>, token::StellarAssetClient<'_>, Address, Address, Address) {
     let admin = Address::generate(env);
     let token_admin = Address::generate(env);
     let token = create_token_contract(env, &token_admin);
@@ -25,27 +25,38 @@ fn setup_contract(env: &Env) -> (SwiftRemitContractClient, token::StellarAssetCl
     let contract = create_swiftremit_contract(env);
     
     env.mock_all_auths();
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
     contract.register_agent(&agent);
+    contract.assign_role(&admin, &agent, &Role::Settler);
 
     token.mint(&sender, &10000);
 
     (contract, token, admin, agent, sender)
 }
 
+fn default_currency(env: &Env) -> String {
+    String::from_str(env, "USD")
+}
+
+fn default_country(env: &Env) -> String {
+    String::from_str(env, "US")
+}
+
 #[test]
 fn test_lifecycle_pending_to_processing() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Pending);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Processing);
@@ -54,10 +65,10 @@ fn test_lifecycle_pending_to_processing() {
 #[test]
 fn test_lifecycle_pending_to_cancelled() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Pending);
@@ -71,12 +82,12 @@ fn test_lifecycle_pending_to_cancelled() {
 #[test]
 fn test_lifecycle_processing_to_completed() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Processing);
@@ -90,58 +101,61 @@ fn test_lifecycle_processing_to_completed() {
 #[test]
 fn test_lifecycle_processing_to_failed() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Processing);
 
-    contract.mark_failed(&remittance_id);
+    contract.fail_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Failed);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #7)")]
-fn test_invalid_transition_pending_to_completed() {
+fn test_confirm_without_acceptance_passes_through_processing() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    // Should fail: cannot go directly from Pending to Completed
+    // Direct settlement takes the Pending -> Processing -> Completed path in one call
     contract.confirm_payout(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(contract.get_transfer_state(&remittance_id), Some(TransferState::Completed));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_invalid_transition_pending_to_failed() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // Should fail: cannot go directly from Pending to Failed
-    contract.mark_failed(&remittance_id);
+    contract.fail_remittance(&remittance_id);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #7)")]
+#[should_panic(expected = "Error(Contract, #47)")]
 fn test_invalid_transition_processing_to_cancelled() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 
     // Should fail: cannot cancel once processing has started
     contract.cancel_remittance(&remittance_id);
@@ -151,58 +165,58 @@ fn test_invalid_transition_processing_to_cancelled() {
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_terminal_state_completed_cannot_transition() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Should fail: Completed is a terminal state
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_terminal_state_cancelled_cannot_transition() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     contract.cancel_remittance(&remittance_id);
 
     // Should fail: Cancelled is a terminal state
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_terminal_state_failed_cannot_transition() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
-    contract.mark_failed(&remittance_id);
+    contract.accept_remittance(&remittance_id);
+    contract.fail_remittance(&remittance_id);
 
     // Should fail: Failed is a terminal state
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
 }
 
 #[test]
 fn test_transition_events_logged() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
+    contract.accept_remittance(&remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Just verify the remittance completed successfully
@@ -213,31 +227,86 @@ fn test_transition_events_logged() {
 #[test]
 fn test_failed_remittance_refunds_sender() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, asset, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
     
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &asset.address, &default_currency(&env), &default_country(&env), &None, &None);
 
-    contract.start_processing(&remittance_id);
-    contract.mark_failed(&remittance_id);
+    contract.accept_remittance(&remittance_id);
+    contract.fail_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Failed);
+    assert_eq!(token::Client::new(&env, &asset.address).balance(&sender), 10000);
+    assert_eq!(contract.get_transfer_state(&remittance_id), Some(TransferState::Refunded));
+}
+
+#[test]
+fn test_lapsed_acceptance_reverts_to_pending() {
+    let env = Env::default();
+    let (contract, token, admin, agent, sender) = setup_contract(&env);
+
+    env.mock_all_auths();
+    contract.update_acceptance_timeout(&admin, &60);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+
+    let deadline = contract.accept_remittance(&remittance_id);
+    assert_eq!(deadline, env.ledger().timestamp() + 60);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+
+    // Sender can cancel once the acceptance has lapsed
+    contract.cancel_remittance(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Cancelled);
+    assert!(contract.get_acceptance_deadline(&remittance_id).is_none());
+}
+
+#[test]
+fn test_revert_lapsed_acceptance_is_permissionless() {
+    let env = Env::default();
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
+
+    env.mock_all_auths();
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let deadline = contract.accept_remittance(&remittance_id);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    env.set_auths(&[]);
+    contract.revert_lapsed_acceptance(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(contract.get_transfer_state(&remittance_id), Some(TransferState::Initiated));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #47)")]
+fn test_revert_before_deadline_rejected() {
+    let env = Env::default();
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
+
+    env.mock_all_auths();
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    contract.accept_remittance(&remittance_id);
+
+    contract.revert_lapsed_acceptance(&remittance_id);
 }
 
 #[test]
 fn test_multiple_remittances_independent_lifecycles() {
     let env = Env::default();
-    let (contract, _token, _admin, agent, sender) = setup_contract(&env);
+    let (contract, token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
     
-    let remittance_id_1 = contract.create_remittance(&sender, &agent, &1000, &None);
-    let remittance_id_2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    let remittance_id_1 = contract.create_remittance(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
+    let remittance_id_2 = contract.create_remittance(&sender, &agent, &2000, &token.address, &default_currency(&env), &default_country(&env), &None, &None);
 
     // First remittance: Pending -> Processing -> Completed
-    contract.start_processing(&remittance_id_1);
+    contract.accept_remittance(&remittance_id_1);
     contract.confirm_payout(&remittance_id_1);
 
     // Second remittance: Pending -> Cancelled
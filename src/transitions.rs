@@ -1,5 +1,43 @@
-use crate::types::RemittanceStatus;
+//! Remittance lifecycle state machine.
+//!
+//! `TRANSITIONS` is the single source of truth for how a remittance moves
+//! between statuses. The `TransferState` registry is derived from it through
+//! `transfer_state_for`, so the two can never disagree.
+
+use soroban_sdk::Env;
+
 use crate::errors::ContractError;
+use crate::storage::{reindex_remittance_status, set_remittance, set_transfer_state};
+use crate::types::{Remittance, RemittanceStatus, TransferState};
+
+/// Every allowed remittance status transition as `(from, to)`.
+const TRANSITIONS: [(RemittanceStatus, RemittanceStatus); 9] = [
+    // From Pending
+    (RemittanceStatus::Pending, RemittanceStatus::Processing),
+    (RemittanceStatus::Pending, RemittanceStatus::Cancelled),
+    (RemittanceStatus::Pending, RemittanceStatus::Expired),
+    (RemittanceStatus::Pending, RemittanceStatus::Frozen),
+    // From Processing
+    (RemittanceStatus::Processing, RemittanceStatus::Pending),
+    (RemittanceStatus::Processing, RemittanceStatus::Completed),
+    (RemittanceStatus::Processing, RemittanceStatus::Failed),
+    (RemittanceStatus::Processing, RemittanceStatus::Frozen),
+    // From Frozen
+    (RemittanceStatus::Frozen, RemittanceStatus::Pending),
+];
+
+/// Maps a remittance status to its transfer registry state.
+pub fn transfer_state_for(status: &RemittanceStatus) -> TransferState {
+    match status {
+        RemittanceStatus::Pending => TransferState::Initiated,
+        RemittanceStatus::Processing => TransferState::Processing,
+        RemittanceStatus::Completed => TransferState::Completed,
+        RemittanceStatus::Frozen => TransferState::Frozen,
+        RemittanceStatus::Cancelled | RemittanceStatus::Expired | RemittanceStatus::Failed => {
+            TransferState::Refunded
+        }
+    }
+}
 
 /// Validates if a state transition is allowed.
 /// Returns Ok(()) if valid, Err(ContractError::InvalidStatus) if invalid.
@@ -7,25 +45,52 @@ pub fn validate_transition(
     from: &RemittanceStatus,
     to: &RemittanceStatus,
 ) -> Result<(), ContractError> {
-    match (from, to) {
-        // From Pending
-        (RemittanceStatus::Pending, RemittanceStatus::Processing) => Ok(()),
-        (RemittanceStatus::Pending, RemittanceStatus::Cancelled) => Ok(()),
-        
-        // From Processing
-        (RemittanceStatus::Processing, RemittanceStatus::Completed) => Ok(()),
-        (RemittanceStatus::Processing, RemittanceStatus::Failed) => Ok(()),
-        
-        // Terminal states cannot transition
-        (RemittanceStatus::Completed, _) => Err(ContractError::InvalidStatus),
-        (RemittanceStatus::Cancelled, _) => Err(ContractError::InvalidStatus),
-        (RemittanceStatus::Failed, _) => Err(ContractError::InvalidStatus),
-        
-        // All other transitions are invalid
-        _ => Err(ContractError::InvalidStatus),
+    if TRANSITIONS.iter().any(|(a, b)| a == from && b == to) {
+        Ok(())
+    } else {
+        Err(ContractError::InvalidStatus)
     }
 }
 
+/// Returns whether a transfer registry transition is allowed.
+///
+/// A transition is allowed if some remittance transition maps onto it.
+/// Staying in the same state is allowed unless the state is terminal.
+pub fn is_transfer_transition_allowed(from: &TransferState, to: &TransferState) -> bool {
+    let mut has_outgoing = false;
+    for (a, b) in TRANSITIONS.iter() {
+        if transfer_state_for(a) == *from {
+            has_outgoing = true;
+            if transfer_state_for(b) == *to {
+                return true;
+            }
+        }
+    }
+    has_outgoing && from == to
+}
+
+/// Returns whether a remittance in `status` can still be paid out.
+pub fn can_settle(status: &RemittanceStatus) -> bool {
+    matches!(status, RemittanceStatus::Pending | RemittanceStatus::Processing)
+}
+
+/// Moves a remittance to a new status and persists it.
+///
+/// Validates the transition, stores the remittance, moves it between status
+/// indexes and updates the transfer registry.
+pub fn transition_remittance(
+    env: &Env,
+    remittance: &mut Remittance,
+    to: RemittanceStatus,
+) -> Result<(), ContractError> {
+    validate_transition(&remittance.status, &to)?;
+
+    let from = core::mem::replace(&mut remittance.status, to);
+    set_remittance(env, remittance.id, remittance);
+    reindex_remittance_status(env, remittance.id, &from, &remittance.status);
+    set_transfer_state(env, remittance.id, transfer_state_for(&remittance.status))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,6 +99,7 @@ mod tests {
     fn test_valid_transitions() {
         assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Processing).is_ok());
         assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Cancelled).is_ok());
+        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Expired).is_ok());
         assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Completed).is_ok());
         assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Failed).is_ok());
     }
@@ -46,8 +112,25 @@ mod tests {
 
     #[test]
     fn test_invalid_transitions_from_processing() {
-        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Pending).is_err());
         assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Cancelled).is_err());
+        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Expired).is_err());
+    }
+
+    #[test]
+    fn test_lapsed_acceptance_reverts_to_pending() {
+        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Pending).is_ok());
+        assert!(is_transfer_transition_allowed(&TransferState::Processing, &TransferState::Initiated));
+    }
+
+    #[test]
+    fn test_frozen_only_returns_to_pending() {
+        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Frozen).is_ok());
+        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Frozen).is_ok());
+        assert!(validate_transition(&RemittanceStatus::Frozen, &RemittanceStatus::Pending).is_ok());
+        assert!(validate_transition(&RemittanceStatus::Frozen, &RemittanceStatus::Completed).is_err());
+        assert!(validate_transition(&RemittanceStatus::Frozen, &RemittanceStatus::Cancelled).is_err());
+        assert!(validate_transition(&RemittanceStatus::Frozen, &RemittanceStatus::Expired).is_err());
+        assert!(!is_transfer_transition_allowed(&TransferState::Frozen, &TransferState::Refunded));
     }
 
     #[test]
@@ -55,6 +138,19 @@ mod tests {
         assert!(validate_transition(&RemittanceStatus::Completed, &RemittanceStatus::Pending).is_err());
         assert!(validate_transition(&RemittanceStatus::Completed, &RemittanceStatus::Processing).is_err());
         assert!(validate_transition(&RemittanceStatus::Cancelled, &RemittanceStatus::Pending).is_err());
+        assert!(validate_transition(&RemittanceStatus::Expired, &RemittanceStatus::Pending).is_err());
         assert!(validate_transition(&RemittanceStatus::Failed, &RemittanceStatus::Processing).is_err());
     }
+
+    #[test]
+    fn test_transfer_states_follow_table() {
+        assert!(is_transfer_transition_allowed(&TransferState::Initiated, &TransferState::Processing));
+        assert!(is_transfer_transition_allowed(&TransferState::Initiated, &TransferState::Refunded));
+        assert!(is_transfer_transition_allowed(&TransferState::Processing, &TransferState::Completed));
+        assert!(is_transfer_transition_allowed(&TransferState::Processing, &TransferState::Refunded));
+        assert!(is_transfer_transition_allowed(&TransferState::Initiated, &TransferState::Initiated));
+        assert!(!is_transfer_transition_allowed(&TransferState::Initiated, &TransferState::Completed));
+        assert!(!is_transfer_transition_allowed(&TransferState::Completed, &TransferState::Completed));
+        assert!(!is_transfer_transition_allowed(&TransferState::Refunded, &TransferState::Initiated));
+    }
 }
@@ -3,7 +3,7 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address, Vec, String};
+use soroban_sdk::{contracttype, Address, BytesN, Vec, String};
 
 /// Role types for authorization
 #[contracttype]
@@ -11,6 +11,12 @@ use soroban_sdk::{contracttype, Address, Vec, String};
 pub enum Role {
     Admin,
     Settler,
+    /// Rules on disputes opened against completed remittances
+    Arbitrator,
+    /// Posts currency pair prices to the FX rate registry
+    RateFeeder,
+    /// Manages sender KYC records and tier limits
+    ComplianceOfficer,
 }
 
 /// Transfer state for on-chain registry
@@ -21,26 +27,15 @@ pub enum TransferState {
     Processing,
     Completed,
     Refunded,
+    Frozen,
 }
 
 impl TransferState {
-    /// Validates if transition to new state is allowed
+    /// Validates if transition to new state is allowed.
+    ///
+    /// Derived from the remittance transition table in `transitions.rs`.
     pub fn can_transition_to(&self, new_state: &TransferState) -> bool {
-        match (self, new_state) {
-            // From Initiated
-            (TransferState::Initiated, TransferState::Processing) => true,
-            (TransferState::Initiated, TransferState::Refunded) => true,
-            // From Processing
-            (TransferState::Processing, TransferState::Completed) => true,
-            (TransferState::Processing, TransferState::Refunded) => true,
-            // Terminal states cannot transition
-            (TransferState::Completed, _) => false,
-            (TransferState::Refunded, _) => false,
-            // Same state is allowed (idempotent)
-            (a, b) if a == b => true,
-            // All other transitions invalid
-            _ => false,
-        }
+        crate::transitions::is_transfer_transition_allowed(self, new_state)
     }
 }
 
@@ -48,17 +43,57 @@ impl TransferState {
 ///
 /// Remittances progress through these states:
 /// - `Pending`: Initial state after creation, awaiting agent confirmation
+/// - `Processing`: Agent has accepted and is paying out; the sender cannot cancel
 /// - `Completed`: Agent has confirmed payout and received funds
 /// - `Cancelled`: Sender has cancelled and received refund
+/// - `Expired`: Expiry passed before settlement and the sender was refunded
+/// - `Failed`: Agent could not pay out and the sender was refunded
+/// - `Frozen`: A party is sanctioned; funds are held until a compliance officer releases it
+///
+/// Allowed transitions are defined in `transitions.rs`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RemittanceStatus {
     /// Remittance is awaiting agent confirmation
     Pending,
+    /// Remittance has been accepted by the agent and is locked for payout
+    Processing,
     /// Remittance has been paid out to the agent
     Completed,
     /// Remittance has been cancelled and refunded to sender
     Cancelled,
+    /// Remittance passed its expiry unsettled and was refunded to sender
+    Expired,
+    /// Agent reported the payout as failed and the sender was refunded
+    Failed,
+    /// A party is on the sanctions denylist; funds are held until released
+    Frozen,
+}
+
+/// Result of a `sweep_expired` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SweepResult {
+    /// Remittances expired and refunded by this sweep
+    pub expired_ids: Vec<u64>,
+    /// Position in the pending index to resume the next sweep from
+    pub next_cursor: u32,
+    /// Whether the sweep reached the end of the pending index
+    pub done: bool,
+}
+
+/// One page of a remittance history query.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittancePage {
+    /// Remittances on this page
+    pub remittances: Vec<Remittance>,
+    /// 1-based page number that was requested
+    pub page: u32,
+    /// Number of pages at the effective page size
+    pub total_pages: u32,
+    /// Number of remittances matching the query
+    pub total_records: u32,
 }
 
 /// Escrow status for locked funds
@@ -78,6 +113,7 @@ pub struct Escrow {
     pub sender: Address,
     pub recipient: Address,
     pub amount: i128,
+    pub token: Address,
     pub status: EscrowStatus,
 }
 
@@ -93,15 +129,49 @@ pub struct Remittance {
     /// Address of the sender who initiated the remittance
     pub sender: Address,
     /// Address of the agent who will receive the payout
+    /// (the contract address for an unclaimed open-claim remittance)
     pub agent: Address,
-    /// Total amount sent by the sender (in USDC)
+    /// Total amount sent by the sender (in `token` units)
     pub amount: i128,
-    /// Platform fee deducted from the amount (in USDC)
+    /// Platform fee deducted from the amount (in `token` units)
     pub fee: i128,
+    /// Whitelisted token contract the remittance is denominated in
+    pub token: Address,
     /// Current status of the remittance
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
     pub expiry: Option<u64>,
+    /// Payout currency of the corridor (e.g., "NGN")
+    pub currency: String,
+    /// Destination country of the corridor (e.g., "NG")
+    pub country: String,
+    /// Amount the agent must pay out in `currency`, when created from a quote
+    pub dest_amount: Option<i128>,
+    /// Protocol fee locked by a quote; settlement applies the current rate when absent
+    pub protocol_fee: Option<i128>,
+    /// Timestamp at which the remittance was created
+    pub created_at: u64,
+}
+
+/// Idempotency record for a `create_remittance_idempotent` request.
+///
+/// Binds a client-provided key to the hash of the original request payload
+/// so retries return the original remittance instead of moving funds again.
+/// Keys are scoped per sender: the same key used by two senders refers to two
+/// independent records.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdempotencyRecord {
+    /// Sender the key belongs to
+    pub sender: Address,
+    /// The client-provided idempotency key
+    pub key: String,
+    /// SHA-256 hash of the request payload (sender, agent, amount, token, currency, country, expiry)
+    pub request_hash: BytesN<32>,
+    /// The remittance ID returned from the original request
+    pub remittance_id: u64,
+    /// Ledger timestamp after which the record no longer applies
+    pub expires_at: u64,
 }
 
 /// Entry for batch settlement processing.
@@ -137,6 +207,7 @@ pub struct SettlementSimulation {
     pub error_message: Option<u32>,
 }
 
+/// Rolling 24-hour send cap for a corridor, applied per sender.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimit {
@@ -145,9 +216,30 @@ pub struct DailyLimit {
     pub limit: i128,
 }
 
+/// A sender's transfer in a corridor, kept for rolling daily limit checks.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferRecord {
     pub timestamp: u64,
     pub amount: i128,
 }
+
+/// Fee terms of a registered integrator (white-label partner).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Integrator {
+    /// Fee charged on top of remittances in basis points
+    pub fee_bps: u32,
+    /// Address receiving the integrator's fee withdrawals
+    pub withdrawal_address: Address,
+}
+
+/// Integrator fee charged on a remittance, held until the remittance settles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntegratorCharge {
+    /// Integrator the remittance was created through
+    pub integrator: Address,
+    /// Fee paid by the sender on top of the remittance amount
+    pub fee: i128,
+}
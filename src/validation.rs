@@ -87,6 +87,14 @@ pub fn validate_remittance_pending(remittance: &crate::Remittance) -> Result<(),
     Ok(())
 }
 
+/// Validates that a remittance can still be paid out (Pending or Processing).
+pub fn validate_remittance_settleable(remittance: &crate::Remittance) -> Result<(), ContractError> {
+    if !crate::can_settle(&remittance.status) {
+        return Err(ContractError::InvalidStatus);
+    }
+    Ok(())
+}
+
 /// Validates that a settlement has not expired.
 pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result<(), ContractError> {
     if let Some(expiry_time) = expiry {
@@ -138,20 +146,257 @@ pub fn validate_initialize_request(
     Ok(())
 }
 
+/// Validates that a corridor names both a currency and a country.
+pub fn validate_corridor(
+    currency: &soroban_sdk::String,
+    country: &soroban_sdk::String,
+) -> Result<(), ContractError> {
+    if currency.is_empty() || country.is_empty() {
+        return Err(ContractError::InvalidCorridor);
+    }
+    Ok(())
+}
+
+/// Validates that a token is whitelisted for remittances and escrows.
+pub fn validate_token_whitelisted(env: &Env, token: &Address) -> Result<(), ContractError> {
+    if !crate::is_token_whitelisted(env, token) {
+        return Err(ContractError::TokenNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Validates that an HTLC timelock lies in the future.
+pub fn validate_timelock(env: &Env, timelock: u64) -> Result<(), ContractError> {
+    if timelock <= env.ledger().timestamp() {
+        return Err(ContractError::InvalidTimelock);
+    }
+    Ok(())
+}
+
+/// Validates a standing order schedule.
+pub fn validate_standing_order_schedule(
+    interval: u64,
+    first_due: u64,
+    count: u32,
+    end_date: Option<u64>,
+) -> Result<(), ContractError> {
+    if interval == 0 || count == 0 {
+        return Err(ContractError::InvalidSchedule);
+    }
+    if matches!(end_date, Some(end_date) if end_date < first_due) {
+        return Err(ContractError::InvalidSchedule);
+    }
+    Ok(())
+}
+
+/// Validates the destination currency, FX rate and deadline of a new quote.
+pub fn validate_quote_terms(
+    env: &Env,
+    dest_currency: &soroban_sdk::String,
+    dest_country: &soroban_sdk::String,
+    fx_rate: i128,
+    valid_until: u64,
+) -> Result<(), ContractError> {
+    validate_corridor(dest_currency, dest_country)?;
+    if fx_rate <= 0 {
+        return Err(ContractError::InvalidFxRate);
+    }
+    if valid_until <= env.ledger().timestamp() {
+        return Err(ContractError::QuoteExpired);
+    }
+    Ok(())
+}
+
+/// Validates a rate feeder's submission for a currency pair.
+pub fn validate_rate_submission(
+    env: &Env,
+    base: &soroban_sdk::String,
+    quote: &soroban_sdk::String,
+    rate: i128,
+    timestamp: u64,
+    previous: Option<crate::RateSubmission>,
+) -> Result<(), ContractError> {
+    if base.is_empty() || quote.is_empty() {
+        return Err(ContractError::InvalidCorridor);
+    }
+    if rate <= 0 {
+        return Err(ContractError::InvalidFxRate);
+    }
+    if timestamp > env.ledger().timestamp()
+        || matches!(previous, Some(previous) if timestamp < previous.timestamp)
+    {
+        return Err(ContractError::InvalidRateTimestamp);
+    }
+    if !crate::is_rate_fresh(env, timestamp) {
+        return Err(ContractError::StaleRate);
+    }
+    Ok(())
+}
+
+/// Validates that KYC tier caps are non-negative and nested.
+pub fn validate_kyc_limits(limits: &crate::KycLimits) -> Result<(), ContractError> {
+    if limits.per_transaction < 0
+        || limits.per_transaction > limits.daily
+        || limits.daily > limits.monthly
+    {
+        return Err(ContractError::InvalidKycLimits);
+    }
+    Ok(())
+}
+
+/// Validates that an address is not on the sanctions denylist.
+pub fn validate_not_sanctioned(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if crate::is_denylisted(env, address) {
+        return Err(ContractError::AddressSanctioned);
+    }
+    Ok(())
+}
+
+/// Validates that a bond threshold and minimum bond are non-negative.
+pub fn validate_bond_config(config: &crate::BondConfig) -> Result<(), ContractError> {
+    if config.threshold < 0 || config.min_bond < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Validates that the unbonding delay outlasts the dispute window, so collateral
+/// cannot leave before every dispute over past payouts has been opened.
+pub fn validate_unbonding_delay(unbonding_delay: u64, dispute_window: u64) -> Result<(), ContractError> {
+    if unbonding_delay <= dispute_window {
+        return Err(ContractError::InvalidUnbondingDelay);
+    }
+    Ok(())
+}
+
+/// Validates that an agent profile lists at least one country and currency, within size limits.
+pub fn validate_agent_profile(profile: &crate::AgentProfile) -> Result<(), ContractError> {
+    let max = crate::MAX_PROFILE_ENTRIES;
+    if profile.countries.is_empty()
+        || profile.currencies.is_empty()
+        || profile.countries.len() > max
+        || profile.currencies.len() > max
+        || profile.payout_methods.len() > max
+        || profile.countries.iter().any(|country| country.is_empty())
+        || profile.currencies.iter().any(|currency| currency.is_empty())
+    {
+        return Err(ContractError::InvalidAgentProfile);
+    }
+    Ok(())
+}
+
+/// Validates that an agent serves the remittance corridor.
+pub fn validate_agent_serves_corridor(
+    env: &Env,
+    agent: &Address,
+    currency: &soroban_sdk::String,
+    country: &soroban_sdk::String,
+) -> Result<(), ContractError> {
+    if !crate::agent_serves_corridor(env, agent, currency, country) {
+        return Err(ContractError::AgentCorridorNotServed);
+    }
+    Ok(())
+}
+
+/// Validates a fee strategy before it is stored.
+pub fn validate_fee_strategy(strategy: &crate::FeeStrategy) -> Result<(), ContractError> {
+    match strategy {
+        crate::FeeStrategy::Percentage(bps) | crate::FeeStrategy::Dynamic(bps) => validate_fee_bps(*bps),
+        crate::FeeStrategy::Flat(fee) => {
+            if *fee < 0 {
+                return Err(ContractError::InvalidAmount);
+            }
+            Ok(())
+        }
+        crate::FeeStrategy::Tiered(tiers) => crate::validate_fee_tiers(tiers),
+    }
+}
+
+/// Validates that fee limits are non-negative and the maximum is not below the minimum.
+pub fn validate_fee_limits(limits: &crate::FeeLimits) -> Result<(), ContractError> {
+    if limits.min_fee.is_some_and(|min_fee| min_fee < 0) || limits.max_fee.is_some_and(|max_fee| max_fee < 0) {
+        return Err(ContractError::InvalidFeeLimits);
+    }
+    if let (Some(min_fee), Some(max_fee)) = (limits.min_fee, limits.max_fee) {
+        if max_fee < min_fee {
+            return Err(ContractError::InvalidFeeLimits);
+        }
+    }
+    Ok(())
+}
+
+/// Validates a fee schedule's strategy and limits.
+pub fn validate_fee_schedule(schedule: &crate::FeeSchedule) -> Result<(), ContractError> {
+    validate_fee_strategy(&schedule.strategy)?;
+    validate_fee_limits(&schedule.limits)
+}
+
+/// Validates that a quote has not been consumed and is still within its deadline.
+pub fn validate_quote_usable(env: &Env, quote: &crate::Quote) -> Result<(), ContractError> {
+    if quote.remittance_id.is_some() {
+        return Err(ContractError::QuoteAlreadyUsed);
+    }
+    if env.ledger().timestamp() > quote.valid_until {
+        return Err(ContractError::QuoteExpired);
+    }
+    Ok(())
+}
+
+/// Comprehensive validation for create_open_remittance request.
+pub fn validate_create_open_remittance_request(
+    env: &Env,
+    sender: &Address,
+    amount: i128,
+    token: &Address,
+    currency: &soroban_sdk::String,
+    country: &soroban_sdk::String,
+) -> Result<(), ContractError> {
+    validate_address(sender)?;
+    validate_amount(amount)?;
+    validate_token_whitelisted(env, token)?;
+    validate_corridor(currency, country)?;
+    Ok(())
+}
+
 /// Comprehensive validation for create_remittance request.
 pub fn validate_create_remittance_request(
     env: &Env,
     sender: &Address,
     agent: &Address,
     amount: i128,
+    token: &Address,
+    currency: &soroban_sdk::String,
+    country: &soroban_sdk::String,
 ) -> Result<(), ContractError> {
     validate_address(sender)?;
     validate_address(agent)?;
     validate_amount(amount)?;
+    validate_token_whitelisted(env, token)?;
+    validate_corridor(currency, country)?;
     validate_agent_registered(env, agent)?;
     Ok(())
 }
 
+/// Validates that an idempotency key is non-empty, within the length limit and
+/// made only of ASCII letters, digits, `-` and `_`.
+pub fn validate_idempotency_key(key: &soroban_sdk::String) -> Result<(), ContractError> {
+    let len = key.len();
+    if len == 0 || len > crate::MAX_IDEMPOTENCY_KEY_LEN {
+        return Err(ContractError::InvalidIdempotencyKey);
+    }
+
+    let mut buf = [0u8; crate::MAX_IDEMPOTENCY_KEY_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    key.copy_into_slice(bytes);
+    if !bytes
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || *b == b'-' || *b == b'_')
+    {
+        return Err(ContractError::InvalidIdempotencyKey);
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for confirm_payout request.
 /// Returns the remittance to avoid re-reading in the caller.
 pub fn validate_confirm_payout_request(
@@ -160,7 +405,7 @@ pub fn validate_confirm_payout_request(
 ) -> Result<crate::Remittance, ContractError> {
     validate_not_paused(env)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
-    validate_remittance_pending(&remittance)?;
+    validate_remittance_settleable(&remittance)?;
     validate_no_duplicate_settlement(env, remittance_id)?;
     validate_settlement_not_expired(env, remittance.expiry)?;
     validate_address(&remittance.agent)?;
@@ -184,9 +429,10 @@ pub fn validate_cancel_remittance_request(
 pub fn validate_withdraw_fees_request(
     env: &Env,
     to: &Address,
+    token: &Address,
 ) -> Result<i128, ContractError> {
     validate_address(to)?;
-    let fees = crate::get_accumulated_fees(env)?;
+    let fees = crate::get_accumulated_fees(env, token);
     validate_fees_available(fees)?;
     Ok(fees)
 }
@@ -0,0 +1,113 @@
+//! Off-chain settlement proof validation.
+//!
+//! Some settlements must not be released on agent authorization alone: an
+//! oracle has to attest that the fiat leg actually happened. This module keeps
+//! a registry of trusted oracle ed25519 public keys, tracks which remittances
+//! and agents require such an attestation, and verifies signed proofs.
+//!
+//! A proof is an ed25519 signature over the 32-byte settlement ID produced by
+//! `compute_settlement_id_from_remittance`. Because the settlement ID commits
+//! to the remittance ID, parties, amount, fee and expiry, a proof for one
+//! remittance can never be replayed against another.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env};
+
+use crate::{compute_settlement_id_from_remittance, ContractError, Remittance};
+
+/// Signed oracle attestation that a remittance was paid out off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofData {
+    /// Ed25519 public key of the registered oracle that signed the proof
+    pub oracle: BytesN<32>,
+    /// Ed25519 signature over the remittance settlement ID
+    pub signature: BytesN<64>,
+}
+
+/// Storage keys for proof validation configuration.
+#[contracttype]
+#[derive(Clone)]
+pub enum VerificationKey {
+    /// Oracle registration status indexed by ed25519 public key (persistent storage)
+    Oracle(BytesN<32>),
+    /// Proof requirement for every remittance assigned to an agent (persistent storage)
+    AgentProofRequired(Address),
+    /// Proof requirement for a single remittance (persistent storage)
+    RemittanceProofRequired(u64),
+}
+
+/// Checks if an oracle public key is registered.
+pub fn is_oracle_registered(env: &Env, oracle: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .get(&VerificationKey::Oracle(oracle.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets an oracle public key's registration status.
+pub fn set_oracle_registered(env: &Env, oracle: &BytesN<32>, registered: bool) {
+    env.storage()
+        .persistent()
+        .set(&VerificationKey::Oracle(oracle.clone()), &registered);
+}
+
+/// Checks if an agent requires oracle proofs for all its settlements.
+pub fn is_agent_proof_required(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&VerificationKey::AgentProofRequired(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets whether an agent requires oracle proofs for all its settlements.
+pub fn set_agent_proof_required(env: &Env, agent: &Address, required: bool) {
+    env.storage()
+        .persistent()
+        .set(&VerificationKey::AgentProofRequired(agent.clone()), &required);
+}
+
+/// Checks if a single remittance has been marked as requiring an oracle proof.
+pub fn is_remittance_proof_required(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&VerificationKey::RemittanceProofRequired(remittance_id))
+        .unwrap_or(false)
+}
+
+/// Marks a single remittance as requiring an oracle proof.
+pub fn set_remittance_proof_required(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&VerificationKey::RemittanceProofRequired(remittance_id), &true);
+}
+
+/// Returns whether settling `remittance` requires an oracle proof, either
+/// because the remittance itself or its assigned agent was configured so.
+pub fn requires_proof(env: &Env, remittance: &Remittance) -> bool {
+    is_remittance_proof_required(env, remittance.id)
+        || is_agent_proof_required(env, &remittance.agent)
+}
+
+/// Verifies an oracle proof for a remittance.
+///
+/// # Returns
+///
+/// * `Ok(())` - The proof was signed by a registered oracle over this remittance's settlement ID
+/// * `Err(ContractError::OracleNotRegistered)` - The signing key is not in the oracle registry
+///
+/// # Panics
+///
+/// `ed25519_verify` traps on a bad signature, so an invalid signature aborts
+/// the invocation rather than returning an error.
+pub fn verify_proof(env: &Env, remittance: &Remittance, proof: &ProofData) -> Result<(), ContractError> {
+    if !is_oracle_registered(env, &proof.oracle) {
+        return Err(ContractError::OracleNotRegistered);
+    }
+
+    let settlement_id = compute_settlement_id_from_remittance(env, remittance);
+    let message = Bytes::from_array(env, &settlement_id.to_array());
+    env.crypto()
+        .ed25519_verify(&proof.oracle, &message, &proof.signature);
+
+    Ok(())
+}